@@ -153,6 +153,9 @@ fn bench_luau_generation(c: &mut Criterion) {
                 value: v.clone(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             })
             .collect();
 
@@ -187,6 +190,9 @@ fn bench_csv_generation(c: &mut Criterion) {
                 value: v.clone(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             })
             .collect();
 
@@ -241,6 +247,9 @@ fn bench_complete_build(c: &mut Criterion) {
                             value: v.clone(),
                             locale: "en".to_string(),
                             context: None,
+                            description: None,
+                            max_length: None,
+                            location: None,
                         })
                         .collect();
 
@@ -1,8 +1,8 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use crate::{config, generator, parser};
+use crate::{config, generator, parser, utils, validator};
 
 /// Build translations from source files
 pub fn build(config_path: &Path) -> Result<()> {
@@ -21,32 +21,26 @@ pub fn build(config_path: &Path) -> Result<()> {
     let mut all_translations = Vec::new();
     let mut total_keys = 0;
 
+    let input_dir = Path::new(&config.input_directory);
+
     for locale in &config.supported_locales {
-        // Try JSON first
-        let json_path = Path::new(&config.input_directory).join(format!("{}.json", locale));
-
-        // Try YAML if JSON doesn't exist
-        let yaml_path = Path::new(&config.input_directory).join(format!("{}.yaml", locale));
-
-        let yml_path = Path::new(&config.input_directory).join(format!("{}.yml", locale));
-
-        let translations = if json_path.exists() {
-            parser::parse_json_file(&json_path, locale)
-                .context(format!("Failed to parse JSON for {}", locale))?
-        } else if yaml_path.exists() {
-            parser::parse_yaml_file(&yaml_path, locale)
-                .context(format!("Failed to parse YAML for {}", locale))?
-        } else if yml_path.exists() {
-            parser::parse_yaml_file(&yml_path, locale)
-                .context(format!("Failed to parse YAML for {}", locale))?
-        } else {
-            log::warn!("Translation file not found for locale: {}", locale);
-            println!(
-                "{} Translation file not found for locale: {} (tried .json, .yaml, .yml)",
-                "⚠".yellow(),
-                locale
-            );
-            continue;
+        let translations = match parser::load_locale_translations(
+            input_dir,
+            locale,
+            &config.base_locale,
+            config.scalar_values,
+        )? {
+            Some(translations) => translations,
+            None => {
+                log::warn!("Translation file not found for locale: {}", locale);
+                println!(
+                    "{} Translation file not found for locale: {} (tried {})",
+                    "⚠".yellow(),
+                    locale,
+                    parser::locale_source_patterns(locale)
+                );
+                continue;
+            }
         };
 
         let key_count = translations.len();
@@ -67,63 +61,137 @@ pub fn build(config_path: &Path) -> Result<()> {
     }
 
     // Parse and merge overrides if enabled
+    let mut variants = Vec::new();
     if let Some(override_config) = &config.overrides {
         if override_config.enabled {
             let override_path = Path::new(&override_config.file);
 
             if override_path.exists() {
-                let overrides =
-                    parser::parse_overrides(override_path).context("Failed to parse overrides")?;
+                let overrides = parser::parse_override_file(override_path)
+                    .context("Failed to parse overrides")?;
+
+                // Checked against the source files, before the overrides are merged in
+                let issues = validator::overrides::check_overrides(
+                    &overrides,
+                    &all_translations,
+                    &config.base_locale,
+                    &config.supported_locales,
+                );
+                if !issues.is_empty() {
+                    for issue in &issues {
+                        eprintln!("{} {}", "✗".red(), issue);
+                    }
+                    anyhow::bail!(
+                        "{} override(s) don't match the translations\n\
+                         \n\
+                         Hint: Overrides and variants must use keys of the base locale and\n\
+                         locales in supported_locales, with the same {{params}} as the base entry.",
+                        issues.len()
+                    );
+                }
 
-                if !overrides.is_empty() {
+                if !overrides.translations.is_empty() {
                     println!(
                         "{} Loaded {} overrides from {}",
                         "✓".green(),
-                        overrides.len(),
+                        overrides.translations.len(),
                         override_path.display()
                     );
 
-                    all_translations = parser::merge_translations(all_translations, overrides);
+                    all_translations =
+                        parser::merge_translations(all_translations, overrides.translations);
+                }
+
+                if !overrides.variants.is_empty() {
+                    println!(
+                        "{} Loaded {} variants from {}",
+                        "✓".green(),
+                        overrides.variants.len(),
+                        override_path.display()
+                    );
                 }
+
+                variants = overrides.variants;
             } else {
                 log::warn!("Override file not found: {}", override_path.display());
             }
         }
     }
 
-    // Create output directory
-    let output_dir = Path::new(&config.output_directory);
-    std::fs::create_dir_all(output_dir).context("Failed to create output directory")?;
-
-    // Generate Luau code with analytics config
-    let luau_code = if let Some(analytics_config) = &config.analytics {
-        generator::generate_luau_with_config(
+    // Links are resolved after the overrides are merged in, so overrides can link
+    // and be linked to
+    let issues =
+        validator::links::check_links(&all_translations, &config.base_locale, &config.fallbacks);
+    if !issues.is_empty() {
+        for issue in &issues {
+            eprintln!("{} {}", "✗".red(), issue);
+        }
+        anyhow::bail!(
+            "{} translation value(s) have broken links\n\
+             \n\
+             Hint: @:key and @:(key) link to another key, which must exist in the locale,\n\
+             its fallbacks or the base locale, and must not link back.",
+            issues.len()
+        );
+    }
+    for variant in &mut variants {
+        variant.translations = utils::links::resolve_overlay_links(
             &all_translations,
+            &variant.translations,
             &config.base_locale,
-            Some(analytics_config),
+            &config.fallbacks,
         )
-        .context("Failed to generate Luau code")?
-    } else {
-        generator::generate_luau(&all_translations, &config.base_locale)
-            .context("Failed to generate Luau code")?
-    };
-
-    let output_file = output_dir.join("Translations.lua");
-    std::fs::write(&output_file, luau_code).context("Failed to write Luau file")?;
-
-    println!("{} Generated {}", "✓".green(), output_file.display());
+        .with_context(|| format!("Failed to resolve links in variant '{}'", variant.name))?;
+    }
+    let all_translations =
+        utils::links::resolve_links(&all_translations, &config.base_locale, &config.fallbacks)?;
 
-    // Generate type definitions
-    let types_dir = output_dir.join("types");
-    std::fs::create_dir_all(&types_dir).context("Failed to create types directory")?;
+    // Placeholders are checked after the overrides are merged in, so both are
+    // covered, and in every variant
+    let mut issues =
+        validator::placeholders::check_placeholders(&all_translations, &config.formatters);
+    for variant in &variants {
+        issues.extend(validator::placeholders::check_placeholders(
+            &variant.translations,
+            &config.formatters,
+        ));
+    }
+    if !issues.is_empty() {
+        for issue in &issues {
+            eprintln!("{} {}", "✗".red(), issue);
+        }
+        anyhow::bail!(
+            "{} translation value(s) have invalid placeholders\n\
+             \n\
+             Hint: Placeholders look like {{name}} or {{name:spec}}, with the specifiers int, num,\n\
+             fixed(n), compact, percent, currency(unit), relative, duration, date, time,\n\
+             datetime and translate, plus the formatters in slang-roblox.yaml.\n\
+             Write {{{{ and }}}} for literal braces.",
+            issues.len()
+        );
+    }
 
-    let type_defs = generator::generate_type_definitions(&all_translations, &config.base_locale)
-        .context("Failed to generate type definitions")?;
+    // Create output directory
+    let output_dir = Path::new(&config.output_directory);
+    std::fs::create_dir_all(output_dir).context("Failed to create output directory")?;
 
-    let types_file = types_dir.join("Translations.d.luau");
-    std::fs::write(&types_file, type_defs).context("Failed to write type definitions")?;
+    let mut generated = match config.luau_output {
+        config::LuauOutput::Classic => {
+            write_classic_module(&config, output_dir, &all_translations, &variants)?
+        }
+        config::LuauOutput::Strict => {
+            write_strict_module(&config, output_dir, &all_translations, &variants)?
+        }
+    };
 
-    println!("{} Generated {}", "✓".green(), types_file.display());
+    // Locale chunks required by the embedded runtime
+    if config.embedded_chunks != config::EmbeddedChunks::Single {
+        generated.push(write_locale_modules(
+            &config,
+            output_dir,
+            &all_translations,
+        )?);
+    }
 
     // Generate CSV for Roblox Cloud
     let csv_content = generator::generate_csv(
@@ -133,7 +201,7 @@ pub fn build(config_path: &Path) -> Result<()> {
     )
     .context("Failed to generate CSV")?;
 
-    let csv_file = output_dir.join("roblox_upload.csv");
+    let csv_file = output_dir.join(upload_csv_name(&config));
     std::fs::write(&csv_file, csv_content).context("Failed to write CSV file")?;
 
     println!("{} Generated {}", "✓".green(), csv_file.display());
@@ -144,12 +212,124 @@ pub fn build(config_path: &Path) -> Result<()> {
     println!("  Locales: {}", config.supported_locales.join(", "));
     println!();
     println!("Generated files:");
-    println!("  • {} - Main translation module", output_file.display());
-    println!(
-        "  • {} - Type definitions for autocomplete",
-        types_file.display()
-    );
+    for (file, description) in &generated {
+        println!("  • {} - {}", file.display(), description);
+    }
     println!("  • {} - CSV for Roblox Cloud upload", csv_file.display());
 
     Ok(())
 }
+
+/// Name of the CSV for Roblox Cloud, prefixed with the namespace if there is one
+fn upload_csv_name(config: &config::Config) -> String {
+    match &config.namespace {
+        Some(namespace) => format!("{}_roblox_upload.csv", namespace),
+        None => "roblox_upload.csv".to_string(),
+    }
+}
+
+/// Write `Translations.lua` (named after the namespace, if set) and its type
+/// definitions, returning the files written
+fn write_classic_module(
+    config: &config::Config,
+    output_dir: &Path,
+    translations: &[parser::Translation],
+    variants: &[parser::Variant],
+) -> Result<Vec<(PathBuf, &'static str)>> {
+    // Generate Luau code with analytics config, variants and the configured runtime
+    let luau_code = generator::generate_luau_for_config(translations, config, variants)
+        .context("Failed to generate Luau code")?;
+
+    let output_file = output_dir.join(format!("{}.lua", config.module_name()));
+    std::fs::write(&output_file, luau_code).context("Failed to write Luau file")?;
+
+    println!("{} Generated {}", "✓".green(), output_file.display());
+
+    // Generate type definitions
+    let types_dir = output_dir.join("types");
+    std::fs::create_dir_all(&types_dir).context("Failed to create types directory")?;
+
+    let type_defs = generator::generate_type_definitions_for_module(
+        translations,
+        &config.base_locale,
+        variants,
+        config.module_name(),
+        &config.formatters,
+    )
+    .context("Failed to generate type definitions")?;
+
+    let types_file = types_dir.join(format!("{}.d.luau", config.module_name()));
+    std::fs::write(&types_file, type_defs).context("Failed to write type definitions")?;
+
+    println!("{} Generated {}", "✓".green(), types_file.display());
+
+    Ok(vec![
+        (output_file, "Main translation module"),
+        (types_file, "Type definitions for autocomplete"),
+    ])
+}
+
+/// Write the embedded locale chunks, returning their folder
+///
+/// The folder is recreated so chunks of removed locales or namespaces don't linger.
+fn write_locale_modules(
+    config: &config::Config,
+    output_dir: &Path,
+    translations: &[parser::Translation],
+) -> Result<(PathBuf, &'static str)> {
+    let modules = generator::generate_locale_modules(
+        translations,
+        &config.base_locale,
+        &config.supported_locales,
+        config.embedded_chunks,
+        config.module_name(),
+        config.luau_output == config::LuauOutput::Strict,
+        &config.formatters,
+    )
+    .context("Failed to generate locale modules")?;
+
+    let modules_dir = output_dir.join(generator::locale_modules_dir(config.module_name()));
+    if modules_dir.exists() {
+        std::fs::remove_dir_all(&modules_dir).context("Failed to clear locale modules")?;
+    }
+
+    for (path, code) in &modules {
+        let file = output_dir.join(path);
+        if let Some(parent) = file.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create locale modules directory")?;
+        }
+        std::fs::write(&file, code)
+            .with_context(|| format!("Failed to write locale module: {}", file.display()))?;
+    }
+
+    println!(
+        "{} Generated {} locale modules in {}",
+        "✓".green(),
+        modules.len(),
+        modules_dir.display()
+    );
+
+    Ok((
+        modules_dir,
+        "Locale modules, required when a locale is used",
+    ))
+}
+
+/// Write the `--!strict` `Translations.luau` module (named after the namespace,
+/// if set), returning the files written
+fn write_strict_module(
+    config: &config::Config,
+    output_dir: &Path,
+    translations: &[parser::Translation],
+    variants: &[parser::Variant],
+) -> Result<Vec<(PathBuf, &'static str)>> {
+    let luau_code = generator::generate_strict_luau(translations, config, variants)
+        .context("Failed to generate Luau code")?;
+
+    let output_file = output_dir.join(format!("{}.luau", config.module_name()));
+    std::fs::write(&output_file, luau_code).context("Failed to write Luau file")?;
+
+    println!("{} Generated {}", "✓".green(), output_file.display());
+
+    Ok(vec![(output_file, "Strictly typed translation module")])
+}
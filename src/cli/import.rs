@@ -3,7 +3,7 @@ use colored::Colorize;
 use std::collections::HashMap;
 use std::path::Path;
 
-use crate::{config, generator, utils::flatten};
+use crate::{config, generator, parser};
 
 /// Import translations from a Roblox CSV file
 pub fn import_csv(csv_path: &Path, config_path: &Path) -> Result<()> {
@@ -27,7 +27,8 @@ pub fn import_csv(csv_path: &Path, config_path: &Path) -> Result<()> {
         .context(format!("Failed to read {}", csv_path.display()))?;
 
     // Parse CSV
-    let translations = generator::parse_csv(&csv_content).context("Failed to parse CSV")?;
+    let translations =
+        generator::parse_csv(&csv_content, &config.base_locale).context("Failed to parse CSV")?;
 
     if translations.is_empty() {
         println!("{} No translations found in CSV", "⚠".yellow());
@@ -41,8 +42,8 @@ pub fn import_csv(csv_path: &Path, config_path: &Path) -> Result<()> {
     );
 
     // Group translations by locale
-    let mut by_locale: HashMap<String, Vec<&crate::parser::Translation>> = HashMap::new();
-    for translation in &translations {
+    let mut by_locale: HashMap<String, Vec<crate::parser::Translation>> = HashMap::new();
+    for translation in translations {
         by_locale
             .entry(translation.locale.clone())
             .or_default()
@@ -53,31 +54,26 @@ pub fn import_csv(csv_path: &Path, config_path: &Path) -> Result<()> {
     let input_dir = Path::new(&config.input_directory);
     std::fs::create_dir_all(input_dir).context("Failed to create input directory")?;
 
-    // Convert each locale to JSON and save
-    let locale_count = by_locale.len();
-    for (locale, locale_translations) in by_locale {
-        // Create flat map
-        let mut flat_map: HashMap<String, String> = HashMap::new();
-        for translation in locale_translations {
-            flat_map.insert(translation.key.clone(), translation.value.clone());
-        }
-
-        // Unflatten to nested JSON
-        let json = flatten::unflatten_to_json(&flat_map);
-
-        // Write to file
-        let output_path = input_dir.join(format!("{}.json", locale));
-        let json_string =
-            serde_json::to_string_pretty(&json).context("Failed to serialize JSON")?;
-
-        std::fs::write(&output_path, json_string)
-            .context(format!("Failed to write {}", output_path.display()))?;
+    // Merge each locale into its source files, keeping their key order
+    let mut locales: Vec<(String, Vec<crate::parser::Translation>)> =
+        by_locale.into_iter().collect();
+    locales.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let locale_count = locales.len();
+    for (locale, locale_translations) in locales {
+        parser::write_locale_translations(
+            input_dir,
+            &locale,
+            &config.base_locale,
+            &locale_translations,
+        )
+        .context(format!("Failed to write translations for {}", locale))?;
 
         println!(
-            "{} Created {} ({} keys)",
+            "{} Wrote {} ({} keys)",
             "✓".green(),
-            output_path.display(),
-            flat_map.len()
+            locale,
+            locale_translations.len()
         );
     }
 
@@ -87,7 +83,7 @@ pub fn import_csv(csv_path: &Path, config_path: &Path) -> Result<()> {
     println!();
     println!("Next steps:");
     println!(
-        "  1. Review the generated JSON files in {}/",
+        "  1. Review the translation files in {}/",
         config.input_directory
     );
     println!("  2. Run 'roblox-slang build' to generate Luau code");
@@ -1,4 +1,5 @@
 use crate::config;
+use crate::roblox::types::CloudSyncError;
 use crate::roblox::{AuthConfig, RobloxCloudClient, SyncOrchestrator};
 use crate::{parser, validator};
 use anyhow::{Context, Result};
@@ -34,31 +35,24 @@ pub async fn upload(table_id: Option<String>, dry_run: bool, skip_validation: bo
         let mut all_translations = Vec::new();
         let mut parse_errors = Vec::new();
 
+        let input_dir = Path::new(&config.input_directory);
+
         for locale in &config.supported_locales {
-            // Try JSON first
-            let json_path = Path::new(&config.input_directory).join(format!("{}.json", locale));
-            let yaml_path = Path::new(&config.input_directory).join(format!("{}.yaml", locale));
-            let yml_path = Path::new(&config.input_directory).join(format!("{}.yml", locale));
-
-            let translations = if json_path.exists() {
-                match parser::parse_json_file(&json_path, locale) {
-                    Ok(t) => t,
-                    Err(e) => {
-                        parse_errors.push(format!("Failed to parse {} JSON: {}", locale, e));
-                        continue;
-                    }
+            let translations = match parser::load_locale_translations(
+                input_dir,
+                locale,
+                &config.base_locale,
+                config.scalar_values,
+            ) {
+                Ok(Some(t)) => t,
+                Ok(None) => {
+                    parse_errors.push(format!("No translation file found for locale: {}", locale));
+                    continue;
                 }
-            } else if let Some(path) = [&yaml_path, &yml_path].iter().find(|p| p.exists()) {
-                match parser::parse_yaml_file(path, locale) {
-                    Ok(t) => t,
-                    Err(e) => {
-                        parse_errors.push(format!("Failed to parse {} YAML: {}", locale, e));
-                        continue;
-                    }
+                Err(e) => {
+                    parse_errors.push(format!("Failed to parse {}: {:#}", locale, e));
+                    continue;
                 }
-            } else {
-                parse_errors.push(format!("No translation file found for locale: {}", locale));
-                continue;
             };
 
             all_translations.extend(translations);
@@ -86,11 +80,31 @@ pub async fn upload(table_id: Option<String>, dry_run: bool, skip_validation: bo
             anyhow::bail!("No translations found");
         }
 
+        // Malformed placeholders would be uploaded as broken strings
+        let issues =
+            validator::placeholders::placeholder_issues(&all_translations, &config.formatters);
+        if !issues.is_empty() {
+            eprintln!("{} Invalid placeholders:", "✗".red());
+            return Err(validation_error(&issues));
+        }
+
+        // Links are resolved before upload, so they must all resolve
+        let issues = validator::links::link_issues(
+            &all_translations,
+            &config.base_locale,
+            &config.fallbacks,
+        );
+        if !issues.is_empty() {
+            eprintln!("{} Broken links:", "✗".red());
+            return Err(validation_error(&issues));
+        }
+
         // Check for missing keys (critical for upload)
-        let missing = validator::missing::detect_missing_keys(
+        let missing = validator::missing::detect_missing_keys_with_fallbacks(
             &all_translations,
             &config.base_locale,
             &config.supported_locales,
+            &config.fallbacks,
         );
 
         if !missing.is_empty() {
@@ -98,11 +112,14 @@ pub async fn upload(table_id: Option<String>, dry_run: bool, skip_validation: bo
                 "{} Missing translations detected (this may cause incomplete localization):",
                 "⚠".yellow()
             );
+            let locations =
+                validator::missing::base_key_locations(&all_translations, &config.base_locale);
             for (locale, keys) in &missing {
                 eprintln!("  {} missing in '{}':", keys.len(), locale.yellow());
                 // Show first 5 missing keys
                 for key in keys.iter().take(5) {
-                    eprintln!("    - {}", key);
+                    let location = locations.get(key.as_str()).copied();
+                    eprintln!("    - {}", validator::format_diagnostic(location, key));
                 }
                 if keys.len() > 5 {
                     eprintln!("    ... and {} more", keys.len() - 5);
@@ -185,3 +202,29 @@ pub async fn upload(table_id: Option<String>, dry_run: bool, skip_validation: bo
 
     Ok(())
 }
+
+/// Print validation issues and return the error for the first one, as a
+/// `CloudSyncError::ValidationError` pointing at its entry when it has a location
+fn validation_error(issues: &[(&parser::Translation, String)]) -> anyhow::Error {
+    for (translation, message) in issues {
+        eprintln!(
+            "  - {}",
+            validator::format_diagnostic(translation.location.as_ref(), message)
+        );
+    }
+    eprintln!(
+        "\n{} Use --skip-validation to bypass validation",
+        "Hint:".yellow()
+    );
+
+    let (translation, message) = &issues[0];
+    match &translation.location {
+        Some(location) => CloudSyncError::ValidationError {
+            file: location.file.display().to_string(),
+            line: location.line,
+            reason: message.clone(),
+        }
+        .into(),
+        None => anyhow::anyhow!("Translation validation failed: {}", message),
+    }
+}
@@ -2,17 +2,33 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use std::path::Path;
 
-use crate::{config, parser, validator};
+use crate::{config, parser, utils, validator};
+
+/// Checks to run when validating translations
+#[derive(Debug, Clone, Copy)]
+pub struct Checks {
+    /// Keys missing from a locale and its fallbacks
+    pub missing: bool,
+    /// Keys the source directory never uses
+    pub unused: bool,
+    /// Duplicate keys and conflicts
+    pub conflicts: bool,
+    /// Overrides for unknown keys, unsupported locales and parameter drift
+    pub overrides: bool,
+    /// Plural forms each locale's plural rules need
+    pub plurals: bool,
+    /// Select cases across locales
+    pub selects: bool,
+    /// Unknown format specifiers and unbalanced braces
+    pub placeholders: bool,
+    /// `@:key` links to missing keys or back to themselves
+    pub links: bool,
+    /// Coverage report per locale
+    pub coverage: bool,
+}
 
 /// Validate translations
-pub fn validate(
-    config_path: &Path,
-    check_missing: bool,
-    check_unused: bool,
-    check_conflicts: bool,
-    show_coverage: bool,
-    source_dir: Option<&Path>,
-) -> Result<()> {
+pub fn validate(config_path: &Path, checks: Checks, source_dir: Option<&Path>) -> Result<()> {
     println!("{} Validating translations...", "→".blue());
 
     // Load config
@@ -21,26 +37,17 @@ pub fn validate(
     // Parse all translations
     let mut all_translations = Vec::new();
 
+    let input_dir = Path::new(&config.input_directory);
+
     for locale in &config.supported_locales {
-        // Try JSON first
-        let json_path = Path::new(&config.input_directory).join(format!("{}.json", locale));
-
-        // Try YAML if JSON doesn't exist
-        let yaml_path = Path::new(&config.input_directory).join(format!("{}.yaml", locale));
-
-        let yml_path = Path::new(&config.input_directory).join(format!("{}.yml", locale));
-
-        let translations = if json_path.exists() {
-            parser::parse_json_file(&json_path, locale)
-                .context(format!("Failed to parse JSON for {}", locale))?
-        } else if yaml_path.exists() {
-            parser::parse_yaml_file(&yaml_path, locale)
-                .context(format!("Failed to parse YAML for {}", locale))?
-        } else if yml_path.exists() {
-            parser::parse_yaml_file(&yml_path, locale)
-                .context(format!("Failed to parse YAML for {}", locale))?
-        } else {
-            continue;
+        let translations = match parser::load_locale_translations(
+            input_dir,
+            locale,
+            &config.base_locale,
+            config.scalar_values,
+        )? {
+            Some(translations) => translations,
+            None => continue,
         };
 
         all_translations.extend(translations);
@@ -54,29 +61,33 @@ pub fn validate(
     let mut has_issues = false;
 
     // Check for missing keys
-    if check_missing {
+    if checks.missing {
         println!("\n{} Checking for missing translations...", "→".blue());
-        let missing = validator::missing::detect_missing_keys(
+        let missing = validator::missing::detect_missing_keys_with_fallbacks(
             &all_translations,
             &config.base_locale,
             &config.supported_locales,
+            &config.fallbacks,
         );
 
         if missing.is_empty() {
             println!("{} No missing translations found", "✓".green());
         } else {
             has_issues = true;
+            let locations =
+                validator::missing::base_key_locations(&all_translations, &config.base_locale);
             for (locale, keys) in &missing {
                 println!("\n{} Missing in '{}':", "✗".red(), locale.yellow());
                 for key in keys {
-                    println!("  - {}", key);
+                    let location = locations.get(key.as_str()).copied();
+                    println!("  - {}", validator::format_diagnostic(location, key));
                 }
             }
         }
     }
 
     // Check for conflicts
-    if check_conflicts {
+    if checks.conflicts {
         println!("\n{} Checking for conflicts...", "→".blue());
         let conflicts = validator::conflicts::detect_conflicts(&all_translations);
 
@@ -91,8 +102,114 @@ pub fn validate(
         }
     }
 
+    // Check overrides against the source files
+    if checks.overrides {
+        println!("\n{} Checking overrides...", "→".blue());
+
+        match config.overrides.as_ref().filter(|o| o.enabled) {
+            Some(override_config) => {
+                let overrides = parser::parse_override_file(Path::new(&override_config.file))
+                    .context("Failed to parse overrides")?;
+                let issues = validator::overrides::check_overrides(
+                    &overrides,
+                    &all_translations,
+                    &config.base_locale,
+                    &config.supported_locales,
+                );
+
+                if issues.is_empty() {
+                    println!("{} No override problems found", "✓".green());
+                } else {
+                    has_issues = true;
+                    println!("\n{} Override problems:", "✗".red());
+                    for issue in &issues {
+                        println!("  - {}", issue);
+                    }
+                }
+            }
+            None => println!(
+                "{} Skipping overrides check (overrides are not enabled)",
+                "⚠".yellow()
+            ),
+        }
+    }
+
+    // Check plural forms against each locale's rules
+    if checks.plurals {
+        println!("\n{} Checking plural forms...", "→".blue());
+        let issues =
+            validator::plurals::check_plural_forms(&all_translations, &config.supported_locales);
+
+        if issues.is_empty() {
+            println!("{} All plural forms present", "✓".green());
+        } else {
+            has_issues = true;
+            println!("\n{} Incomplete plural forms:", "✗".red());
+            for issue in &issues {
+                println!("  - {}", issue);
+            }
+        }
+    }
+
+    // Check select cases across locales
+    if checks.selects {
+        println!("\n{} Checking select cases...", "→".blue());
+        let issues = validator::selects::check_select_cases(
+            &all_translations,
+            &config.base_locale,
+            &config.supported_locales,
+        );
+
+        if issues.is_empty() {
+            println!("{} All select cases consistent", "✓".green());
+        } else {
+            has_issues = true;
+            println!("\n{} Inconsistent select cases:", "✗".red());
+            for issue in &issues {
+                println!("  - {}", issue);
+            }
+        }
+    }
+
+    // Check placeholders and format specifiers
+    if checks.placeholders {
+        println!("\n{} Checking placeholders...", "→".blue());
+        let issues =
+            validator::placeholders::check_placeholders(&all_translations, &config.formatters);
+
+        if issues.is_empty() {
+            println!("{} All placeholders valid", "✓".green());
+        } else {
+            has_issues = true;
+            println!("\n{} Invalid placeholders:", "✗".red());
+            for issue in &issues {
+                println!("  - {}", issue);
+            }
+        }
+    }
+
+    // Check links between keys
+    if checks.links {
+        println!("\n{} Checking links...", "→".blue());
+        let issues = validator::links::check_links(
+            &all_translations,
+            &config.base_locale,
+            &config.fallbacks,
+        );
+
+        if issues.is_empty() {
+            println!("{} All links resolve", "✓".green());
+        } else {
+            has_issues = true;
+            println!("\n{} Broken links:", "✗".red());
+            for issue in &issues {
+                println!("  - {}", issue);
+            }
+        }
+    }
+
     // Check for unused keys
-    if check_unused {
+    if checks.unused {
         if let Some(src_dir) = source_dir {
             println!(
                 "\n{} Checking for unused keys in {}...",
@@ -100,10 +217,11 @@ pub fn validate(
                 src_dir.display()
             );
 
-            // Get all unique keys
+            // Get all unique keys, except those other values link to
+            let linked = utils::links::linked_keys(&all_translations);
             let keys: Vec<String> = all_translations
                 .iter()
-                .filter(|t| t.locale == config.base_locale)
+                .filter(|t| t.locale == config.base_locale && !linked.contains(&t.key))
                 .map(|t| t.key.clone())
                 .collect();
 
@@ -128,7 +246,7 @@ pub fn validate(
     }
 
     // Show coverage report
-    if show_coverage {
+    if checks.coverage {
         println!("\n{} Translation Coverage Report", "→".blue());
         println!();
 
@@ -5,7 +5,7 @@ use std::path::Path;
 use std::sync::mpsc::channel;
 use std::time::Duration;
 
-use crate::cli;
+use crate::{cli, parser};
 
 /// Watch for file changes and rebuild automatically
 pub fn watch(config_path: &Path) -> Result<()> {
@@ -70,7 +70,7 @@ pub fn watch(config_path: &Path) -> Result<()> {
                 let should_rebuild = event.paths.iter().any(|path| {
                     path.extension()
                         .and_then(|ext| ext.to_str())
-                        .map(|ext| ext == "json" || ext == "yaml" || ext == "yml")
+                        .map(|ext| parser::SOURCE_EXTENSIONS.contains(&ext) || ext == "csv")
                         .unwrap_or(false)
                 });
 
@@ -1,4 +1,4 @@
-use super::Config;
+use super::{Config, EmbeddedChunks, LuauOutput, Runtime, ScalarPolicy};
 
 impl Default for Config {
     fn default() -> Self {
@@ -11,6 +11,12 @@ impl Default for Config {
             overrides: None,
             analytics: None,
             cloud: None,
+            scalar_values: ScalarPolicy::default(),
+            luau_output: LuauOutput::default(),
+            runtime: Runtime::default(),
+            embedded_chunks: EmbeddedChunks::default(),
+            fallbacks: Default::default(),
+            formatters: Default::default(),
         }
     }
 }
@@ -31,6 +37,11 @@ mod tests {
         assert!(config.overrides.is_none());
         assert!(config.analytics.is_none());
         assert!(config.cloud.is_none());
+        assert_eq!(config.scalar_values, ScalarPolicy::Stringify);
+        assert_eq!(config.luau_output, LuauOutput::Classic);
+        assert_eq!(config.runtime, Runtime::LocalizationService);
+        assert_eq!(config.embedded_chunks, EmbeddedChunks::Single);
+        assert!(config.fallbacks.is_empty());
     }
 
     #[test]
@@ -192,6 +192,16 @@ pub fn create_default_overrides(path: &Path) -> Result<()> {
 #
 # id:
 #   ui.buttons.buy: "Beli Sekarang!"
+
+# Example variants (switched at runtime with t:setVariant("halloween")):
+# variants:
+#   halloween:
+#     start: 2025-10-20        # optional, UTC
+#     end: 2025-11-01          # optional, inclusive
+#     segments: [vip]          # optional, see t:setSegments()
+#     translations:
+#       en:
+#         ui.buttons.buy: "Trick or Treat!"
 "#;
 
     std::fs::write(path, yaml).map_err(|e| {
@@ -405,4 +415,45 @@ analytics:
         assert!(!analytics.track_usage);
         assert_eq!(analytics.callback, Some("game.Analytics.Track".to_string()));
     }
+
+    #[test]
+    fn test_load_config_with_formatters() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+
+        let yaml = r#"
+base_locale: en
+supported_locales:
+  - en
+formatters:
+  health:
+    module: game.ReplicatedStorage.Formatters
+    function: heartBar
+    input: number
+  gametime:
+    module: game.ReplicatedStorage.GameTime
+    function: format
+"#;
+        fs::write(&config_path, yaml).unwrap();
+
+        let config = load_config(&config_path).unwrap();
+        assert_eq!(config.formatters.len(), 2);
+        assert_eq!(
+            config.formatters["health"],
+            FormatterConfig {
+                module: "game.ReplicatedStorage.Formatters".to_string(),
+                function: "heartBar".to_string(),
+                input: "number".to_string(),
+            }
+        );
+        assert_eq!(config.formatters["gametime"].input, "unknown");
+
+        fs::write(
+            &config_path,
+            yaml.replace("function: heartBar", "function: heart-bar"),
+        )
+        .unwrap();
+        let error = format!("{:#}", load_config(&config_path).unwrap_err());
+        assert!(error.contains("formatter 'health' has an invalid function name 'heart-bar'"));
+    }
 }
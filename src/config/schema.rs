@@ -1,7 +1,17 @@
 use crate::roblox::types::CloudConfig;
-use crate::utils::locales;
+use crate::utils::{format, locales};
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Name of the generated module and its types when no `namespace` is set
+pub const DEFAULT_MODULE_NAME: &str = "Translations";
+
+/// Reserved words a namespace can't use
+const LUAU_KEYWORDS: &[&str] = &[
+    "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "if", "in", "local",
+    "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
+];
 
 /// Main configuration structure for Roblox Slang
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -20,7 +30,7 @@ pub struct Config {
     #[serde(default = "default_output_directory")]
     pub output_directory: String,
 
-    /// Optional namespace prefix for generated code
+    /// Optional name of the generated module and its types (default `Translations`)
     #[serde(default)]
     pub namespace: Option<String>,
 
@@ -35,6 +45,96 @@ pub struct Config {
     /// Cloud sync configuration
     #[serde(default)]
     pub cloud: Option<CloudConfig>,
+
+    /// How numbers and booleans in translation files are handled
+    #[serde(default)]
+    pub scalar_values: ScalarPolicy,
+
+    /// Shape of the generated Luau module
+    #[serde(default)]
+    pub luau_output: LuauOutput,
+
+    /// Where the generated module reads translations from at runtime
+    #[serde(default)]
+    pub runtime: Runtime,
+
+    /// How embedded strings are split into ModuleScripts
+    #[serde(default)]
+    pub embedded_chunks: EmbeddedChunks,
+
+    /// Fallback locales tried, in order, before the base locale
+    /// (e.g. `pt: [es]`, `zh-tw: [zh-cn]`)
+    #[serde(default)]
+    pub fallbacks: BTreeMap<String, Vec<String>>,
+
+    /// Custom format specifiers (`{hp:health}`), by name
+    #[serde(default)]
+    pub formatters: BTreeMap<String, FormatterConfig>,
+}
+
+/// A custom format specifier, formatted by a function of a ModuleScript
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FormatterConfig {
+    /// Path of the ModuleScript (e.g. `game.ReplicatedStorage.Formatters`)
+    pub module: String,
+
+    /// Function of the module, called with the value and the locale
+    pub function: String,
+
+    /// Luau type of the parameter in the type definitions
+    #[serde(default = "default_formatter_input")]
+    pub input: String,
+}
+
+/// Handling of non-string leaves (numbers, booleans) in translation files
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScalarPolicy {
+    /// Convert the value to its text form (`8` -> `"8"`)
+    #[default]
+    Stringify,
+
+    /// Report the value as an error
+    Reject,
+}
+
+/// Shape of the generated Luau module
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LuauOutput {
+    /// `Translations.lua` plus `types/Translations.d.luau` type definitions
+    #[default]
+    Classic,
+
+    /// A single `--!strict` `Translations.luau` module with inline types
+    Strict,
+}
+
+/// Where the generated module reads translations from at runtime
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Runtime {
+    /// `LocalizationService` translators, backed by the uploaded localization table
+    #[default]
+    LocalizationService,
+
+    /// Strings for every supported locale embedded in the module itself
+    Embedded,
+}
+
+/// How embedded strings are split into ModuleScripts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddedChunks {
+    /// Every locale inside the main module
+    #[default]
+    Single,
+
+    /// One ModuleScript per locale, required when the locale is first used
+    Locale,
+
+    /// One ModuleScript per locale and top-level namespace
+    Namespace,
 }
 
 /// Override configuration
@@ -70,6 +170,11 @@ pub struct AnalyticsConfig {
 }
 
 impl Config {
+    /// Name of the generated module and its types: the namespace, or `Translations`
+    pub fn module_name(&self) -> &str {
+        self.namespace.as_deref().unwrap_or(DEFAULT_MODULE_NAME)
+    }
+
     /// Validate configuration values
     pub fn validate(&self) -> Result<()> {
         // Validate base_locale
@@ -190,6 +295,99 @@ impl Config {
             );
         }
 
+        // Validate fallback chains
+        for (locale, chain) in &self.fallbacks {
+            for fallback in std::iter::once(locale).chain(chain) {
+                if !self.supported_locales.contains(fallback) {
+                    bail!(
+                        "Configuration error: fallback locale '{}' must be included in supported_locales\n\
+                         \n\
+                         Current supported_locales: [{}]\n\
+                         \n\
+                         Hint: Fallbacks can only use locales the game ships, e.g.\n\
+                         fallbacks:\n\
+                           pt: [es]",
+                        fallback,
+                        self.supported_locales.join(", ")
+                    );
+                }
+            }
+            if chain.contains(locale) {
+                bail!(
+                    "Configuration error: locale '{}' lists itself as a fallback\n\
+                     \n\
+                     Hint: List only the other locales to try, in order.\n\
+                     The base locale is always tried last.",
+                    locale
+                );
+            }
+        }
+
+        // Validate custom formatters
+        format::validate_formatters(&self.formatters)?;
+
+        // Chunks only exist for embedded strings
+        if self.embedded_chunks != EmbeddedChunks::Single && self.runtime != Runtime::Embedded {
+            bail!(
+                "Configuration error: embedded_chunks requires runtime: embedded\n\
+                 \n\
+                 With runtime: localization_service, strings come from the uploaded\n\
+                 localization table and are not split into modules.\n\
+                 \n\
+                 Hint: Add 'runtime: embedded' or remove embedded_chunks."
+            );
+        }
+
+        // The namespace names the generated module, its types and folders
+        if let Some(ref namespace) = self.namespace {
+            if namespace.is_empty() {
+                bail!(
+                    "Configuration error: namespace cannot be empty\n\
+                     \n\
+                     Either remove the namespace field or provide a valid value.\n\
+                     Example: namespace: MyGame"
+                );
+            }
+
+            if !namespace
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                bail!(
+                    "Configuration error: namespace '{}' contains invalid characters\n\
+                     \n\
+                     Namespace can only contain:\n\
+                     - Letters (a-z, A-Z)\n\
+                     - Digits (0-9)\n\
+                     - Underscores (_)\n\
+                     \n\
+                     Example: namespace: MyGame_Translations",
+                    namespace
+                );
+            }
+
+            if namespace.starts_with(|c: char| c.is_ascii_digit()) {
+                bail!(
+                    "Configuration error: namespace '{}' cannot start with a digit\n\
+                     \n\
+                     Luau identifiers cannot start with digits.\n\
+                     Example: '{}' → 'Game{}'",
+                    namespace,
+                    namespace,
+                    namespace
+                );
+            }
+
+            if LUAU_KEYWORDS.contains(&namespace.as_str()) {
+                bail!(
+                    "Configuration error: namespace '{}' is a Luau keyword\n\
+                     \n\
+                     Example: namespace: MyGame",
+                    namespace
+                );
+            }
+        }
+
         Ok(())
     }
 }
@@ -210,6 +408,10 @@ fn default_true() -> bool {
     true
 }
 
+fn default_formatter_input() -> String {
+    "unknown".to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,6 +427,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         assert!(config.validate().is_ok());
@@ -241,6 +444,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = config.validate();
@@ -262,6 +466,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = config.validate();
@@ -283,6 +488,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = config.validate();
@@ -304,6 +510,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = config.validate();
@@ -325,6 +532,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = config.validate();
@@ -346,6 +554,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = config.validate();
@@ -367,6 +576,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = config.validate();
@@ -377,6 +587,48 @@ mod tests {
             .contains("cannot be the same"));
     }
 
+    #[test]
+    fn test_config_validate_fallbacks() {
+        let mut config = Config {
+            supported_locales: vec!["en".to_string(), "es".to_string(), "pt".to_string()],
+            fallbacks: BTreeMap::from([("pt".to_string(), vec!["es".to_string()])]),
+            ..Config::default()
+        };
+        assert!(config.validate().is_ok());
+
+        config.fallbacks = BTreeMap::from([("pt".to_string(), vec!["fr".to_string()])]);
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("fallback locale 'fr' must be included in supported_locales"));
+
+        config.fallbacks = BTreeMap::from([("pt".to_string(), vec!["pt".to_string()])]);
+        assert!(config
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("locale 'pt' lists itself as a fallback"));
+    }
+
+    #[test]
+    fn test_config_validate_chunks_without_embedded_runtime() {
+        let mut config = Config {
+            embedded_chunks: EmbeddedChunks::Locale,
+            ..Config::default()
+        };
+
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("embedded_chunks requires runtime: embedded"));
+
+        config.runtime = Runtime::Embedded;
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_config_with_namespace() {
         let config = Config {
@@ -388,12 +640,36 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         assert!(config.validate().is_ok());
         assert_eq!(config.namespace, Some("MyGame".to_string()));
     }
 
+    #[test]
+    fn test_config_validate_namespace_identifier() {
+        for (namespace, error) in [
+            ("my-game", "contains invalid characters"),
+            ("1Game", "cannot start with a digit"),
+            ("", "namespace cannot be empty"),
+            ("end", "is a Luau keyword"),
+        ] {
+            let config = Config {
+                namespace: Some(namespace.to_string()),
+                ..Config::default()
+            };
+            let err = config.validate().unwrap_err().to_string();
+            assert!(err.contains(error), "{}: {}", namespace, err);
+        }
+
+        let config = Config {
+            namespace: Some("Game_Strings2".to_string()),
+            ..Config::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_override_config_defaults() {
         let override_config = OverrideConfig {
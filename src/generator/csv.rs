@@ -1,4 +1,4 @@
-use crate::parser::Translation;
+use crate::parser::{CsvRow, CsvTable, Translation};
 use anyhow::Result;
 use std::collections::HashMap;
 
@@ -8,27 +8,35 @@ pub fn generate_csv(
     base_locale: &str,
     locales: &[String],
 ) -> Result<String> {
-    let mut csv = String::new();
-
     // Header row
-    csv.push_str("Source,Context,Key");
-    for locale in locales {
-        csv.push(',');
-        csv.push_str(locale);
-    }
-    csv.push('\n');
+    let mut headers: Vec<String> = ["Source", "Context", "Key", "Example"]
+        .iter()
+        .map(|h| h.to_string())
+        .collect();
+    headers.extend(locales.iter().cloned());
+
+    let mut table = CsvTable::new(headers);
 
-    // Group translations by key
-    let mut translation_map: HashMap<String, (Option<String>, HashMap<String, String>)> =
-        HashMap::new();
+    // Group translations by key: (context, description, locale -> value)
+    type Row = (Option<String>, Option<String>, HashMap<String, String>);
+    let mut translation_map: HashMap<String, Row> = HashMap::new();
 
     for translation in translations {
         let entry = translation_map
             .entry(translation.key.clone())
-            .or_insert_with(|| (translation.context.clone(), HashMap::new()));
+            .or_insert_with(|| (None, None, HashMap::new()));
+
+        // Metadata normally lives in the base locale; fall back to any locale that has it
+        let is_base = translation.locale == base_locale;
+        if translation.context.is_some() && (is_base || entry.0.is_none()) {
+            entry.0 = translation.context.clone();
+        }
+        if translation.description.is_some() && (is_base || entry.1.is_none()) {
+            entry.1 = translation.description.clone();
+        }
 
         entry
-            .1
+            .2
             .insert(translation.locale.clone(), translation.value.clone());
     }
 
@@ -38,189 +46,100 @@ pub fn generate_csv(
 
     // Generate rows
     for key in keys {
-        let (context, locale_values) = translation_map.get(&key).unwrap();
-
-        // Source column (base locale value)
-        let source = locale_values
-            .get(base_locale)
-            .map(|s| escape_csv_value(s))
-            .unwrap_or_else(|| String::from("\"\""));
-
-        csv.push_str(&source);
-        csv.push(',');
-
-        // Context column (for disambiguation)
-        let context_str = context
-            .as_ref()
-            .map(|c| escape_csv_value(c))
-            .unwrap_or_else(|| String::from("\"\""));
-        csv.push_str(&context_str);
-        csv.push(',');
-
-        // Key column
-        csv.push_str(&escape_csv_value(&key));
+        let (context, description, locale_values) = translation_map.get(&key).unwrap();
+
+        let mut cells = vec![
+            // Source column (base locale value)
+            locale_values.get(base_locale).cloned().unwrap_or_default(),
+            // Context column (for disambiguation)
+            context.clone().unwrap_or_default(),
+            key.clone(),
+            // Example column (translator description)
+            description.clone().unwrap_or_default(),
+        ];
 
         // Locale columns
         for locale in locales {
-            csv.push(',');
-            let value = locale_values
-                .get(locale)
-                .map(|s| escape_csv_value(s))
-                .unwrap_or_else(|| String::from("\"\""));
-            csv.push_str(&value);
+            cells.push(locale_values.get(locale).cloned().unwrap_or_default());
         }
 
-        csv.push('\n');
+        table.rows.push(CsvRow { line: 0, cells });
     }
 
-    Ok(csv)
-}
-
-/// Escape CSV value (wrap in quotes and escape internal quotes)
-fn escape_csv_value(value: &str) -> String {
-    // Check if value needs escaping
-    let needs_escape =
-        value.contains('"') || value.contains(',') || value.contains('\n') || value.contains('\r');
-
-    if needs_escape || !value.is_empty() {
-        // Escape internal quotes by doubling them
-        let escaped = value.replace('"', "\"\"");
-        format!("\"{}\"", escaped)
-    } else {
-        String::from("\"\"")
-    }
+    table.to_csv()
 }
 
 /// Parse CSV file (for import/migration)
-pub fn parse_csv(content: &str) -> Result<Vec<Translation>> {
-    let mut translations = Vec::new();
-    let mut lines = content.lines();
-
-    // Parse header
-    let header = lines
-        .next()
-        .ok_or_else(|| anyhow::anyhow!("CSV file is empty"))?;
-    let headers = parse_csv_line(header);
-
-    if headers.len() < 3 {
-        anyhow::bail!("Invalid CSV header: expected at least Source,Context,Key columns");
+///
+/// Columns are matched by name, so both `Source,Context,Key,...` and Roblox's
+/// exported `Key,Source,Context,Example,...` layouts work. The `Source` column
+/// is read as `base_locale` when the table has no column of its own for it.
+pub fn parse_csv(content: &str, base_locale: &str) -> Result<Vec<Translation>> {
+    let table = CsvTable::parse(content)?;
+
+    let mut locales = table.locales();
+    if !locales.iter().any(|l| l == base_locale) && table.has_locale(base_locale, Some(base_locale))
+    {
+        locales.insert(0, base_locale.to_string());
     }
 
-    // Extract locale columns (skip Source, Context, Key)
-    let locales: Vec<String> = headers[3..].to_vec();
-
-    // Parse data rows
-    for line in lines {
-        if line.trim().is_empty() {
-            continue;
-        }
-
-        let values = parse_csv_line(line);
-
-        if values.len() < 3 {
-            log::warn!("Skipping invalid CSV row: {}", line);
-            continue;
-        }
-
-        let key = values[2].clone();
-        let context = if values[1].is_empty() {
-            None
-        } else {
-            Some(values[1].clone())
-        };
-
-        // Create translation for each locale
-        for (i, locale) in locales.iter().enumerate() {
-            let value_index = 3 + i;
-            if value_index < values.len() {
-                let value = &values[value_index];
-                if !value.is_empty() {
-                    translations.push(Translation {
-                        key: key.clone(),
-                        value: value.clone(),
-                        locale: locale.clone(),
-                        context: context.clone(),
-                    });
-                }
-            }
-        }
-    }
-
-    Ok(translations)
-}
-
-/// Parse a single CSV line (handles quoted values)
-fn parse_csv_line(line: &str) -> Vec<String> {
-    let mut values = Vec::new();
-    let mut current = String::new();
-    let mut in_quotes = false;
-    let mut chars = line.chars().peekable();
-
-    while let Some(ch) = chars.next() {
-        match ch {
-            '"' => {
-                if in_quotes {
-                    // Check for escaped quote
-                    if chars.peek() == Some(&'"') {
-                        current.push('"');
-                        chars.next();
-                    } else {
-                        in_quotes = false;
-                    }
-                } else {
-                    in_quotes = true;
-                }
-            }
-            ',' => {
-                if in_quotes {
-                    current.push(',');
-                } else {
-                    values.push(current.trim().to_string());
-                    current.clear();
-                }
-            }
-            _ => {
-                current.push(ch);
-            }
-        }
-    }
-
-    // Add last value
-    values.push(current.trim().to_string());
-
-    values
+    Ok(locales
+        .iter()
+        .flat_map(|locale| table.translations(locale, Some(base_locale), None))
+        .collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Serialize a single cell the way `generate_csv` does
+    fn write_cell(value: &str) -> String {
+        let mut table = CsvTable::new(vec!["Key".to_string(), "en".to_string()]);
+        table.rows.push(CsvRow {
+            line: 0,
+            cells: vec!["k".to_string(), value.to_string()],
+        });
+
+        let csv = table.to_csv().unwrap();
+        csv.strip_prefix("Key,en\nk,")
+            .and_then(|row| row.strip_suffix('\n'))
+            .unwrap()
+            .to_string()
+    }
+
+    /// Parse the cells of a single data row
+    fn read_cells(line: &str) -> Vec<String> {
+        let table = CsvTable::parse(&format!("Key,A,B,C\nk,{}\n", line)).unwrap();
+        table.rows[0].cells[1..].to_vec()
+    }
+
     #[test]
-    fn test_escape_csv_value() {
-        assert_eq!(escape_csv_value("Hello"), "\"Hello\"");
-        assert_eq!(escape_csv_value("Hello, World"), "\"Hello, World\"");
-        assert_eq!(escape_csv_value("Say \"Hi\""), "\"Say \"\"Hi\"\"\"");
+    fn test_write_cell_quotes_only_when_needed() {
+        assert_eq!(write_cell("Hello"), "Hello");
+        assert_eq!(write_cell("Hello, World"), "\"Hello, World\"");
+        assert_eq!(write_cell("Say \"Hi\""), "\"Say \"\"Hi\"\"\"");
     }
 
     #[test]
-    fn test_escape_csv_value_empty() {
-        assert_eq!(escape_csv_value(""), "\"\"");
+    fn test_write_cell_empty() {
+        assert_eq!(write_cell(""), "");
     }
 
     #[test]
-    fn test_escape_csv_value_newline() {
-        assert_eq!(escape_csv_value("Line1\nLine2"), "\"Line1\nLine2\"");
+    fn test_write_cell_newline() {
+        assert_eq!(write_cell("Line1\nLine2"), "\"Line1\nLine2\"");
     }
 
     #[test]
-    fn test_escape_csv_value_carriage_return() {
-        assert_eq!(escape_csv_value("Line1\rLine2"), "\"Line1\rLine2\"");
+    fn test_write_cell_carriage_return() {
+        assert_eq!(write_cell("Line1\rLine2"), "\"Line1\rLine2\"");
     }
 
     #[test]
-    fn test_escape_csv_value_multiple_quotes() {
+    fn test_write_cell_multiple_quotes() {
         assert_eq!(
-            escape_csv_value("\"Quote1\" and \"Quote2\""),
+            write_cell("\"Quote1\" and \"Quote2\""),
             "\"\"\"Quote1\"\" and \"\"Quote2\"\"\""
         );
     }
@@ -233,20 +152,25 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Beli".to_string(),
                 locale: "id".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
         let csv = generate_csv(&translations, "en", &["en".to_string(), "id".to_string()]).unwrap();
 
-        assert!(csv.contains("Source,Context,Key,en,id"));
-        assert!(csv.contains("\"Buy\""));
-        assert!(csv.contains("\"Beli\""));
+        assert!(csv.contains("Source,Context,Key,Example,en,id"));
+        assert!(csv.contains("Buy,,ui.button,,Buy,Beli\n"));
     }
 
     #[test]
@@ -257,18 +181,59 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: Some("Purchase button".to_string()),
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Beli".to_string(),
                 locale: "id".to_string(),
                 context: Some("Purchase button".to_string()),
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
         let csv = generate_csv(&translations, "en", &["en".to_string(), "id".to_string()]).unwrap();
 
-        assert!(csv.contains("\"Purchase button\""));
+        assert!(csv.contains("Buy,Purchase button,ui.button,,Buy,Beli\n"));
+    }
+
+    #[test]
+    fn test_generate_csv_with_description() {
+        let translations = vec![
+            Translation {
+                key: "ui.button".to_string(),
+                value: "Buy".to_string(),
+                locale: "en".to_string(),
+                context: Some("button".to_string()),
+                description: Some("Shop purchase button".to_string()),
+                max_length: Some(12),
+                location: None,
+            },
+            Translation {
+                key: "ui.button".to_string(),
+                value: "Beli".to_string(),
+                locale: "id".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+        ];
+
+        let csv = generate_csv(&translations, "en", &["en".to_string(), "id".to_string()]).unwrap();
+
+        assert!(csv.contains("Buy,button,ui.button,Shop purchase button,Buy,Beli\n"));
+
+        let parsed = parse_csv(&csv, "en").unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert!(parsed.iter().all(|t| {
+            t.context.as_deref() == Some("button")
+                && t.description.as_deref() == Some("Shop purchase button")
+        }));
     }
 
     #[test]
@@ -278,6 +243,9 @@ mod tests {
             value: "Buy".to_string(),
             locale: "en".to_string(),
             context: None,
+            description: None,
+            max_length: None,
+            location: None,
         }];
 
         let csv = generate_csv(
@@ -288,7 +256,7 @@ mod tests {
         .unwrap();
 
         // Should have empty cells for missing locales
-        assert!(csv.contains("\"Buy\",\"\",\"\""));
+        assert!(csv.contains("Buy,,ui.button,,Buy,,\n"));
     }
 
     #[test]
@@ -299,18 +267,27 @@ mod tests {
                 value: "Z".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "a.key".to_string(),
                 value: "A".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "m.key".to_string(),
                 value: "M".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -330,12 +307,19 @@ mod tests {
             value: "Hello, \"World\"!\nNew line".to_string(),
             locale: "en".to_string(),
             context: None,
+            description: None,
+            max_length: None,
+            location: None,
         }];
 
         let csv = generate_csv(&translations, "en", &["en".to_string()]).unwrap();
 
         // Should properly escape quotes and preserve newlines
         assert!(csv.contains("\"Hello, \"\"World\"\"!\nNew line\""));
+
+        let parsed = parse_csv(&csv, "en").unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].value, "Hello, \"World\"!\nNew line");
     }
 
     #[test]
@@ -345,7 +329,7 @@ mod tests {
 "Sell","","ui.sell","Sell","Jual"
 "#;
 
-        let translations = parse_csv(csv_content).unwrap();
+        let translations = parse_csv(csv_content, "en").unwrap();
 
         assert_eq!(translations.len(), 4); // 2 keys × 2 locales
         assert!(translations
@@ -356,17 +340,29 @@ mod tests {
             .any(|t| t.key == "ui.button" && t.locale == "id" && t.value == "Beli"));
     }
 
+    #[test]
+    fn test_parse_csv_source_as_base_locale() {
+        let csv_content = "Key,Source,Context,Example,es\nui.button,Buy,,,Comprar\n";
+
+        let translations = parse_csv(csv_content, "en").unwrap();
+
+        assert_eq!(translations.len(), 2);
+        assert_eq!(translations[0].locale, "en");
+        assert_eq!(translations[0].value, "Buy");
+        assert_eq!(translations[1].locale, "es");
+    }
+
     #[test]
     fn test_parse_csv_empty_file() {
         let csv_content = "";
-        let result = parse_csv(csv_content);
+        let result = parse_csv(csv_content, "en");
         assert!(result.is_err());
     }
 
     #[test]
     fn test_parse_csv_invalid_header() {
         let csv_content = "Source,Context\n";
-        let result = parse_csv(csv_content);
+        let result = parse_csv(csv_content, "en");
         assert!(result.is_err());
     }
 
@@ -376,7 +372,7 @@ mod tests {
 "Buy","","ui.button","Buy",""
 "#;
 
-        let translations = parse_csv(csv_content).unwrap();
+        let translations = parse_csv(csv_content, "en").unwrap();
 
         // Should only have 1 translation (id is empty)
         assert_eq!(translations.len(), 1);
@@ -389,7 +385,7 @@ mod tests {
 "Buy","Purchase button","ui.button","Buy"
 "#;
 
-        let translations = parse_csv(csv_content).unwrap();
+        let translations = parse_csv(csv_content, "en").unwrap();
 
         assert_eq!(translations.len(), 1);
         assert_eq!(translations[0].context, Some("Purchase button".to_string()));
@@ -403,53 +399,92 @@ mod tests {
 
 "#;
 
-        let translations = parse_csv(csv_content).unwrap();
+        let translations = parse_csv(csv_content, "en").unwrap();
+
+        assert_eq!(translations.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_csv_multiline_value() {
+        let csv_content = "Key,Source,Context,Example,en\n\
+                           dialog.intro,\"Hello!\nWelcome to the shop.\",,,\"Hello!\nWelcome to the shop.\"\n\
+                           ui.button,Buy,,,Buy\n";
+
+        let translations = parse_csv(csv_content, "en").unwrap();
+
+        assert_eq!(translations.len(), 2);
+        assert_eq!(translations[0].value, "Hello!\nWelcome to the shop.");
+        assert_eq!(translations[1].key, "ui.button");
+    }
+
+    #[test]
+    fn test_parse_csv_bom_and_crlf() {
+        let csv_content = "\u{feff}Key,Source,Context,Example,en\r\nui.button,Buy,,,Buy\r\n";
+
+        let translations = parse_csv(csv_content, "en").unwrap();
 
         assert_eq!(translations.len(), 1);
+        assert_eq!(translations[0].key, "ui.button");
+        assert_eq!(translations[0].value, "Buy");
     }
 
     #[test]
-    fn test_parse_csv_line() {
-        let line = "\"Hello\",\"World\",\"Test\"";
-        let values = parse_csv_line(line);
+    fn test_read_cells() {
+        let values = read_cells("\"Hello\",\"World\",\"Test\"");
         assert_eq!(values, vec!["Hello", "World", "Test"]);
     }
 
     #[test]
-    fn test_parse_csv_line_with_quotes() {
-        let line = "\"Say \"\"Hi\"\"\",\"World\"";
-        let values = parse_csv_line(line);
+    fn test_read_cells_with_quotes() {
+        let values = read_cells("\"Say \"\"Hi\"\"\",\"World\",");
         assert_eq!(values[0], "Say \"Hi\"");
     }
 
     #[test]
-    fn test_parse_csv_line_with_commas() {
-        let line = "\"Hello, World\",\"Test\"";
-        let values = parse_csv_line(line);
-        assert_eq!(values, vec!["Hello, World", "Test"]);
+    fn test_read_cells_with_commas() {
+        let values = read_cells("\"Hello, World\",\"Test\",");
+        assert_eq!(values, vec!["Hello, World", "Test", ""]);
     }
 
     #[test]
-    fn test_parse_csv_line_unquoted() {
-        let line = "Hello,World,Test";
-        let values = parse_csv_line(line);
+    fn test_read_cells_unquoted() {
+        let values = read_cells("Hello,World,Test");
         assert_eq!(values, vec!["Hello", "World", "Test"]);
     }
 
     #[test]
-    fn test_parse_csv_line_mixed() {
-        let line = "\"Quoted\",Unquoted,\"Mixed\"";
-        let values = parse_csv_line(line);
+    fn test_read_cells_mixed() {
+        let values = read_cells("\"Quoted\",Unquoted,\"Mixed\"");
         assert_eq!(values, vec!["Quoted", "Unquoted", "Mixed"]);
     }
 
     #[test]
-    fn test_parse_csv_line_empty_values() {
-        let line = "\"\",\"\",\"\"";
-        let values = parse_csv_line(line);
+    fn test_read_cells_empty_values() {
+        let values = read_cells("\"\",\"\",\"\"");
         assert_eq!(values, vec!["", "", ""]);
     }
 
+    #[test]
+    fn test_roundtrip_escaped_braces() {
+        let translations = vec![Translation {
+            key: "ui.hint".to_string(),
+            value: "Press {{E}}, {name}".to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }];
+
+        // Escapes are kept, so CSV sources and downloads read back the same value
+        let csv = generate_csv(&translations, "en", &["en".to_string()]).unwrap();
+        assert!(csv.contains("Press {{E}}, {name}"));
+        assert_eq!(
+            parse_csv(&csv, "en").unwrap()[0].value,
+            "Press {{E}}, {name}"
+        );
+    }
+
     #[test]
     fn test_roundtrip_csv() {
         let original_translations = vec![
@@ -458,12 +493,18 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Beli".to_string(),
                 locale: "id".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -476,7 +517,7 @@ mod tests {
         .unwrap();
 
         // Parse it back
-        let parsed_translations = parse_csv(&csv).unwrap();
+        let parsed_translations = parse_csv(&csv, "en").unwrap();
 
         // Should have same number of translations
         assert_eq!(parsed_translations.len(), original_translations.len());
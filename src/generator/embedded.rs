@@ -0,0 +1,510 @@
+//! Embedded runtime
+//!
+//! With `runtime: embedded`, the generated module doesn't use
+//! `LocalizationService`: every supported locale's strings are baked into the
+//! module, and a small translator with the same `FormatByKey` method reads them.
+//! The rest of the module is unchanged, so both runtimes share one public API.
+//!
+//! For large games the strings can be split into ModuleScripts per locale (or
+//! per locale and top-level namespace) with `embedded_chunks`. The translator
+//! then requires a chunk the first time one of its keys is looked up.
+
+use super::luau::{compile_messages, Messages};
+use crate::config::{EmbeddedChunks, DEFAULT_MODULE_NAME};
+use crate::parser::Translation;
+use crate::utils::format::{luau_string, Formatters};
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Folder next to the main module holding the locale chunks
+pub const LOCALE_MODULES_DIR: &str = "locales";
+
+/// Folder holding the locale chunks of the module named `module_name`
+///
+/// Namespaced modules prefix it with their name (`GameStrings_locales`), so
+/// several modules can share an output directory.
+pub fn locale_modules_dir(module_name: &str) -> String {
+    if module_name == DEFAULT_MODULE_NAME {
+        LOCALE_MODULES_DIR.to_string()
+    } else {
+        format!("{}_{}", module_name, LOCALE_MODULES_DIR)
+    }
+}
+
+/// Embedded strings by locale and key
+type Strings<'a> = BTreeMap<&'a str, BTreeMap<&'a str, &'a str>>;
+
+/// Collect the strings to embed; ICU message keys are left out, since their
+/// compiled functions format them
+fn embedded_strings<'a>(
+    translations: &'a [Translation],
+    supported_locales: &[String],
+    messages: &Messages,
+) -> Strings<'a> {
+    let mut strings = Strings::new();
+    for translation in translations {
+        if supported_locales.contains(&translation.locale)
+            && !messages.contains_key(&translation.key)
+        {
+            strings
+                .entry(translation.locale.as_str())
+                .or_default()
+                .insert(translation.key.as_str(), translation.value.as_str());
+        }
+    }
+    strings
+}
+
+/// Chunk a key belongs to with `embedded_chunks: namespace`: its first segment
+/// Example: "ui.buttons.buy" -> "ui", "items(one)" -> "items"
+fn chunk_name(key: &str) -> &str {
+    key.split(['.', '(']).next().unwrap_or(key)
+}
+
+/// Generate the embedded strings and the translator reading them
+///
+/// Keys are looked up along the locale's `fallbackChain`, which the module
+/// defines first. With `typed`, the functions are annotated for `--!strict`
+/// modules, which declare the `EmbeddedTranslator` and `Params` types.
+pub(super) fn generate_embedded_translator(
+    code: &mut String,
+    translations: &[Translation],
+    supported_locales: &[String],
+    messages: &Messages,
+    chunks: EmbeddedChunks,
+    module_name: &str,
+    typed: bool,
+) {
+    if chunks == EmbeddedChunks::Single {
+        generate_inline_strings(code, translations, supported_locales, messages, typed);
+    } else {
+        generate_chunk_loader(code, chunks, &locale_modules_dir(module_name), typed);
+    }
+
+    code.push_str(
+        "--- Format a key like Translator:FormatByKey, trying each locale of the fallback chain\n",
+    );
+    if typed {
+        code.push_str("local function formatEmbedded(translator: EmbeddedTranslator, key: string, params: Params?): string\n");
+        code.push_str("    local value: string? = nil\n");
+    } else {
+        code.push_str("local function formatEmbedded(translator, key, params)\n");
+        code.push_str("    local value = nil\n");
+    }
+    code.push_str("    for _, locale in ipairs(fallbackChain(translator.LocaleId)) do\n");
+    code.push_str("        value = lookupEmbedded(locale, key)\n");
+    code.push_str("        if value ~= nil then\n");
+    code.push_str("            break\n");
+    code.push_str("        end\n");
+    code.push_str("    end\n");
+    code.push_str("    if value == nil then\n");
+    code.push_str("        error(\"Translation key not found: \" .. key, 2)\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    if typed {
+        code.push_str("    return (string.gsub(value, \"({([%w_]+)[^}]*})\", function(placeholder: string, param: string): string\n");
+    } else {
+        code.push_str(
+            "    return (string.gsub(value, \"({([%w_]+)[^}]*})\", function(placeholder, param)\n",
+        );
+    }
+    code.push_str("        local replacement = if params then params[param] else nil\n");
+    code.push_str(
+        "        return if replacement ~= nil then tostring(replacement) else placeholder\n",
+    );
+    code.push_str("    end))\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Create a translator for a locale that reads the embedded strings\n");
+    if typed {
+        code.push_str("local function newTranslator(locale: string): EmbeddedTranslator\n");
+    } else {
+        code.push_str("local function newTranslator(locale)\n");
+    }
+    code.push_str("    return { LocaleId = locale, FormatByKey = formatEmbedded }\n");
+    code.push_str("end\n\n");
+}
+
+/// Generate every locale's strings as one table inside the module
+fn generate_inline_strings(
+    code: &mut String,
+    translations: &[Translation],
+    supported_locales: &[String],
+    messages: &Messages,
+    typed: bool,
+) {
+    let strings = embedded_strings(translations, supported_locales, messages);
+
+    code.push_str(
+        "-- Embedded translations (runtime: embedded), read instead of LocalizationService\n",
+    );
+    if typed {
+        code.push_str("local embeddedStrings: { [string]: { [string]: string } } = {\n");
+    } else {
+        code.push_str("local embeddedStrings = {\n");
+    }
+    for locale in supported_locales {
+        let Some(values) = strings.get(locale.as_str()) else {
+            continue;
+        };
+        code.push_str(&format!("    [{}] = {{\n", luau_string(locale)));
+        for (key, value) in values {
+            code.push_str(&format!(
+                "        [{}] = {},\n",
+                luau_string(key),
+                luau_string(value)
+            ));
+        }
+        code.push_str("    },\n");
+    }
+    code.push_str("}\n\n");
+
+    if typed {
+        code.push_str("local function lookupEmbedded(locale: string, key: string): string?\n");
+    } else {
+        code.push_str("local function lookupEmbedded(locale, key)\n");
+    }
+    code.push_str("    local localeStrings = embeddedStrings[locale]\n");
+    code.push_str("    return if localeStrings then localeStrings[key] else nil\n");
+    code.push_str("end\n\n");
+}
+
+/// Generate the lookup that requires locale chunks the first time they are used
+fn generate_chunk_loader(
+    code: &mut String,
+    chunks: EmbeddedChunks,
+    modules_dir: &str,
+    typed: bool,
+) {
+    let namespaces = chunks == EmbeddedChunks::Namespace;
+
+    code.push_str(&format!(
+        "-- Embedded translations (runtime: embedded), one ModuleScript per {} in the\n",
+        if namespaces {
+            "locale and namespace"
+        } else {
+            "locale"
+        }
+    ));
+    code.push_str(&format!(
+        "-- \"{}\" folder next to this module, required when first used\n",
+        modules_dir
+    ));
+    if typed {
+        code.push_str(&format!(
+            "local localeModules = (script.Parent :: Instance):WaitForChild(\"{}\")\n",
+            modules_dir
+        ));
+        code.push_str("local embeddedStrings: { [string]: { [string]: string } | false } = {}\n\n");
+        code.push_str("local function lookupEmbedded(locale: string, key: string): string?\n");
+    } else {
+        code.push_str(&format!(
+            "local localeModules = script.Parent:WaitForChild(\"{}\")\n",
+            modules_dir
+        ));
+        code.push_str("local embeddedStrings = {}\n\n");
+        code.push_str("local function lookupEmbedded(locale, key)\n");
+    }
+
+    if namespaces {
+        code.push_str("    local chunk = string.match(key, \"^[^.(]+\") or key\n");
+        code.push_str("    local id = locale .. \"/\" .. chunk\n");
+    } else {
+        code.push_str("    local id = locale\n");
+    }
+    code.push_str("    local localeStrings = embeddedStrings[id]\n");
+    code.push_str("    if localeStrings == nil then\n");
+    if namespaces {
+        code.push_str("        local folder = localeModules:FindFirstChild(locale)\n");
+        code.push_str(
+            "        local module = if folder then folder:FindFirstChild(chunk) else nil\n",
+        );
+    } else {
+        code.push_str("        local module = localeModules:FindFirstChild(locale)\n");
+    }
+    if typed {
+        code.push_str(
+            "        localeStrings = if module then (require :: any)(module) else false\n",
+        );
+    } else {
+        code.push_str("        localeStrings = if module then require(module) else false\n");
+    }
+    code.push_str("        embeddedStrings[id] = localeStrings\n");
+    code.push_str("    end\n");
+    code.push_str("    return if localeStrings then localeStrings[key] else nil\n");
+    code.push_str("end\n\n");
+}
+
+/// Generate the locale chunk modules for `embedded_chunks: locale` or `namespace`
+///
+/// Returns each module's path relative to the output directory with its code:
+/// `locales/<locale>.lua`, or `locales/<locale>/<namespace>.lua`, in the
+/// [`locale_modules_dir`] of `module_name`. With `strict`, the modules are
+/// `--!strict` `.luau` files. `single` has no chunks. The values may use the
+/// custom specifiers of `formatters`.
+pub fn generate_locale_modules(
+    translations: &[Translation],
+    base_locale: &str,
+    supported_locales: &[String],
+    chunks: EmbeddedChunks,
+    module_name: &str,
+    strict: bool,
+    formatters: &Formatters,
+) -> Result<Vec<(PathBuf, String)>> {
+    let modules_dir = PathBuf::from(locale_modules_dir(module_name));
+    let base_translations: Vec<_> = translations
+        .iter()
+        .filter(|t| t.locale == base_locale)
+        .collect();
+    let messages = compile_messages(translations, &base_translations, formatters)?;
+    let strings = embedded_strings(translations, supported_locales, &messages);
+    let extension = if strict { "luau" } else { "lua" };
+
+    let mut modules = Vec::new();
+    for locale in supported_locales {
+        let Some(values) = strings.get(locale.as_str()) else {
+            continue;
+        };
+
+        match chunks {
+            EmbeddedChunks::Single => {}
+            EmbeddedChunks::Locale => {
+                let path = modules_dir.join(format!("{}.{}", locale, extension));
+                let code =
+                    generate_chunk_module(&format!("{} translations", locale), values, strict);
+                modules.push((path, code));
+            }
+            EmbeddedChunks::Namespace => {
+                let mut namespaces: BTreeMap<&str, BTreeMap<&str, &str>> = BTreeMap::new();
+                for (key, value) in values {
+                    namespaces
+                        .entry(chunk_name(key))
+                        .or_default()
+                        .insert(key, value);
+                }
+                for (namespace, values) in namespaces {
+                    let path = modules_dir
+                        .join(locale)
+                        .join(format!("{}.{}", namespace, extension));
+                    let code = generate_chunk_module(
+                        &format!("{} translations for {}", namespace, locale),
+                        &values,
+                        strict,
+                    );
+                    modules.push((path, code));
+                }
+            }
+        }
+    }
+
+    Ok(modules)
+}
+
+/// Generate a chunk module returning its strings by key
+fn generate_chunk_module(title: &str, values: &BTreeMap<&str, &str>, strict: bool) -> String {
+    let mut code = String::new();
+    if strict {
+        code.push_str("--!strict\n");
+    }
+    code.push_str(&format!(
+        "-- Roblox Slang - {}, auto-generated by roblox-slang CLI tool\n",
+        title
+    ));
+    code.push_str("-- DO NOT MODIFY BY HAND - Your changes will be overwritten!\n\n");
+
+    if strict {
+        code.push_str("local strings: { [string]: string } = {\n");
+    } else {
+        code.push_str("return {\n");
+    }
+    for (key, value) in values {
+        code.push_str(&format!(
+            "    [{}] = {},\n",
+            luau_string(key),
+            luau_string(value)
+        ));
+    }
+    code.push_str("}\n");
+    if strict {
+        code.push_str("\nreturn strings\n");
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(key: &str, value: &str, locale: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_generate_embedded_translator() {
+        let translations = vec![
+            translation("ui.buy", "Buy", "en"),
+            translation("ui.greeting", "Hello, \"{name}\"!", "en"),
+            translation("ui.buy", "Beli", "id"),
+            translation("ui.buy", "Comprar", "es"),
+        ];
+        let locales = vec!["en".to_string(), "id".to_string()];
+
+        let mut code = String::new();
+        generate_embedded_translator(
+            &mut code,
+            &translations,
+            &locales,
+            &Messages::new(),
+            EmbeddedChunks::Single,
+            DEFAULT_MODULE_NAME,
+            false,
+        );
+
+        assert!(code.contains("    [\"en\"] = {\n        [\"ui.buy\"] = \"Buy\",\n"));
+        assert!(code.contains("[\"ui.greeting\"] = \"Hello, \\\"{name}\\\"!\","));
+        assert!(code.contains("    [\"id\"] = {\n        [\"ui.buy\"] = \"Beli\",\n"));
+        // Locales outside supported_locales are not embedded
+        assert!(!code.contains("Comprar"));
+        assert!(
+            code.contains("    for _, locale in ipairs(fallbackChain(translator.LocaleId)) do\n")
+        );
+        assert!(code.contains("local function newTranslator(locale)\n"));
+        assert!(!code.contains(": string"));
+
+        let mut typed = String::new();
+        generate_embedded_translator(
+            &mut typed,
+            &translations,
+            &locales,
+            &Messages::new(),
+            EmbeddedChunks::Single,
+            DEFAULT_MODULE_NAME,
+            true,
+        );
+        assert!(
+            typed.contains("local function newTranslator(locale: string): EmbeddedTranslator\n")
+        );
+    }
+
+    #[test]
+    fn test_generate_chunk_loader() {
+        let translations = vec![translation("ui.buy", "Buy", "en")];
+        let locales = vec!["en".to_string()];
+
+        let mut code = String::new();
+        generate_embedded_translator(
+            &mut code,
+            &translations,
+            &locales,
+            &Messages::new(),
+            EmbeddedChunks::Locale,
+            DEFAULT_MODULE_NAME,
+            false,
+        );
+
+        // Strings are required from the chunks, not inlined
+        assert!(!code.contains("\"Buy\""));
+        assert!(code.contains("local localeModules = script.Parent:WaitForChild(\"locales\")\n"));
+        assert!(code.contains("        local module = localeModules:FindFirstChild(locale)\n"));
+
+        let mut namespaced = String::new();
+        generate_embedded_translator(
+            &mut namespaced,
+            &translations,
+            &locales,
+            &Messages::new(),
+            EmbeddedChunks::Namespace,
+            "GameStrings",
+            true,
+        );
+        assert!(namespaced.contains("    local id = locale .. \"/\" .. chunk\n"));
+        assert!(namespaced.contains(":WaitForChild(\"GameStrings_locales\")\n"));
+        assert!(namespaced.contains("(require :: any)(module)"));
+    }
+
+    #[test]
+    fn test_generate_locale_modules() {
+        let translations = vec![
+            translation("ui.buy", "Buy", "en"),
+            translation("items(one)", "{count} item", "en"),
+            translation("items(other)", "{count} items", "en"),
+            translation("title", "Obby", "en"),
+            translation("ui.buy", "Beli", "id"),
+        ];
+        let locales = vec!["en".to_string(), "id".to_string()];
+
+        let modules = generate_locale_modules(
+            &translations,
+            "en",
+            &locales,
+            EmbeddedChunks::Locale,
+            DEFAULT_MODULE_NAME,
+            false,
+            &Formatters::new(),
+        )
+        .unwrap();
+        let paths: Vec<_> = modules.iter().map(|(path, _)| path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("locales/en.lua"),
+                PathBuf::from("locales/id.lua")
+            ]
+        );
+        assert!(modules[1]
+            .1
+            .contains("return {\n    [\"ui.buy\"] = \"Beli\",\n}\n"));
+
+        let modules = generate_locale_modules(
+            &translations,
+            "en",
+            &locales,
+            EmbeddedChunks::Namespace,
+            DEFAULT_MODULE_NAME,
+            true,
+            &Formatters::new(),
+        )
+        .unwrap();
+        let paths: Vec<_> = modules.iter().map(|(path, _)| path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("locales/en/items.luau"),
+                PathBuf::from("locales/en/title.luau"),
+                PathBuf::from("locales/en/ui.luau"),
+                PathBuf::from("locales/id/ui.luau"),
+            ]
+        );
+        assert!(modules[0].1.starts_with("--!strict\n"));
+        assert!(modules[0]
+            .1
+            .contains("    [\"items(one)\"] = \"{count} item\",\n"));
+
+        assert!(generate_locale_modules(
+            &translations,
+            "en",
+            &locales,
+            EmbeddedChunks::Single,
+            DEFAULT_MODULE_NAME,
+            false,
+            &Formatters::new(),
+        )
+        .unwrap()
+        .is_empty());
+    }
+
+    #[test]
+    fn test_locale_modules_dir() {
+        assert_eq!(locale_modules_dir(DEFAULT_MODULE_NAME), "locales");
+        assert_eq!(locale_modules_dir("GameStrings"), "GameStrings_locales");
+    }
+}
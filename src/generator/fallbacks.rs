@@ -0,0 +1,251 @@
+//! Locale fallback chains
+//!
+//! Generated lookups try the current locale, its configured `fallbacks`, then
+//! the base locale. Embedded strings, ICU messages and assets walk the chain at
+//! runtime. With `LocalizationService`, whose translators only fall back to the
+//! source text, the keys each locale lacks are routed to the translator of the
+//! chain locale that has them.
+
+use super::luau::Messages;
+use crate::parser::Translation;
+use crate::utils::format::luau_string;
+use crate::utils::locales;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Keys missing in a locale, with the chain locale to format them in
+pub(super) type FallbackKeys<'a> = BTreeMap<&'a str, BTreeMap<&'a str, &'a str>>;
+
+/// Generate the fallback chain of every supported locale and `fallbackChain`
+pub(super) fn generate_fallback_chains(
+    code: &mut String,
+    base_locale: &str,
+    supported_locales: &[String],
+    fallbacks: &BTreeMap<String, Vec<String>>,
+    typed: bool,
+) {
+    let base = luau_string(base_locale);
+
+    code.push_str(
+        "-- Locale fallback chains: the locale, its configured fallbacks, then the base locale\n",
+    );
+    if typed {
+        code.push_str("local fallbackChains: { [string]: { string } } = {\n");
+    } else {
+        code.push_str("local fallbackChains = {\n");
+    }
+    for locale in supported_locales {
+        let chain: Vec<String> = locales::fallback_chain(locale, fallbacks, base_locale)
+            .into_iter()
+            .map(luau_string)
+            .collect();
+        code.push_str(&format!(
+            "    [{}] = {{ {} }},\n",
+            luau_string(locale),
+            chain.join(", ")
+        ));
+    }
+    code.push_str("}\n\n");
+
+    code.push_str(
+        "--- Locales to try for a locale, in order (other locales use their language's chain)\n",
+    );
+    if typed {
+        code.push_str("local function fallbackChain(locale: string): { string }\n");
+    } else {
+        code.push_str("local function fallbackChain(locale)\n");
+    }
+    code.push_str("    return fallbackChains[locale]\n");
+    code.push_str("        or fallbackChains[string.match(locale, \"^%a+\") or locale]\n");
+    code.push_str(&format!("        or {{ locale, {} }}\n", base));
+    code.push_str("end\n\n");
+}
+
+/// Find the keys each locale lacks that a configured fallback locale has
+///
+/// Keys only the base locale has are left out: translators fall back to the
+/// source text on their own. ICU message keys are formatted from their compiled
+/// functions and are left out too.
+pub(super) fn fallback_keys<'a>(
+    translations: &'a [Translation],
+    base_locale: &str,
+    supported_locales: &'a [String],
+    fallbacks: &'a BTreeMap<String, Vec<String>>,
+    messages: &Messages,
+) -> FallbackKeys<'a> {
+    let mut keys_by_locale: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for translation in translations {
+        keys_by_locale
+            .entry(translation.locale.as_str())
+            .or_default()
+            .insert(translation.key.as_str());
+    }
+    let empty = HashSet::new();
+    let mut base_keys: Vec<&str> = keys_by_locale
+        .get(base_locale)
+        .unwrap_or(&empty)
+        .iter()
+        .copied()
+        .filter(|key| !messages.contains_key(*key))
+        .collect();
+    base_keys.sort();
+
+    let mut result = FallbackKeys::new();
+    for locale in supported_locales {
+        let Some(chain) = fallbacks.get(locale) else {
+            continue;
+        };
+        let own = keys_by_locale.get(locale.as_str()).unwrap_or(&empty);
+
+        for key in base_keys.iter().filter(|key| !own.contains(*key)) {
+            let fallback = chain
+                .iter()
+                .take_while(|fallback| fallback.as_str() != base_locale)
+                .find(|fallback| {
+                    keys_by_locale
+                        .get(fallback.as_str())
+                        .is_some_and(|keys| keys.contains(key))
+                });
+            if let Some(fallback) = fallback {
+                result
+                    .entry(locale.as_str())
+                    .or_default()
+                    .insert(key, fallback.as_str());
+            }
+        }
+    }
+
+    result
+}
+
+/// Generate `withFallbacks`, which wraps a `LocalizationService` translator so
+/// keys its locale lacks are formatted by the chain locale that has them
+///
+/// With `typed`, the module declares the `FallbackTranslator` and `Params` types.
+pub(super) fn generate_fallback_translator(code: &mut String, keys: &FallbackKeys, typed: bool) {
+    code.push_str("-- Keys a locale lacks, and the locale of its fallback chain that has them\n");
+    if typed {
+        code.push_str("local fallbackKeys: { [string]: { [string]: string } } = {\n");
+    } else {
+        code.push_str("local fallbackKeys = {\n");
+    }
+    for (locale, locale_keys) in keys {
+        code.push_str(&format!("    [{}] = {{\n", luau_string(locale)));
+        for (key, fallback) in locale_keys {
+            code.push_str(&format!(
+                "        [{}] = {},\n",
+                luau_string(key),
+                luau_string(fallback)
+            ));
+        }
+        code.push_str("    },\n");
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("--- Wrap a translator so keys its locale lacks are formatted in the next locale of its chain\n");
+    if typed {
+        code.push_str(
+            "local function withFallbacks(translator: Translator, locale: string): FallbackTranslator\n",
+        );
+    } else {
+        code.push_str("local function withFallbacks(translator, locale)\n");
+    }
+    // Regional locales use their language's keys, as in `fallbackChain`
+    code.push_str("    local keys = fallbackKeys[locale]\n");
+    code.push_str("        or fallbackKeys[string.match(locale, \"^%a+\") or locale]\n");
+    code.push_str("        or {}\n");
+    if typed {
+        code.push_str("    local translators: { [string]: Translator } = {}\n");
+    } else {
+        code.push_str("    local translators = {}\n");
+    }
+    code.push_str("    return {\n");
+    code.push_str("        LocaleId = locale,\n");
+    if typed {
+        code.push_str("        FormatByKey = function(_: FallbackTranslator, key: string, params: Params?): string\n");
+    } else {
+        code.push_str("        FormatByKey = function(_, key, params)\n");
+    }
+    code.push_str("            local fallback = keys[key]\n");
+    code.push_str("            if fallback == nil then\n");
+    code.push_str("                return translator:FormatByKey(key, params)\n");
+    code.push_str("            end\n");
+    code.push_str("            \n");
+    code.push_str("            local fallbackTranslator = translators[fallback]\n");
+    code.push_str("            if fallbackTranslator == nil then\n");
+    code.push_str(
+        "                local LocalizationService = game:GetService(\"LocalizationService\")\n",
+    );
+    code.push_str(
+        "                fallbackTranslator = LocalizationService:GetTranslatorForLocaleAsync(fallback)\n",
+    );
+    code.push_str("                translators[fallback] = fallbackTranslator\n");
+    code.push_str("            end\n");
+    code.push_str("            return fallbackTranslator:FormatByKey(key, params)\n");
+    code.push_str("        end,\n");
+    code.push_str("    }\n");
+    code.push_str("end\n\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(key: &str, locale: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: "Text".to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_generate_fallback_chains() {
+        let locales = vec!["en".to_string(), "es".to_string(), "pt".to_string()];
+        let fallbacks = BTreeMap::from([("pt".to_string(), vec!["es".to_string()])]);
+
+        let mut code = String::new();
+        generate_fallback_chains(&mut code, "en", &locales, &fallbacks, false);
+
+        assert!(code.contains("    [\"en\"] = { \"en\" },\n"));
+        assert!(code.contains("    [\"es\"] = { \"es\", \"en\" },\n"));
+        assert!(code.contains("    [\"pt\"] = { \"pt\", \"es\", \"en\" },\n"));
+        assert!(code.contains("        or { locale, \"en\" }\n"));
+    }
+
+    #[test]
+    fn test_fallback_keys() {
+        let translations = vec![
+            translation("ui.buy", "en"),
+            translation("ui.sell", "en"),
+            translation("ui.title", "en"),
+            translation("ui.buy", "pt"),
+            translation("ui.sell", "es"),
+        ];
+        let locales = vec!["en".to_string(), "es".to_string(), "pt".to_string()];
+        let fallbacks =
+            BTreeMap::from([("pt".to_string(), vec!["es".to_string(), "en".to_string()])]);
+
+        let keys = fallback_keys(&translations, "en", &locales, &fallbacks, &Messages::new());
+
+        // ui.title is only in the base locale, which translators fall back to already
+        assert_eq!(
+            keys,
+            FallbackKeys::from([("pt", BTreeMap::from([("ui.sell", "es")]))])
+        );
+
+        let mut code = String::new();
+        generate_fallback_translator(&mut code, &keys, true);
+        assert!(code.contains("    [\"pt\"] = {\n        [\"ui.sell\"] = \"es\",\n    },\n"));
+        assert!(code.contains(
+            "local function withFallbacks(translator: Translator, locale: string): FallbackTranslator\n"
+        ));
+        // `pt-br` gets the keys of `pt` when it isn't configured itself
+        assert!(code.contains(
+            "    local keys = fallbackKeys[locale]\n        or fallbackKeys[string.match(locale, \"^%a+\") or locale]\n        or {}\n"
+        ));
+    }
+}
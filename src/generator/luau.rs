@@ -1,19 +1,69 @@
-use crate::parser::Translation;
-use crate::utils::{format, plurals};
+use super::embedded::generate_embedded_translator;
+use super::fallbacks::{fallback_keys, generate_fallback_chains, generate_fallback_translator};
+use super::numbers::generate_number_formats;
+use super::time_units::generate_time_units;
+use crate::config::{Config, Runtime};
+use crate::parser::{Translation, Variant};
+use crate::utils::format::{self, luau_string, Formatters};
+use crate::utils::icu::{self, Part};
+use crate::utils::{lists, plurals, selects};
 use anyhow::Result;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Generate Luau code from translations
+#[allow(dead_code)] // Public API for library users
 pub fn generate_luau(translations: &[Translation], base_locale: &str) -> Result<String> {
     generate_luau_with_config(translations, base_locale, None)
 }
 
 /// Generate Luau code with analytics config
+#[allow(dead_code)] // Public API for library users
 pub fn generate_luau_with_config(
     translations: &[Translation],
     base_locale: &str,
     analytics_config: Option<&crate::config::AnalyticsConfig>,
 ) -> Result<String> {
+    // Plural rules are generated for every locale that has translations
+    let mut locales: Vec<String> = vec![base_locale.to_string()];
+    for translation in translations {
+        if !locales.contains(&translation.locale) {
+            locales.push(translation.locale.clone());
+        }
+    }
+
+    let config = Config {
+        base_locale: base_locale.to_string(),
+        supported_locales: locales,
+        analytics: analytics_config.cloned(),
+        ..Config::default()
+    };
+    generate_luau_for_config(translations, &config, &[])
+}
+
+/// Generate Luau code with the options of a config
+///
+/// With [`Runtime::Embedded`], the strings of `supported_locales` are baked into
+/// the module and read without `LocalizationService`. With `embedded_chunks`
+/// other than [`EmbeddedChunks::Single`](crate::config::EmbeddedChunks::Single),
+/// they are required from the modules written by
+/// [`generate_locale_modules`](super::generate_locale_modules) instead.
+///
+/// Lookups try the current locale, its `fallbacks`, then the base locale. The
+/// doc comments and the chunk folder use the config's module name. Parameters
+/// with the custom specifiers of `formatters` are formatted by their module.
+pub fn generate_luau_for_config(
+    translations: &[Translation],
+    config: &Config,
+    variants: &[Variant],
+) -> Result<String> {
+    let base_locale = config.base_locale.as_str();
+    let supported_locales = config.supported_locales.as_slice();
+    let analytics_config = config.analytics.as_ref();
+    let runtime = config.runtime;
+    let chunks = config.embedded_chunks;
+    let fallbacks = &config.fallbacks;
+    let module_name = config.module_name();
+    let formatters = &config.formatters;
     let mut code = String::new();
 
     // Professional header with documentation
@@ -40,15 +90,55 @@ pub fn generate_luau_with_config(
         return Ok(code + "-- No translations found\nreturn {}\n");
     }
 
+    // ICU MessageFormat values, compiled for every locale
+    let messages = compile_messages(translations, &base_translations, formatters)?;
+
     // Class definition
     code.push_str("local Translations = {}\n");
     code.push_str("Translations.__index = Translations\n\n");
 
+    // Locale fallback chains
+    generate_fallback_chains(&mut code, base_locale, supported_locales, fallbacks, false);
+
+    // Strings baked into the module (embedded runtime), or the keys
+    // LocalizationService translators have to look up in a fallback locale
+    let mut wrap_translator = false;
+    if runtime == Runtime::Embedded {
+        generate_embedded_translator(
+            &mut code,
+            translations,
+            supported_locales,
+            &messages,
+            chunks,
+            module_name,
+            false,
+        );
+    } else {
+        let keys = fallback_keys(
+            translations,
+            base_locale,
+            supported_locales,
+            fallbacks,
+            &messages,
+        );
+        if !keys.is_empty() {
+            generate_fallback_translator(&mut code, &keys, false);
+            wrap_translator = true;
+        }
+    }
+
     // Constructor
-    generate_constructor(&mut code, analytics_config);
+    generate_constructor(
+        &mut code,
+        base_locale,
+        module_name,
+        analytics_config,
+        runtime,
+        wrap_translator,
+    );
 
     // Locale detection methods
-    generate_locale_detection(&mut code);
+    generate_locale_detection(&mut code, base_locale, module_name);
 
     // Analytics methods (if enabled)
     if let Some(config) = analytics_config {
@@ -57,8 +147,53 @@ pub fn generate_luau_with_config(
         }
     }
 
+    // Variant switching methods (if the override file defines variants)
+    if !variants.is_empty() {
+        generate_variant_methods(&mut code, variants);
+    }
+
+    // Number formats (if any key formats numbers with the locale's symbols)
+    if uses_format(
+        &base_translations,
+        &messages,
+        format::FormatSpecifier::is_locale_aware,
+        formatters,
+    ) {
+        generate_number_formats(&mut code, base_locale, supported_locales, false);
+    }
+
+    // Plural rules (if there are plural keys, ICU plurals or relative times)
+    let (cardinal, ordinal) = plural_rule_kinds(&base_translations, &messages, formatters);
+    if cardinal || ordinal {
+        generate_plural_rules(&mut code, supported_locales, cardinal, ordinal);
+    }
+
+    // Relative time and duration units (if any key uses them)
+    let (relative, duration) = time_unit_kinds(&base_translations, &messages, formatters);
+    if relative || duration {
+        generate_time_units(
+            &mut code,
+            base_locale,
+            supported_locales,
+            relative,
+            duration,
+            false,
+        );
+    }
+
+    if !messages.is_empty() {
+        generate_message_runtime(&mut code, &messages, base_locale);
+    }
+
     // Generate flat methods (internal)
-    generate_flat_methods(&mut code, &base_translations, analytics_config);
+    generate_flat_methods(
+        &mut code,
+        &base_translations,
+        &messages,
+        analytics_config,
+        !variants.is_empty(),
+        formatters,
+    );
 
     // Generate namespace structure (syntax sugar)
     generate_namespace_structure(&mut code, &base_translations);
@@ -70,16 +205,24 @@ pub fn generate_luau_with_config(
 }
 
 /// Generate constructor method
+///
+/// With `wrap_translator`, translators are wrapped by `withFallbacks`.
 fn generate_constructor(
     code: &mut String,
+    base_locale: &str,
+    module_name: &str,
     analytics_config: Option<&crate::config::AnalyticsConfig>,
+    runtime: Runtime,
+    wrap_translator: bool,
 ) {
-    code.push_str("--- Create a new Translations instance\n");
+    let base = luau_string(base_locale);
+
+    code.push_str(&format!("--- Create a new {} instance\n", module_name));
     code.push_str("--- @param locale string The locale to use (e.g., \"en\", \"id\")\n");
-    code.push_str("--- @return Translations\n");
+    code.push_str(&format!("--- @return {}\n", module_name));
     code.push_str("function Translations.new(locale)\n");
     code.push_str("    local self = setmetatable({}, Translations)\n");
-    code.push_str("    self._locale = locale or \"en\"\n");
+    code.push_str(&format!("    self._locale = locale or {}\n", base));
     code.push_str("    self._localeChangedCallbacks = {}\n");
 
     // Add analytics initialization if enabled
@@ -107,19 +250,33 @@ fn generate_constructor(
     }
 
     code.push_str("    \n");
-    code.push_str("    -- Get LocalizationService translator\n");
-    code.push_str("    local LocalizationService = game:GetService(\"LocalizationService\")\n");
-    code.push_str("    local success, translator = pcall(function()\n");
-    code.push_str("        return LocalizationService:GetTranslatorForLocaleAsync(self._locale)\n");
-    code.push_str("    end)\n");
-    code.push_str("    \n");
-    code.push_str("    if not success then\n");
-    code.push_str("        warn(\"Failed to get translator for locale: \" .. self._locale .. \", falling back to base locale\")\n");
-    code.push_str("        -- Fallback to base locale (works on both client and server)\n");
-    code.push_str("        translator = LocalizationService:GetTranslatorForLocaleAsync(\"en\")\n");
-    code.push_str("    end\n");
-    code.push_str("    \n");
-    code.push_str("    self._translator = translator\n");
+    if runtime == Runtime::Embedded {
+        code.push_str("    -- Translator reading the embedded strings\n");
+        code.push_str("    self._translator = newTranslator(self._locale)\n");
+    } else {
+        code.push_str("    -- Get LocalizationService translator\n");
+        code.push_str("    local LocalizationService = game:GetService(\"LocalizationService\")\n");
+        code.push_str("    local success, translator = pcall(function()\n");
+        code.push_str(
+            "        return LocalizationService:GetTranslatorForLocaleAsync(self._locale)\n",
+        );
+        code.push_str("    end)\n");
+        code.push_str("    \n");
+        code.push_str("    if not success then\n");
+        code.push_str("        warn(\"Failed to get translator for locale: \" .. self._locale .. \", falling back to base locale\")\n");
+        code.push_str("        -- Fallback to base locale (works on both client and server)\n");
+        code.push_str(&format!(
+            "        translator = LocalizationService:GetTranslatorForLocaleAsync({})\n",
+            base
+        ));
+        code.push_str("    end\n");
+        code.push_str("    \n");
+        if wrap_translator {
+            code.push_str("    self._translator = withFallbacks(translator, self._locale)\n");
+        } else {
+            code.push_str("    self._translator = translator\n");
+        }
+    }
     code.push_str("    \n");
     code.push_str("    return self\n");
     code.push_str("end\n\n");
@@ -136,18 +293,26 @@ fn generate_constructor(
     code.push_str("    self._locale = locale\n");
     code.push_str("    \n");
     code.push_str("    -- Get new translator\n");
-    code.push_str("    local LocalizationService = game:GetService(\"LocalizationService\")\n");
-    code.push_str("    local success, translator = pcall(function()\n");
-    code.push_str("        return LocalizationService:GetTranslatorForLocaleAsync(locale)\n");
-    code.push_str("    end)\n");
-    code.push_str("    \n");
-    code.push_str("    if success then\n");
-    code.push_str("        self._translator = translator\n");
-    code.push_str("    else\n");
-    code.push_str("        warn(\"Failed to switch to locale: \" .. locale)\n");
-    code.push_str("        self._locale = oldLocale\n");
-    code.push_str("        return\n");
-    code.push_str("    end\n");
+    if runtime == Runtime::Embedded {
+        code.push_str("    self._translator = newTranslator(locale)\n");
+    } else {
+        code.push_str("    local LocalizationService = game:GetService(\"LocalizationService\")\n");
+        code.push_str("    local success, translator = pcall(function()\n");
+        code.push_str("        return LocalizationService:GetTranslatorForLocaleAsync(locale)\n");
+        code.push_str("    end)\n");
+        code.push_str("    \n");
+        code.push_str("    if success then\n");
+        if wrap_translator {
+            code.push_str("        self._translator = withFallbacks(translator, locale)\n");
+        } else {
+            code.push_str("        self._translator = translator\n");
+        }
+        code.push_str("    else\n");
+        code.push_str("        warn(\"Failed to switch to locale: \" .. locale)\n");
+        code.push_str("        self._locale = oldLocale\n");
+        code.push_str("        return\n");
+        code.push_str("    end\n");
+    }
     code.push_str("    \n");
     code.push_str("    -- Fire locale changed callbacks\n");
     code.push_str("    for _, callback in ipairs(self._localeChangedCallbacks) do\n");
@@ -172,23 +337,31 @@ fn generate_constructor(
     code.push_str("--- @param assetKey string The asset key\n");
     code.push_str("--- @return string The asset ID for current locale\n");
     code.push_str("function Translations:getAsset(assetKey)\n");
-    code.push_str("    local key = \"assets.\" .. assetKey .. \".\" .. self._locale\n");
-    code.push_str("    local success, result = pcall(function()\n");
-    code.push_str("        return self._translator:FormatByKey(key)\n");
-    code.push_str("    end)\n");
-    code.push_str("    \n");
-    code.push_str("    if success then\n");
-    code.push_str("        return result\n");
+    code.push_str("    for _, locale in ipairs(fallbackChain(self._locale)) do\n");
+    code.push_str("        local success, result = pcall(function()\n");
+    code.push_str(
+        "            return self._translator:FormatByKey(\"assets.\" .. assetKey .. \".\" .. locale)\n",
+    );
+    code.push_str("        end)\n");
+    code.push_str("        \n");
+    code.push_str("        if success then\n");
+    code.push_str("            return result\n");
+    code.push_str("        end\n");
     code.push_str("    end\n");
     code.push_str("    \n");
     code.push_str("    -- Fallback to base locale\n");
-    code.push_str("    local fallbackKey = \"assets.\" .. assetKey .. \".en\"\n");
+    code.push_str(&format!(
+        "    local fallbackKey = \"assets.\" .. assetKey .. {}\n",
+        luau_string(&format!(".{}", base_locale))
+    ));
     code.push_str("    return self._translator:FormatByKey(fallbackKey)\n");
     code.push_str("end\n\n");
 }
 
 /// Generate locale detection methods
-fn generate_locale_detection(code: &mut String) {
+fn generate_locale_detection(code: &mut String, base_locale: &str, module_name: &str) {
+    let base = luau_string(base_locale);
+
     use crate::utils::locales;
 
     code.push_str("--- Detect player's locale based on their country\n");
@@ -203,7 +376,10 @@ fn generate_locale_detection(code: &mut String) {
     code.push_str("    end)\n");
     code.push_str("    \n");
     code.push_str("    if not success or not countryCode then\n");
-    code.push_str("        return \"en\"  -- Fallback to English\n");
+    code.push_str(&format!(
+        "        return {}  -- Fallback to base locale\n",
+        base
+    ));
     code.push_str("    end\n");
     code.push_str("    \n");
     code.push_str("    -- Map country code to locale\n");
@@ -217,12 +393,18 @@ fn generate_locale_detection(code: &mut String) {
 
     code.push_str("    }\n");
     code.push_str("    \n");
-    code.push_str("    return countryLocaleMap[countryCode] or \"en\"\n");
+    code.push_str(&format!(
+        "    return countryLocaleMap[countryCode] or {}\n",
+        base
+    ));
     code.push_str("end\n\n");
 
-    code.push_str("--- Create a new Translations instance for a player (auto-detect locale)\n");
+    code.push_str(&format!(
+        "--- Create a new {} instance for a player (auto-detect locale)\n",
+        module_name
+    ));
     code.push_str("--- @param player Player The player to create translations for\n");
-    code.push_str("--- @return Translations\n");
+    code.push_str(&format!("--- @return {}\n", module_name));
     code.push_str("function Translations.newForPlayer(player)\n");
     code.push_str("    local locale = Translations.detectLocale(player)\n");
     code.push_str("    return Translations.new(locale)\n");
@@ -276,22 +458,341 @@ fn generate_analytics_methods(code: &mut String, config: &crate::config::Analyti
     }
 }
 
+/// Generate runtime variant data and the methods that switch between variants
+fn generate_variant_methods(code: &mut String, variants: &[Variant]) {
+    code.push_str("-- Override variants (activate with t:setVariant(name))\n");
+    code.push_str("Translations._variants = {\n");
+    for variant in variants {
+        code.push_str(&format!("    [{}] = {{\n", luau_string(&variant.name)));
+        if let Some(start) = variant.start {
+            code.push_str(&format!("        startTime = {},\n", start));
+        }
+        if let Some(end) = variant.end {
+            code.push_str(&format!("        endTime = {},\n", end));
+        }
+        if !variant.segments.is_empty() {
+            let segments: Vec<String> = variant.segments.iter().map(|s| luau_string(s)).collect();
+            code.push_str(&format!(
+                "        segments = {{ {} }},\n",
+                segments.join(", ")
+            ));
+        }
+
+        // locale -> key -> value, sorted for deterministic output
+        let mut by_locale: BTreeMap<&str, BTreeMap<&str, &str>> = BTreeMap::new();
+        for translation in &variant.translations {
+            by_locale
+                .entry(&translation.locale)
+                .or_default()
+                .insert(&translation.key, &translation.value);
+        }
+
+        code.push_str("        values = {\n");
+        for (locale, values) in &by_locale {
+            code.push_str(&format!("            [{}] = {{\n", luau_string(locale)));
+            for (key, value) in values {
+                code.push_str(&format!(
+                    "                [{}] = {},\n",
+                    luau_string(key),
+                    luau_string(value)
+                ));
+            }
+            code.push_str("            },\n");
+        }
+        code.push_str("        },\n");
+        code.push_str("    },\n");
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("--- Activate an override variant, or pass nil to use the base translations\n");
+    code.push_str("--- @param name string? The variant name from the overrides file\n");
+    code.push_str("function Translations:setVariant(name)\n");
+    code.push_str("    if name ~= nil and Translations._variants[name] == nil then\n");
+    code.push_str("        warn(\"Unknown translation variant: \" .. tostring(name))\n");
+    code.push_str("        return\n");
+    code.push_str("    end\n");
+    code.push_str("    self._variant = name\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Set the player's segments, used by variants limited to segments\n");
+    code.push_str("--- @param segments {string} Segment names (e.g., {\"vip\", \"groupB\"})\n");
+    code.push_str("function Translations:setSegments(segments)\n");
+    code.push_str("    self._segments = {}\n");
+    code.push_str("    for _, segment in ipairs(segments) do\n");
+    code.push_str("        self._segments[segment] = true\n");
+    code.push_str("    end\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Get the active variant (nil if none is set or it is outside its date window or segments)\n");
+    code.push_str("--- @return string?\n");
+    code.push_str("function Translations:getVariant()\n");
+    code.push_str("    local variant = self._variant and Translations._variants[self._variant]\n");
+    code.push_str("    if not variant then\n");
+    code.push_str("        return nil\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    local now = os.time()\n");
+    code.push_str("    if (variant.startTime and now < variant.startTime) or (variant.endTime and now > variant.endTime) then\n");
+    code.push_str("        return nil\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    if variant.segments then\n");
+    code.push_str("        local segments = self._segments or {}\n");
+    code.push_str("        for _, segment in ipairs(variant.segments) do\n");
+    code.push_str("            if segments[segment] then\n");
+    code.push_str("                return self._variant\n");
+    code.push_str("            end\n");
+    code.push_str("        end\n");
+    code.push_str("        return nil\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    return self._variant\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Format a key, using the active variant's value when it has one\n");
+    code.push_str("function Translations:_formatByKey(key, params)\n");
+    code.push_str("    local name = self:getVariant()\n");
+    code.push_str(
+        "    local values = name and Translations._variants[name].values[self._locale]\n",
+    );
+    code.push_str("    local value = values and values[key]\n");
+    code.push_str("    if value == nil then\n");
+    code.push_str("        -- Per-key fallback to the base translation\n");
+    code.push_str("        return self._translator:FormatByKey(key, params)\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    return (string.gsub(value, \"{([%w_]+)[^}]*}\", function(param)\n");
+    code.push_str("        local replacement = params and params[param]\n");
+    code.push_str("        return replacement ~= nil and tostring(replacement) or nil\n");
+    code.push_str("    end))\n");
+    code.push_str("end\n\n");
+}
+
+/// ICU messages by key and locale
+pub(super) type Messages = BTreeMap<String, BTreeMap<String, Vec<Part>>>;
+
+/// Parse the ICU MessageFormat values of regular keys, in every locale
+///
+/// A key is compiled as a message when any locale's value uses `plural`,
+/// `selectordinal` or `select`, or escapes literal braces (`{{`, `}}`), which
+/// Roblox's translators would not unescape; its other values are compiled too,
+/// so every locale is formatted the same way.
+pub(super) fn compile_messages(
+    translations: &[Translation],
+    base: &[&Translation],
+    formatters: &Formatters,
+) -> Result<Messages> {
+    let keys: HashSet<&str> = base
+        .iter()
+        .filter(|t| {
+            !plurals::is_plural_key(&t.key)
+                && !selects::is_select_key(&t.key)
+                && !lists::is_list_item_key(&t.key)
+        })
+        .map(|t| t.key.as_str())
+        .collect();
+    let message_keys: HashSet<&str> = translations
+        .iter()
+        .filter(|t| {
+            keys.contains(t.key.as_str())
+                && (icu::is_icu_message(&t.value) || icu::has_escaped_braces(&t.value))
+        })
+        .map(|t| t.key.as_str())
+        .collect();
+
+    let mut messages = Messages::new();
+    for translation in translations
+        .iter()
+        .filter(|t| message_keys.contains(t.key.as_str()))
+    {
+        let parts = icu::parse_value(&translation.value, formatters).map_err(|e| {
+            anyhow::anyhow!(
+                "Invalid {} in: {}\n\
+                 Key '{}' ({}): {}\n\
+                 \n\
+                 Hint: Arguments look like {{count, plural, one {{# item}} other {{# items}}}}\n\
+                 or {{gender, select, male {{He}} other {{They}}}}, and each needs an 'other' case.\n\
+                 Quote literal braces with apostrophes in ICU messages: '{{' and '}}',\n\
+                 and double them elsewhere: {{{{ and }}}}.",
+                if icu::is_icu_message(&translation.value) {
+                    "ICU message"
+                } else {
+                    "placeholder"
+                },
+                translation
+                    .location
+                    .as_ref()
+                    .map(|l| l.to_string())
+                    .unwrap_or_else(|| translation.key.clone()),
+                translation.key,
+                translation.locale,
+                e
+            )
+        })?;
+
+        messages
+            .entry(translation.key.clone())
+            .or_default()
+            .insert(translation.locale.clone(), parts);
+    }
+
+    Ok(messages)
+}
+
+/// Which plural rules the module needs: (cardinal, ordinal)
+///
+/// Relative times pick their form with the cardinal rules.
+pub(super) fn plural_rule_kinds(
+    base: &[&Translation],
+    messages: &Messages,
+    formatters: &Formatters,
+) -> (bool, bool) {
+    let plural_keys: Vec<&str> = base
+        .iter()
+        .filter(|t| plurals::is_plural_key(&t.key))
+        .map(|t| t.key.as_str())
+        .collect();
+    let message_parts = || messages.values().flat_map(|locales| locales.values());
+    let cardinal = plural_keys.iter().any(|k| !plurals::is_ordinal_key(k))
+        || message_parts().any(|parts| icu::uses_plural(parts, false))
+        || time_unit_kinds(base, messages, formatters).0;
+    let ordinal = plural_keys.iter().any(|k| plurals::is_ordinal_key(k))
+        || message_parts().any(|parts| icu::uses_plural(parts, true));
+    (cardinal, ordinal)
+}
+
+/// Which time unit helpers the module needs: (relative, duration)
+pub(super) fn time_unit_kinds(
+    base: &[&Translation],
+    messages: &Messages,
+    formatters: &Formatters,
+) -> (bool, bool) {
+    let relative = uses_format(
+        base,
+        messages,
+        |f| *f == format::FormatSpecifier::Relative,
+        formatters,
+    );
+    let duration = uses_format(
+        base,
+        messages,
+        |f| *f == format::FormatSpecifier::Duration,
+        formatters,
+    );
+    (relative, duration)
+}
+
+/// Whether any key has a parameter whose format `matches`
+pub(super) fn uses_format(
+    base: &[&Translation],
+    messages: &Messages,
+    matches: fn(&format::FormatSpecifier) -> bool,
+    formatters: &Formatters,
+) -> bool {
+    let specifiers = base
+        .iter()
+        .filter(|t| !messages.contains_key(&t.key))
+        .any(|t| {
+            format::extract_parameters_with_formatters(&t.value, formatters)
+                .values()
+                .any(matches)
+        });
+    specifiers
+        || messages
+            .values()
+            .flat_map(|locales| locales.values())
+            .any(|parts| icu::uses_format(parts, matches))
+}
+
+/// Generate the compiled ICU messages and the helpers they use
+fn generate_message_runtime(code: &mut String, messages: &Messages, base_locale: &str) {
+    code.push_str("-- ICU MessageFormat support\n");
+    code.push_str("local function icuPlural(locale, value, offset, ordinal, cases)\n");
+    code.push_str("    local number = tonumber(value) or 0\n");
+    code.push_str("    local shown = if offset == 0 then (value or 0) else number - offset\n");
+    code.push_str("    local case = cases[\"=\" .. tostring(number)]\n");
+    code.push_str("        or cases[Translations.pluralCategory(locale, shown, ordinal)]\n");
+    code.push_str("        or cases.other\n");
+    code.push_str("    return case(tostring(shown))\n");
+    code.push_str("end\n\n");
+
+    code.push_str("local function icuSelect(value, cases)\n");
+    code.push_str("    local case = cases[tostring(value)] or cases.other\n");
+    code.push_str("    return case()\n");
+    code.push_str("end\n\n");
+
+    code.push_str("Translations._messages = {\n");
+    for (key, locales) in messages {
+        code.push_str(&format!("    [{}] = {{\n", luau_string(key)));
+        for (locale, parts) in locales {
+            code.push_str(&format!(
+                "        [{}] = function(params, locale)\n",
+                luau_string(locale)
+            ));
+            code.push_str(&format!(
+                "            return {}\n",
+                icu::message_to_luau(parts)
+            ));
+            code.push_str("        end,\n");
+        }
+        code.push_str("    },\n");
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("--- Format an ICU message in the first locale of the current locale's fallback chain that has it\n");
+    code.push_str("function Translations:_formatMessage(key, params)\n");
+    code.push_str("    local messages = Translations._messages[key]\n");
+    code.push_str("    for _, locale in ipairs(fallbackChain(self._locale)) do\n");
+    code.push_str("        if messages[locale] then\n");
+    code.push_str("            return messages[locale](params or {}, locale)\n");
+    code.push_str("        end\n");
+    code.push_str("    end\n");
+    code.push_str(&format!(
+        "    return messages[{}](params or {{}}, {})\n",
+        luau_string(base_locale),
+        luau_string(base_locale)
+    ));
+    code.push_str("end\n\n");
+}
+
+/// Luau callee used for key lookups; with variants they check the active variant first
+pub(super) fn lookup_fn(variants: bool) -> &'static str {
+    if variants {
+        "self:_formatByKey"
+    } else {
+        "self._translator:FormatByKey"
+    }
+}
+
 /// Generate flat methods (internal, using underscores)
 fn generate_flat_methods(
     code: &mut String,
     translations: &[&Translation],
+    messages: &Messages,
     analytics_config: Option<&crate::config::AnalyticsConfig>,
+    variants: bool,
+    formatters: &Formatters,
 ) {
     code.push_str("-- Internal methods (flat keys)\n\n");
 
-    // Separate plural and non-plural translations
+    let lookup = lookup_fn(variants);
+
+    // Separate plural, select, list and regular translations
     let mut plural_groups: HashMap<String, Vec<&Translation>> = HashMap::new();
+    let mut select_groups: HashMap<String, Vec<&Translation>> = HashMap::new();
+    let mut list_groups: HashMap<String, Vec<&Translation>> = HashMap::new();
     let mut regular_translations = Vec::new();
 
     for translation in translations {
         if plurals::is_plural_key(&translation.key) {
             let base_key = plurals::extract_base_key(&translation.key);
             plural_groups.entry(base_key).or_default().push(translation);
+        } else if selects::is_select_key(&translation.key) {
+            let base_key = selects::extract_base_key(&translation.key);
+            select_groups.entry(base_key).or_default().push(translation);
+        } else if lists::is_list_item_key(&translation.key) {
+            let list_key = lists::extract_list_key(&translation.key);
+            list_groups.entry(list_key).or_default().push(translation);
         } else {
             regular_translations.push(*translation);
         }
@@ -307,12 +808,36 @@ fn generate_flat_methods(
 
     for translation in regular_translations {
         let method_name = translation.key.replace(".", "_");
-        let params_with_format = format::extract_parameters_with_format(&translation.value);
+        let params_with_format =
+            format::extract_parameters_with_formatters(&translation.value, formatters);
 
-        if !params_with_format.is_empty() {
+        generate_doc_comment(code, translation);
+
+        if messages.contains_key(&translation.key) {
+            // ICU message, formatted by the compiled message functions
+            code.push_str(&format!("function Translations:{}(params)\n", method_name));
+            if analytics_enabled && track_usage {
+                code.push_str(&format!("    self:_trackUsage(\"{}\")\n", translation.key));
+            }
+            code.push_str(&format!(
+                "    return self:_formatMessage(\"{}\", params)\n",
+                translation.key
+            ));
+        } else if !params_with_format.is_empty() {
             // Method with parameters and format specifiers
+            let format_codes: Vec<String> = params_with_format
+                .iter()
+                .map(|(param_name, specifier)| format::generate_format_code(param_name, specifier))
+                .filter(|format_code| !format_code.is_empty())
+                .collect();
+
             code.push_str(&format!("function Translations:{}(params)\n", method_name));
-            code.push_str("    params = params or {}\n");
+            if format_codes.is_empty() {
+                code.push_str("    params = params or {}\n");
+            } else {
+                // Formatted values go into a copy, so the caller's table can be reused
+                code.push_str("    params = table.clone(params or {})\n");
+            }
 
             // Track usage if enabled
             if analytics_enabled && track_usage {
@@ -320,22 +845,17 @@ fn generate_flat_methods(
             }
 
             // Apply format specifiers
-            for (param_name, specifier) in &params_with_format {
-                if *specifier != format::FormatSpecifier::None {
-                    let format_code = format::generate_format_code(param_name, specifier);
-                    if !format_code.is_empty() {
-                        code.push_str("    ");
-                        code.push_str(&format_code);
-                        code.push('\n');
-                    }
-                }
+            for format_code in &format_codes {
+                code.push_str("    ");
+                code.push_str(format_code);
+                code.push('\n');
             }
 
             // Get translation with missing tracking
             if analytics_enabled && track_missing {
                 code.push_str(&format!(
-                    "    local value = self._translator:FormatByKey(\"{}\", params)\n",
-                    translation.key
+                    "    local value = {}(\"{}\", params)\n",
+                    lookup, translation.key
                 ));
                 code.push_str("    if value == \"\" or value == \"{}\" then\n");
                 code.push_str(&format!(
@@ -350,8 +870,8 @@ fn generate_flat_methods(
                 code.push_str("    return value\n");
             } else {
                 code.push_str(&format!(
-                    "    return self._translator:FormatByKey(\"{}\", params)\n",
-                    translation.key
+                    "    return {}(\"{}\", params)\n",
+                    lookup, translation.key
                 ));
             }
         } else {
@@ -366,8 +886,8 @@ fn generate_flat_methods(
             // Get translation with missing tracking
             if analytics_enabled && track_missing {
                 code.push_str(&format!(
-                    "    local value = self._translator:FormatByKey(\"{}\")\n",
-                    translation.key
+                    "    local value = {}(\"{}\")\n",
+                    lookup, translation.key
                 ));
                 code.push_str("    if value == \"\" or value == \"{}\" then\n");
                 code.push_str(&format!(
@@ -381,10 +901,7 @@ fn generate_flat_methods(
                 code.push_str("    end\n");
                 code.push_str("    return value\n");
             } else {
-                code.push_str(&format!(
-                    "    return self._translator:FormatByKey(\"{}\")\n",
-                    translation.key
-                ));
+                code.push_str(&format!("    return {}(\"{}\")\n", lookup, translation.key));
             }
         }
 
@@ -397,67 +914,191 @@ fn generate_flat_methods(
 
     for base_key in plural_keys_sorted {
         let plural_translations = &plural_groups[base_key];
-        generate_plural_method(code, base_key, plural_translations);
+        generate_plural_method(code, base_key, plural_translations, variants);
+    }
+
+    // Generate select methods
+    let mut select_keys_sorted: Vec<_> = select_groups.keys().collect();
+    select_keys_sorted.sort();
+
+    for base_key in select_keys_sorted {
+        generate_select_method(
+            code,
+            base_key,
+            &select_groups[base_key],
+            analytics_enabled && track_usage,
+            variants,
+        );
+    }
+
+    // Generate list methods
+    let mut list_keys_sorted: Vec<_> = list_groups.keys().collect();
+    list_keys_sorted.sort();
+
+    for list_key in list_keys_sorted {
+        let items = &list_groups[list_key];
+        generate_list_method(
+            code,
+            list_key,
+            items,
+            analytics_enabled && track_usage,
+            variants,
+        );
+    }
+}
+
+/// Generate a list method returning every item, and one returning a random item
+fn generate_list_method(
+    code: &mut String,
+    list_key: &str,
+    translations: &[&Translation],
+    track_usage: bool,
+    variants: bool,
+) {
+    let method_name = list_key.replace(".", "_");
+
+    let mut items = translations.to_vec();
+    items.sort_by_key(|t| lists::list_index(&t.key));
+    if let Some(documented) = items.iter().find(|t| has_metadata(t)) {
+        generate_doc_comment(code, documented);
+    }
+
+    code.push_str(&format!("function Translations:{}(params)\n", method_name));
+    if track_usage {
+        code.push_str(&format!("    self:_trackUsage(\"{}\")\n", list_key));
+    }
+    code.push_str("    return {\n");
+    for item in &items {
+        code.push_str(&format!(
+            "        {}(\"{}\", params),\n",
+            lookup_fn(variants),
+            item.key
+        ));
+    }
+    code.push_str("    }\n");
+    code.push_str("end\n\n");
+
+    code.push_str(&format!("--- Random entry of {}\n", list_key));
+    code.push_str(&format!(
+        "function Translations:{}_random(params)\n",
+        method_name
+    ));
+    code.push_str(&format!("    local items = self:{}(params)\n", method_name));
+    code.push_str("    return items[math.random(#items)]\n");
+    code.push_str("end\n\n");
+}
+
+/// Generate the CLDR plural rules for `locales` and `Translations.pluralCategory`
+fn generate_plural_rules(code: &mut String, locales: &[String], cardinal: bool, ordinal: bool) {
+    code.push_str("-- Plural rules (CLDR) for the supported locales\n");
+    code.push_str("-- Operands: n = absolute value, i = integer digits, v/w = number of visible\n");
+    code.push_str("-- fraction digits with/without trailing zeros, f/t = those digits\n");
+    code.push_str("local function pluralOperands(count)\n");
+    code.push_str("    local text = string.gsub(tostring(count), \"^-\", \"\")\n");
+    code.push_str("    local integer, fraction = string.match(text, \"^(%d+)%.?(%d*)$\")\n");
+    code.push_str("    if not integer then\n");
+    code.push_str("        local n = math.abs(tonumber(count) or 0)\n");
+    code.push_str("        return n, math.floor(n), 0, 0, 0, 0\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    local trimmed = string.gsub(fraction, \"0+$\", \"\")\n");
+    code.push_str("    return tonumber(text), tonumber(integer), #fraction, #trimmed, tonumber(fraction) or 0, tonumber(trimmed) or 0\n");
+    code.push_str("end\n\n");
+
+    code.push_str("local pluralRules = {\n");
+    for locale in locales {
+        let Some(rules) = plurals::rules_for_locale(locale) else {
+            log::warn!(
+                "No CLDR plural rules for locale '{}', plurals will always use 'other'",
+                locale
+            );
+            continue;
+        };
+
+        code.push_str(&format!("    [{}] = {{\n", luau_string(locale)));
+        let kinds = [
+            ("cardinal", cardinal, rules.cardinal),
+            ("ordinal", ordinal, rules.ordinal),
+        ];
+        for (kind, _, rules) in kinds.iter().filter(|(_, used, _)| *used) {
+            code.push_str(&format!("        {} = function(n, i, v, w, f, t)\n", kind));
+            code.push_str(&plurals::rules_to_luau(rules, "            "));
+            code.push_str("        end,\n");
+        }
+        code.push_str("    },\n");
     }
+    code.push_str("}\n\n");
+
+    code.push_str("--- Get the CLDR plural category of a count\n");
+    code.push_str("--- @param locale string The locale whose rules to use\n");
+    code.push_str("--- @param count number|string The count (a string keeps visible decimals, e.g. \"1.50\")\n");
+    code.push_str(
+        "--- @param ordinal boolean? Use ordinal rules (1st, 2nd, 3rd) instead of cardinal\n",
+    );
+    code.push_str("--- @return string One of zero, one, two, few, many, other\n");
+    code.push_str("function Translations.pluralCategory(locale, count, ordinal)\n");
+    code.push_str(
+        "    local rules = pluralRules[locale] or pluralRules[string.match(locale, \"^%a+\")]\n",
+    );
+    code.push_str(
+        "    local rule = rules and (if ordinal then rules.ordinal else rules.cardinal)\n",
+    );
+    code.push_str("    if not rule then\n");
+    code.push_str("        return \"other\"\n");
+    code.push_str("    end\n");
+    code.push_str("    return rule(pluralOperands(count))\n");
+    code.push_str("end\n\n");
 }
 
 /// Generate a plural method
-fn generate_plural_method(code: &mut String, base_key: &str, _translations: &[&Translation]) {
+fn generate_plural_method(
+    code: &mut String,
+    base_key: &str,
+    translations: &[&Translation],
+    variants: bool,
+) {
     let method_name = base_key.replace(".", "_");
 
+    // Metadata may be attached to any of the plural forms
+    let mut forms = translations.to_vec();
+    forms.sort_by(|a, b| a.key.cmp(&b.key));
+    if let Some(documented) = forms.iter().find(|t| has_metadata(t)) {
+        generate_doc_comment(code, documented);
+    }
+
     code.push_str(&format!(
         "function Translations:{}(count, params)\n",
         method_name
     ));
-    code.push_str("    params = params or {}\n");
+    code.push_str("    params = table.clone(params or {})\n");
     code.push_str("    params.count = count\n");
     code.push_str("    \n");
-    code.push_str("    -- Determine plural category\n");
-    code.push_str("    local category = \"other\"\n");
-    code.push_str("    \n");
+    // Ordinal forms (`rank(ordinal:one)`) pick 1st/2nd/3rd-style categories
+    let ordinal = forms.iter().any(|t| plurals::is_ordinal_key(&t.key));
+    let (form_prefix, ordinal_arg) = if ordinal {
+        (plurals::ORDINAL_PREFIX, ", true")
+    } else {
+        ("", "")
+    };
 
-    // Generate category selection logic
-    code.push_str("    if self._locale == \"en\" then\n");
-    code.push_str("        if count == 1 then\n");
-    code.push_str("            category = \"one\"\n");
-    code.push_str("        end\n");
-    code.push_str("    elseif self._locale == \"ru\" or self._locale == \"uk\" then\n");
-    code.push_str("        local mod10 = math.abs(count) % 10\n");
-    code.push_str("        local mod100 = math.abs(count) % 100\n");
-    code.push_str("        if mod10 == 1 and mod100 ~= 11 then\n");
-    code.push_str("            category = \"one\"\n");
-    code.push_str(
-        "        elseif mod10 >= 2 and mod10 <= 4 and (mod100 < 12 or mod100 > 14) then\n",
-    );
-    code.push_str("            category = \"few\"\n");
-    code.push_str("        else\n");
-    code.push_str("            category = \"many\"\n");
-    code.push_str("        end\n");
-    code.push_str("    elseif self._locale == \"ar\" then\n");
-    code.push_str("        local absCount = math.abs(count)\n");
-    code.push_str("        local mod100 = absCount % 100\n");
-    code.push_str("        if absCount == 0 then\n");
-    code.push_str("            category = \"zero\"\n");
-    code.push_str("        elseif absCount == 1 then\n");
-    code.push_str("            category = \"one\"\n");
-    code.push_str("        elseif absCount == 2 then\n");
-    code.push_str("            category = \"two\"\n");
-    code.push_str("        elseif mod100 >= 3 and mod100 <= 10 then\n");
-    code.push_str("            category = \"few\"\n");
-    code.push_str("        elseif mod100 >= 11 and mod100 <= 99 then\n");
-    code.push_str("            category = \"many\"\n");
-    code.push_str("        end\n");
-    code.push_str("    end\n");
+    code.push_str("    -- Determine plural category (CLDR rules for the current locale)\n");
+    code.push_str(&format!(
+        "    local category = Translations.pluralCategory(self._locale, count{})\n",
+        ordinal_arg
+    ));
     code.push_str("    \n");
 
     // Generate key lookup with fallback
     code.push_str("    -- Try to get translation for category\n");
     code.push_str(&format!(
-        "    local key = \"{}(\" .. category .. \")\"\n",
-        base_key
+        "    local key = \"{}({}\" .. category .. \")\"\n",
+        base_key, form_prefix
     ));
     code.push_str("    local success, result = pcall(function()\n");
-    code.push_str("        return self._translator:FormatByKey(key, params)\n");
+    code.push_str(&format!(
+        "        return {}(key, params)\n",
+        lookup_fn(variants)
+    ));
     code.push_str("    end)\n");
     code.push_str("    \n");
     code.push_str("    if success then\n");
@@ -466,24 +1107,105 @@ fn generate_plural_method(code: &mut String, base_key: &str, _translations: &[&T
     code.push_str("    \n");
     code.push_str("    -- Fallback to 'other' category\n");
     code.push_str(&format!(
-        "    return self._translator:FormatByKey(\"{}(other)\", params)\n",
-        base_key
+        "    return {}(\"{}({}other)\", params)\n",
+        lookup_fn(variants),
+        base_key,
+        form_prefix
     ));
     code.push_str("end\n\n");
 }
 
+/// Generate a select method, which picks a `(select:case)` key by name
+fn generate_select_method(
+    code: &mut String,
+    base_key: &str,
+    translations: &[&Translation],
+    track_usage: bool,
+    variants: bool,
+) {
+    let method_name = base_key.replace(".", "_");
+
+    let mut cases = translations.to_vec();
+    cases.sort_by(|a, b| a.key.cmp(&b.key));
+    if let Some(documented) = cases.iter().find(|t| has_metadata(t)) {
+        generate_doc_comment(code, documented);
+    }
+
+    code.push_str(&format!(
+        "function Translations:{}(case, params)\n",
+        method_name
+    ));
+    if track_usage {
+        code.push_str(&format!("    self:_trackUsage(\"{}\")\n", base_key));
+    }
+    code.push_str("    -- Try to get translation for the case\n");
+    code.push_str(&format!(
+        "    local key = \"{}({}\" .. tostring(case) .. \")\"\n",
+        base_key,
+        selects::SELECT_PREFIX
+    ));
+    code.push_str("    local success, result = pcall(function()\n");
+    code.push_str(&format!(
+        "        return {}(key, params)\n",
+        lookup_fn(variants)
+    ));
+    code.push_str("    end)\n");
+    code.push_str("    \n");
+    code.push_str("    if success then\n");
+    code.push_str("        return result\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    -- Fallback to 'other' case\n");
+    code.push_str(&format!(
+        "    return {}(\"{}({}{})\", params)\n",
+        lookup_fn(variants),
+        base_key,
+        selects::SELECT_PREFIX,
+        selects::FALLBACK_CASE
+    ));
+    code.push_str("end\n\n");
+}
+
+/// Whether a translation carries any translator-facing metadata
+pub(super) fn has_metadata(translation: &Translation) -> bool {
+    translation.context.is_some()
+        || translation.description.is_some()
+        || translation.max_length.is_some()
+}
+
+/// Generate a doc comment from a translation's description, context and max length
+pub(super) fn generate_doc_comment(code: &mut String, translation: &Translation) {
+    if let Some(description) = &translation.description {
+        for line in description.lines() {
+            code.push_str(&format!("--- {}\n", line.trim_end()));
+        }
+    }
+    if let Some(context) = &translation.context {
+        code.push_str(&format!("--- Context: {}\n", context.replace('\n', " ")));
+    }
+    if let Some(max_length) = translation.max_length {
+        code.push_str(&format!("--- Max length: {} characters\n", max_length));
+    }
+}
+
 /// Generate namespace structure (syntax sugar for nested access)
 fn generate_namespace_structure(code: &mut String, translations: &[&Translation]) {
     code.push_str("-- Namespace structure (syntax sugar)\n\n");
 
-    // Separate plural and non-plural translations
+    // Separate plural, select, list and regular translations
     let mut plural_base_keys: HashSet<String> = HashSet::new();
+    let mut select_keys: HashSet<String> = HashSet::new();
+    let mut list_keys: HashSet<String> = HashSet::new();
     let mut regular_translations = Vec::new();
 
     for translation in translations {
         if plurals::is_plural_key(&translation.key) {
             let base_key = plurals::extract_base_key(&translation.key);
             plural_base_keys.insert(base_key);
+        } else if selects::is_select_key(&translation.key) {
+            select_keys.insert(selects::extract_base_key(&translation.key));
+        } else if lists::is_list_item_key(&translation.key) {
+            list_keys.insert(lists::extract_list_key(&translation.key));
         } else {
             regular_translations.push(*translation);
         }
@@ -505,8 +1227,12 @@ fn generate_namespace_structure(code: &mut String, translations: &[&Translation]
         }
     }
 
-    // Add namespaces for plural base keys
-    for base_key in &plural_base_keys {
+    // Add namespaces for plural base keys, selects and lists
+    for base_key in plural_base_keys
+        .iter()
+        .chain(&select_keys)
+        .chain(&list_keys)
+    {
         let parts: Vec<&str> = base_key.split('.').collect();
         for i in 0..parts.len() - 1 {
             let namespace = parts[0..=i].join(".");
@@ -535,6 +1261,8 @@ fn generate_namespace_structure(code: &mut String, translations: &[&Translation]
         let flat_method = translation.key.replace(".", "_");
         let params_with_format = format::extract_parameters_with_format(&translation.value);
 
+        generate_doc_comment(code, translation);
+
         if !params_with_format.is_empty() {
             code.push_str(&format!(
                 "function Translations.{}.{}(self, params)\n",
@@ -569,10 +1297,58 @@ fn generate_namespace_structure(code: &mut String, translations: &[&Translation]
         code.push_str(&format!("    return self:{}(count, params)\n", flat_method));
         code.push_str("end\n\n");
     }
+
+    // Generate namespace methods for selects (top-level selects only have flat methods)
+    let mut select_keys_sorted: Vec<_> = select_keys.iter().collect();
+    select_keys_sorted.sort();
+
+    for select_key in select_keys_sorted {
+        let Some((namespace, method)) = select_key.rsplit_once('.') else {
+            continue;
+        };
+        let flat_method = select_key.replace(".", "_");
+
+        code.push_str(&format!(
+            "function Translations.{}.{}(self, case, params)\n",
+            namespace, method
+        ));
+        code.push_str(&format!("    return self:{}(case, params)\n", flat_method));
+        code.push_str("end\n\n");
+    }
+
+    // Generate namespace methods for lists (top-level lists only have flat methods)
+    let mut list_keys_sorted: Vec<_> = list_keys.iter().collect();
+    list_keys_sorted.sort();
+
+    for list_key in list_keys_sorted {
+        let Some((namespace, method)) = list_key.rsplit_once('.') else {
+            continue;
+        };
+        let flat_method = list_key.replace(".", "_");
+
+        for suffix in ["", "_random"] {
+            code.push_str(&format!(
+                "function Translations.{}.{}{}(self, params)\n",
+                namespace, method, suffix
+            ));
+            code.push_str(&format!(
+                "    return self:{}{}(params)\n",
+                flat_method, suffix
+            ));
+            code.push_str("end\n\n");
+        }
+    }
 }
 
 /// Extract parameter names from a translation string
+#[allow(dead_code)] // Public API for library users
 pub fn extract_parameters(text: &str) -> Vec<String> {
+    if icu::is_icu_message(text) {
+        if let Ok(parts) = icu::parse_message(text) {
+            return icu::parameters(&parts).into_keys().collect();
+        }
+    }
+
     let mut params = Vec::new();
     let mut in_param = false;
     let mut current_param = String::new();
@@ -613,6 +1389,7 @@ pub fn extract_parameters(text: &str) -> Vec<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{EmbeddedChunks, DEFAULT_MODULE_NAME};
 
     #[test]
     fn test_extract_parameters() {
@@ -629,7 +1406,14 @@ mod tests {
     #[test]
     fn test_generate_constructor_server_safe() {
         let mut code = String::new();
-        generate_constructor(&mut code, None);
+        generate_constructor(
+            &mut code,
+            "en",
+            DEFAULT_MODULE_NAME,
+            None,
+            Runtime::default(),
+            false,
+        );
 
         // Should NOT contain game.Players.LocalPlayer
         assert!(!code.contains("game.Players.LocalPlayer"));
@@ -647,12 +1431,18 @@ mod tests {
                 value: "{count} item".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.messages.items(other)".to_string(),
                 value: "{count} items".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -685,18 +1475,31 @@ mod tests {
                 value: "{count} item".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.messages.items(other)".to_string(),
                 value: "{count} items".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
         let refs: Vec<_> = translations.iter().collect();
         let mut code = String::new();
-        generate_flat_methods(&mut code, &refs, None);
+        generate_flat_methods(
+            &mut code,
+            &refs,
+            &Messages::new(),
+            None,
+            false,
+            &Formatters::new(),
+        );
 
         // Should generate ONE flat method for plural base key
         let count = code
@@ -709,7 +1512,7 @@ mod tests {
 
         // Should have plural category logic
         assert!(code.contains("Determine plural category"));
-        assert!(code.contains("local category = \"other\""));
+        assert!(code.contains("Translations.pluralCategory(self._locale, count)"));
     }
 
     #[test]
@@ -720,18 +1523,27 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.messages.items(one)".to_string(),
                 value: "{count} item".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.messages.items(other)".to_string(),
                 value: "{count} items".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -758,6 +1570,40 @@ mod tests {
         assert!(code.contains("return Translations"));
     }
 
+    #[test]
+    fn test_generate_doc_comments_from_metadata() {
+        let translations = vec![
+            Translation {
+                key: "ui.buy".to_string(),
+                value: "Buy".to_string(),
+                locale: "en".to_string(),
+                context: Some("button".to_string()),
+                description: Some("Shop purchase button".to_string()),
+                max_length: Some(12),
+                location: None,
+            },
+            Translation {
+                key: "ui.sell".to_string(),
+                value: "Sell".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+        ];
+
+        let code = generate_luau(&translations, "en").unwrap();
+
+        assert!(code.contains(
+            "--- Shop purchase button\n--- Context: button\n--- Max length: 12 characters\nfunction Translations:ui_buy()"
+        ));
+        assert!(code.contains(
+            "--- Shop purchase button\n--- Context: button\n--- Max length: 12 characters\nfunction Translations.ui.buy(self)"
+        ));
+        assert!(code.contains("end\n\nfunction Translations:ui_sell()"));
+    }
+
     #[test]
     fn test_generate_with_format_specifiers() {
         let translations = [
@@ -766,23 +1612,481 @@ mod tests {
                 value: "Price: ${price:fixed(2)}".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.score".to_string(),
                 value: "Score: {score:int}".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
         let refs: Vec<_> = translations.iter().collect();
         let mut code = String::new();
-        generate_flat_methods(&mut code, &refs, None);
+        generate_flat_methods(
+            &mut code,
+            &refs,
+            &Messages::new(),
+            None,
+            false,
+            &Formatters::new(),
+        );
 
         // Should apply format specifiers
-        assert!(code.contains("string.format(\"%.2f\""));
+        assert!(code.contains("params.price = formatNumber(self._locale, params.price, 2, 2)"));
         assert!(code.contains("math.floor"));
     }
+
+    #[test]
+    fn test_generate_custom_formatters() {
+        let translation = |key: &str, value: &str| Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+        let translations = vec![
+            translation("ui.hp", "HP: {hp:health}"),
+            translation(
+                "ui.boss",
+                "{n, plural, one {# boss} other {# bosses}} at {hp:health}",
+            ),
+        ];
+        let formatters = Formatters::from([(
+            "health".to_string(),
+            crate::config::FormatterConfig {
+                module: "game.ReplicatedStorage.Formatters".to_string(),
+                function: "heartBar".to_string(),
+                input: "number".to_string(),
+            },
+        )]);
+        let generate = |formatters: Formatters| {
+            let config = Config {
+                formatters,
+                ..Config::default()
+            };
+            generate_luau_for_config(&translations, &config, &[])
+        };
+
+        let code = generate(formatters).unwrap();
+        assert!(code.contains(
+            "    params.hp = require(game.ReplicatedStorage.Formatters).heartBar(params.hp, self._locale)\n"
+        ));
+        assert!(code.contains(
+            "\" at \" .. tostring(require(game.ReplicatedStorage.Formatters).heartBar(params.hp, locale))"
+        ));
+        // Custom specifiers don't need the number formats
+        assert!(!code.contains("local function formatNumber"));
+
+        let error = generate(Formatters::new()).unwrap_err().to_string();
+        assert!(error.contains("Unknown format specifier 'health' for 'hp'"));
+    }
+
+    #[test]
+    fn test_generate_number_formats() {
+        let translations = vec![
+            Translation {
+                key: "ui.gems".to_string(),
+                value: "Gems: {gems:compact}".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+            Translation {
+                key: "ui.score".to_string(),
+                value: "Score: {score:int}".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+        ];
+        let config = Config {
+            supported_locales: vec!["en".to_string(), "de".to_string()],
+            ..Config::default()
+        };
+
+        let code = generate_luau_for_config(&translations, &config, &[]).unwrap();
+
+        // Tables for every supported locale, declared before the methods use them
+        assert!(code.contains("local numberFormats = {\n    [\"en\"] = {\n"));
+        assert!(code.contains("            { divisor = 1e6, suffix = \"\\u{a0}Mio.\" },\n"));
+        // Formatted values go into a copy, never into the caller's table
+        assert!(code.contains(
+            "function Translations:ui_gems(params)\n    params = table.clone(params or {})\n    params.gems = formatCompact(self._locale, params.gems)\n"
+        ));
+        assert!(
+            code.find("local function formatCompact").unwrap()
+                < code.find("function Translations:ui_gems").unwrap()
+        );
+
+        // No tables when only locale-independent specifiers are used
+        let code = generate_luau_for_config(&translations[1..], &config, &[]).unwrap();
+        assert!(!code.contains("numberFormats"));
+    }
+
+    #[test]
+    fn test_generate_time_units() {
+        let translations = vec![Translation {
+            key: "event.ends".to_string(),
+            value: "Ends {ends:relative} ({left:duration} left) on {day:date(long)}".to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }];
+        let config = Config {
+            supported_locales: vec!["en".to_string(), "ru".to_string()],
+            ..Config::default()
+        };
+
+        let code = generate_luau_for_config(&translations, &config, &[]).unwrap();
+
+        // Relative times need the number formats and the cardinal plural rules
+        assert!(code.contains("local numberFormats = {\n"));
+        assert!(code.contains("        cardinal = function(n, i, v, w, f, t)\n"));
+        assert!(code.contains("local timeUnits = {\n"));
+        assert!(code.contains("params.ends = formatRelative(self._locale, params.ends)"));
+        assert!(code.contains("params.left = formatDuration(self._locale, params.left)"));
+        assert!(code.contains("params.day = params.day:FormatLocalTime(\"LL\", self._locale)"));
+        assert!(
+            code.find("local function formatDuration").unwrap()
+                < code.find("function Translations:event_ends").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generate_list_methods() {
+        let translations = vec![
+            Translation {
+                key: "loading.tips.10".to_string(),
+                value: "Eleventh".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+            Translation {
+                key: "loading.tips.0".to_string(),
+                value: "Jump".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+            Translation {
+                key: "loading.tips.1".to_string(),
+                value: "Run".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+        ];
+
+        let code = generate_luau(&translations, "en").unwrap();
+
+        // Items in index order, not string order
+        let first = code
+            .find("FormatByKey(\"loading.tips.0\", params)")
+            .unwrap();
+        let second = code
+            .find("FormatByKey(\"loading.tips.1\", params)")
+            .unwrap();
+        let last = code
+            .find("FormatByKey(\"loading.tips.10\", params)")
+            .unwrap();
+        assert!(first < second && second < last);
+
+        assert!(code.contains("function Translations:loading_tips(params)"));
+        assert!(code.contains("function Translations:loading_tips_random(params)"));
+        assert!(code.contains("return items[math.random(#items)]"));
+        assert!(code.contains("function Translations.loading.tips(self, params)"));
+        assert!(code.contains("function Translations.loading.tips_random(self, params)"));
+
+        // No per-item methods with numeric names
+        assert!(!code.contains("loading_tips_0"));
+        assert!(!code.contains("Translations.loading.tips.0"));
+    }
+
+    #[test]
+    fn test_generate_plural_rules_for_supported_locales() {
+        let translation = |key: &str, value: &str| Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+        let translations = vec![
+            translation("items(one)", "{count} item"),
+            translation("items(other)", "{count} items"),
+            translation("rank(ordinal:one)", "{count}st"),
+            translation("rank(ordinal:other)", "{count}th"),
+        ];
+        let config = Config {
+            supported_locales: vec!["en".to_string(), "pl".to_string()],
+            ..Config::default()
+        };
+
+        let code = generate_luau_for_config(&translations, &config, &[]).unwrap();
+
+        assert!(code.contains("    [\"en\"] = {"));
+        assert!(code.contains("    [\"pl\"] = {"));
+        assert!(!code.contains("[\"ru\"]"));
+        assert!(code.contains("function Translations.pluralCategory(locale, count, ordinal)"));
+
+        // Ordinal keys use the ordinal rules and forms
+        assert!(code.contains("Translations.pluralCategory(self._locale, count, true)"));
+        assert!(code.contains("local key = \"rank(ordinal:\" .. category .. \")\""));
+        assert!(code.contains("local key = \"items(\" .. category .. \")\""));
+    }
+
+    #[test]
+    fn test_generate_select_methods() {
+        let translations: Vec<_> = ["team.banner(select:red)", "team.banner(select:other)"]
+            .iter()
+            .map(|key| Translation {
+                key: key.to_string(),
+                value: "Go!".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            })
+            .collect();
+
+        let code = generate_luau(&translations, "en").unwrap();
+
+        assert!(code.contains("function Translations:team_banner(case, params)"));
+        assert!(code.contains("    local key = \"team.banner(select:\" .. tostring(case) .. \")\""));
+        assert!(code.contains(
+            "    return self._translator:FormatByKey(\"team.banner(select:other)\", params)"
+        ));
+        assert!(code.contains("function Translations.team.banner(self, case, params)"));
+        assert!(!code.contains("function Translations:team_banner_red"));
+    }
+
+    #[test]
+    fn test_generate_icu_messages() {
+        let translation = |value: &str, locale: &str| Translation {
+            key: "shop.cart".to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+        let translations = vec![
+            translation("{count, plural, one {# item} other {# items}}", "en"),
+            translation("{count} przedmiotów", "pl"),
+        ];
+        let config = Config {
+            supported_locales: vec!["en".to_string(), "pl".to_string()],
+            ..Config::default()
+        };
+
+        let code = generate_luau_for_config(&translations, &config, &[]).unwrap();
+
+        // Plural rules are needed for the ICU plural even without plural keys
+        assert!(code.contains("function Translations.pluralCategory(locale, count, ordinal)"));
+        assert!(code.contains("local function icuPlural(locale, value, offset, ordinal, cases)"));
+        assert!(code.contains(
+            "            return icuPlural(locale, params.count, 0, false, { [\"one\"] = function(n) return n .. \" item\" end, [\"other\"] = function(n) return n .. \" items\" end })"
+        ));
+        // Every locale is compiled, even where the value has no ICU arguments
+        assert!(code.contains("            return tostring(params.count) .. \" przedmiotów\""));
+        assert!(code.contains("    return self:_formatMessage(\"shop.cart\", params)"));
+        assert!(code.contains("function Translations.shop.cart(self, params)"));
+    }
+
+    #[test]
+    fn test_generate_icu_message_error() {
+        let translations = vec![Translation {
+            key: "shop.cart".to_string(),
+            value: "{count, plural, one {# item}}".to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }];
+
+        let err = generate_luau(&translations, "en").unwrap_err().to_string();
+
+        assert!(err.contains("Invalid ICU message"));
+        assert!(err.contains(
+            "Key 'shop.cart' (en): Argument 'count' is missing the required 'other' case"
+        ));
+    }
+
+    #[test]
+    fn test_generate_escaped_braces() {
+        let translation = |value: &str, locale: &str| Translation {
+            key: "ui.hint".to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+        let translations = vec![
+            translation("Press {{E}} to {action}", "en"),
+            translation("Tekan E untuk {action}", "id"),
+        ];
+
+        let code = generate_luau(&translations, "en").unwrap();
+
+        // Compiled into the module for every locale, as translators would keep the escapes
+        assert!(code.contains("return self:_formatMessage(\"ui.hint\", params)"));
+        assert!(code.contains("return \"Press {E} to \" .. tostring(params.action)"));
+        assert!(code.contains("return \"Tekan E untuk \" .. tostring(params.action)"));
+    }
+
+    #[test]
+    fn test_generate_variants() {
+        let translation = |key: &str, value: &str, locale: &str| Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+
+        let translations = vec![
+            translation("ui.buy", "Buy", "en"),
+            translation("ui.greeting", "Hi, {name}!", "en"),
+        ];
+        let variants = vec![Variant {
+            name: "halloween".to_string(),
+            start: Some(1_760_918_400),
+            end: None,
+            segments: vec!["vip".to_string()],
+            translations: vec![translation("ui.greeting", "Boo, \"{name}\"!", "en")],
+        }];
+
+        let code = generate_luau_for_config(&translations, &Config::default(), &variants).unwrap();
+
+        assert!(code.contains("    [\"halloween\"] = {\n        startTime = 1760918400,\n"));
+        assert!(code.contains("        segments = { \"vip\" },\n"));
+        assert!(code.contains("                [\"ui.greeting\"] = \"Boo, \\\"{name}\\\"!\",\n"));
+        assert!(code.contains("function Translations:setVariant(name)"));
+        assert!(code.contains("function Translations:setSegments(segments)"));
+        assert!(code.contains("function Translations:getVariant()"));
+
+        // Every lookup goes through the variant-aware helper
+        assert!(code.contains("    return self:_formatByKey(\"ui.buy\")\n"));
+        assert!(code.contains("    return self:_formatByKey(\"ui.greeting\", params)\n"));
+        assert_eq!(
+            code.matches("self._translator:FormatByKey(key, params)")
+                .count(),
+            1
+        );
+
+        // Without variants the generated code is unchanged
+        let plain = generate_luau(&translations, "en").unwrap();
+        assert!(!plain.contains("_formatByKey"));
+        assert!(!plain.contains("setVariant"));
+    }
+
+    #[test]
+    fn test_generate_embedded_runtime() {
+        let translation = |key: &str, value: &str, locale: &str| Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+
+        let translations = vec![
+            translation("ui.buy", "Buy", "en"),
+            translation("ui.buy", "Beli", "id"),
+        ];
+        let config = Config {
+            supported_locales: vec!["en".to_string(), "id".to_string()],
+            runtime: Runtime::Embedded,
+            embedded_chunks: EmbeddedChunks::Single,
+            ..Config::default()
+        };
+
+        let code = generate_luau_for_config(&translations, &config, &[]).unwrap();
+
+        assert!(code.contains("    [\"id\"] = {\n        [\"ui.buy\"] = \"Beli\",\n"));
+        assert!(code.contains("    self._translator = newTranslator(self._locale)\n"));
+        assert!(code.contains("    self._translator = newTranslator(locale)\n"));
+        assert!(!code.contains("GetTranslatorForLocaleAsync"));
+        // The methods keep using the translator, so the public API is unchanged
+        assert!(code.contains("    return self._translator:FormatByKey(\"ui.buy\")\n"));
+        // The translator is defined before the constructor captures it
+        assert!(
+            code.find("local function newTranslator").unwrap()
+                < code.find("function Translations.new").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_generate_fallback_chains_runtime() {
+        let translation = |key: &str, value: &str, locale: &str| Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+
+        let translations = vec![
+            translation("ui.buy", "Buy", "id"),
+            translation("ui.sell", "Sell", "id"),
+            translation("ui.buy", "Beli", "ms"),
+            translation("ui.sell", "Jual", "jv"),
+        ];
+        let config = Config {
+            base_locale: "id".to_string(),
+            supported_locales: vec!["id".to_string(), "ms".to_string(), "jv".to_string()],
+            fallbacks: BTreeMap::from([("ms".to_string(), vec!["jv".to_string()])]),
+            ..Config::default()
+        };
+
+        let code = generate_luau_for_config(&translations, &config, &[]).unwrap();
+
+        assert!(code.contains("    [\"ms\"] = { \"ms\", \"jv\", \"id\" },\n"));
+        assert!(code.contains("    [\"ms\"] = {\n        [\"ui.sell\"] = \"jv\",\n    },\n"));
+        assert!(code.contains("    self._translator = withFallbacks(translator, self._locale)\n"));
+        assert!(code.contains("    self._translator = withFallbacks(translator, locale)\n"));
+        // The base locale replaces the hardcoded English defaults
+        assert!(code.contains("    self._locale = locale or \"id\"\n"));
+        assert!(code.contains("    local fallbackKey = \"assets.\" .. assetKey .. \".id\"\n"));
+        assert!(code.contains("LocalizationService:GetTranslatorForLocaleAsync(\"id\")"));
+        assert!(code.contains("    return countryLocaleMap[countryCode] or \"id\"\n"));
+    }
 }
 
 #[test]
@@ -794,6 +2098,9 @@ fn test_analytics_generation() {
         value: "Test Value".to_string(),
         locale: "en".to_string(),
         context: None,
+        description: None,
+        max_length: None,
+        location: None,
     }];
 
     let analytics_config = AnalyticsConfig {
@@ -828,6 +2135,9 @@ fn test_analytics_disabled() {
         value: "Test Value".to_string(),
         locale: "en".to_string(),
         context: None,
+        description: None,
+        max_length: None,
+        location: None,
     }];
 
     // No analytics config
@@ -848,6 +2158,9 @@ fn test_analytics_with_custom_callback() {
         value: "Test Value".to_string(),
         locale: "en".to_string(),
         context: None,
+        description: None,
+        max_length: None,
+        location: None,
     }];
 
     let analytics_config = AnalyticsConfig {
@@ -1,12 +1,19 @@
 //! Code generation
 //!
 //! This module generates Luau code, type definitions, and CSV files
-//! from parsed translation data.
+//! from parsed translation data, or a single strictly typed Luau module.
 
 pub mod csv;
+pub mod embedded;
+mod fallbacks;
 pub mod luau;
+mod numbers;
+pub mod strict;
+mod time_units;
 pub mod types;
 
 pub use csv::*;
+pub use embedded::*;
 pub use luau::*;
+pub use strict::*;
 pub use types::*;
@@ -0,0 +1,188 @@
+//! Locale-aware number formatting
+//!
+//! `num`, `fixed`, `compact`, `percent` and `currency` parameters are formatted
+//! at runtime with the CLDR separators, percent pattern and compact suffixes of
+//! the player's locale. The tables are generated for the supported locales only.
+
+use crate::utils::format::luau_string;
+use crate::utils::numbers;
+
+/// Generate the number formats of `locales` and the `formatNumber`,
+/// `formatCompact`, `formatPercent` and `formatCurrency` helpers
+///
+/// Locales without CLDR data use English symbols. Other locales use their
+/// language's format, or the base locale's.
+pub(super) fn generate_number_formats(
+    code: &mut String,
+    base_locale: &str,
+    locales: &[String],
+    typed: bool,
+) {
+    code.push_str("-- Number formats (CLDR) for the supported locales\n");
+    if typed {
+        code.push_str("type NumberFormat = {\n");
+        code.push_str("    decimal: string,\n");
+        code.push_str("    group: string,\n");
+        code.push_str("    minGrouping: number,\n");
+        code.push_str("    percent: string,\n");
+        code.push_str("    compact: { { divisor: number, suffix: string } },\n");
+        code.push_str("}\n\n");
+        code.push_str("local numberFormats: { [string]: NumberFormat } = {\n");
+    } else {
+        code.push_str("local numberFormats = {\n");
+    }
+    for locale in locales {
+        let symbols = numbers::symbols_for_locale(locale).unwrap_or_else(|| {
+            log::warn!(
+                "No CLDR number symbols for locale '{}', numbers will use English separators",
+                locale
+            );
+            numbers::symbols_for_locale("en").expect("English number symbols")
+        });
+
+        code.push_str(&format!("    [{}] = {{\n", luau_string(locale)));
+        code.push_str(&format!(
+            "        decimal = {},\n",
+            luau_string(symbols.decimal)
+        ));
+        code.push_str(&format!(
+            "        group = {},\n",
+            luau_string(symbols.group)
+        ));
+        code.push_str(&format!(
+            "        minGrouping = {},\n",
+            symbols.min_grouping
+        ));
+        code.push_str(&format!(
+            "        percent = {},\n",
+            luau_string(symbols.percent)
+        ));
+        // Largest divisor first, as formatCompact uses the first one that fits
+        code.push_str("        compact = {\n");
+        for (power, suffix) in symbols.compact.iter().rev() {
+            code.push_str(&format!(
+                "            {{ divisor = 1e{}, suffix = {} }},\n",
+                power,
+                luau_string(suffix)
+            ));
+        }
+        code.push_str("        },\n");
+        code.push_str("    },\n");
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("--- Number format of a locale (other locales use their language's, then the base locale's)\n");
+    if typed {
+        code.push_str("local function numberFormat(locale: string): NumberFormat\n");
+    } else {
+        code.push_str("local function numberFormat(locale)\n");
+    }
+    code.push_str("    return numberFormats[locale]\n");
+    code.push_str("        or numberFormats[string.match(locale, \"^%a+\") or locale]\n");
+    code.push_str(&format!(
+        "        or numberFormats[{}]\n",
+        luau_string(base_locale)
+    ));
+    code.push_str("end\n\n");
+
+    code.push_str("--- Format a number with the locale's separators, rounded to maxDecimals and padded to minDecimals\n");
+    if typed {
+        code.push_str("local function formatNumber(locale: string, value: any, minDecimals: number, maxDecimals: number): string\n");
+    } else {
+        code.push_str("local function formatNumber(locale, value, minDecimals, maxDecimals)\n");
+    }
+    code.push_str("    local format = numberFormat(locale)\n");
+    code.push_str("    local number = tonumber(value) or 0\n");
+    code.push_str(
+        "    local text = string.format(\"%.\" .. maxDecimals .. \"f\", math.abs(number))\n",
+    );
+    code.push_str("    local integer, fraction = string.match(text, \"^(%d+)%.?(%d*)$\")\n");
+    code.push_str("    if not integer or not fraction then\n");
+    code.push_str("        return tostring(number)\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    fraction = string.gsub(fraction, \"0+$\", \"\")\n");
+    code.push_str("    fraction ..= string.rep(\"0\", minDecimals - #fraction)\n");
+    code.push_str("    if #integer >= 3 + format.minGrouping then\n");
+    code.push_str("        local head = (#integer - 1) % 3 + 1\n");
+    code.push_str("        local grouped = string.sub(integer, 1, head)\n");
+    code.push_str("        for i = head + 1, #integer, 3 do\n");
+    code.push_str("            grouped ..= format.group .. string.sub(integer, i, i + 2)\n");
+    code.push_str("        end\n");
+    code.push_str("        integer = grouped\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str(
+        "    local sign = if number < 0 and string.find(text, \"[1-9]\") then \"-\" else \"\"\n",
+    );
+    code.push_str("    if fraction == \"\" then\n");
+    code.push_str("        return sign .. integer\n");
+    code.push_str("    end\n");
+    code.push_str("    return sign .. integer .. format.decimal .. fraction\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Format a number in the locale's short compact form (1.2K, 1,2 Mio.)\n");
+    if typed {
+        code.push_str("local function formatCompact(locale: string, value: any): string\n");
+    } else {
+        code.push_str("local function formatCompact(locale, value)\n");
+    }
+    code.push_str("    local number = tonumber(value) or 0\n");
+    code.push_str("    for _, unit in ipairs(numberFormat(locale).compact) do\n");
+    code.push_str("        if math.abs(number) >= unit.divisor then\n");
+    code.push_str("            local scaled = number / unit.divisor\n");
+    code.push_str("            local decimals = if math.abs(scaled) < 10 then 1 else 0\n");
+    code.push_str("            return formatNumber(locale, scaled, 0, decimals) .. unit.suffix\n");
+    code.push_str("        end\n");
+    code.push_str("    end\n");
+    code.push_str("    return formatNumber(locale, number, 0, 0)\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Format a ratio (0.25) as a whole percentage with the locale's pattern\n");
+    if typed {
+        code.push_str("local function formatPercent(locale: string, value: any): string\n");
+    } else {
+        code.push_str("local function formatPercent(locale, value)\n");
+    }
+    code.push_str("    local text = formatNumber(locale, (tonumber(value) or 0) * 100, 0, 0)\n");
+    code.push_str("    return (string.gsub(numberFormat(locale).percent, \"#\", text))\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Format an amount after a currency symbol (the Robux icon, or the unit)\n");
+    if typed {
+        code.push_str("local function formatCurrency(locale: string, value: any, symbol: string, decimals: number): string\n");
+    } else {
+        code.push_str("local function formatCurrency(locale, value, symbol, decimals)\n");
+    }
+    code.push_str("    return symbol .. formatNumber(locale, value, decimals, decimals)\n");
+    code.push_str("end\n\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_number_formats() {
+        let locales = vec!["en".to_string(), "de".to_string(), "xx".to_string()];
+
+        let mut code = String::new();
+        generate_number_formats(&mut code, "en", &locales, false);
+
+        assert!(
+            code.contains("    [\"de\"] = {\n        decimal = \",\",\n        group = \".\",\n")
+        );
+        assert!(code.contains("            { divisor = 1e12, suffix = \"T\" },\n"));
+        // Locales without data use English symbols
+        assert!(code.contains("    [\"xx\"] = {\n        decimal = \".\",\n"));
+        assert!(code.contains("        or numberFormats[\"en\"]\n"));
+        assert!(
+            code.contains("local function formatNumber(locale, value, minDecimals, maxDecimals)\n")
+        );
+
+        let mut code = String::new();
+        generate_number_formats(&mut code, "en", &locales, true);
+        assert!(code.contains("local numberFormats: { [string]: NumberFormat } = {\n"));
+        assert!(code.contains("local function formatCompact(locale: string, value: any): string\n"));
+    }
+}
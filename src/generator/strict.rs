@@ -0,0 +1,1162 @@
+//! Strict Luau module
+//!
+//! With `luau_output: strict`, the runtime and its types are generated as a
+//! single `Translations.luau` module that type checks under `--!strict`: every
+//! function is annotated, the `Translations` and `TranslationsInstance` types
+//! (named after the `namespace`, if set) are exported, and `any` only appears
+//! in the module's private types.
+
+use super::embedded::generate_embedded_translator;
+use super::fallbacks::{fallback_keys, generate_fallback_chains, generate_fallback_translator};
+use super::luau::{
+    compile_messages, generate_doc_comment, has_metadata, lookup_fn, plural_rule_kinds,
+    time_unit_kinds, uses_format, Messages,
+};
+use super::numbers::generate_number_formats;
+use super::time_units::generate_time_units;
+use super::types::generate_type_declarations;
+use crate::config::{AnalyticsConfig, Config, Runtime};
+use crate::parser::{Translation, Variant};
+use crate::utils::format::{self, luau_string, Formatters};
+use crate::utils::{icu, lists, locales, plurals, selects};
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+/// Generate a strictly typed Luau module with its type definitions inline
+///
+/// With [`Runtime::Embedded`], the strings of `supported_locales` are baked into
+/// the module and read without `LocalizationService`, or required from the
+/// modules written by [`generate_locale_modules`](super::generate_locale_modules)
+/// when `embedded_chunks` splits them. Lookups try the current locale, its
+/// `fallbacks`, then the base locale. The exported types are named after the
+/// config's module name, and parameters with the custom specifiers of
+/// `formatters` have their input type.
+pub fn generate_strict_luau(
+    translations: &[Translation],
+    config: &Config,
+    variants: &[Variant],
+) -> Result<String> {
+    let base_locale = config.base_locale.as_str();
+    let supported_locales = config.supported_locales.as_slice();
+    let analytics_config = config.analytics.as_ref();
+    let runtime = config.runtime;
+    let chunks = config.embedded_chunks;
+    let fallbacks = &config.fallbacks;
+    let module_name = config.module_name();
+    let formatters = &config.formatters;
+    let mut code = String::new();
+
+    code.push_str("--!strict\n");
+    code.push_str("--[[\n");
+    code.push_str("    Roblox Slang - Type-Safe Internationalization\n");
+    code.push_str("    \n");
+    code.push_str("    This file is auto-generated by roblox-slang CLI tool.\n");
+    code.push_str("    DO NOT MODIFY BY HAND - Your changes will be overwritten!\n");
+    code.push_str("    \n");
+    code.push_str("    Generated from translation files in your project.\n");
+    code.push_str("    To update translations, edit your JSON/YAML files and run:\n");
+    code.push_str("        roblox-slang build\n");
+    code.push_str("    \n");
+    code.push_str("    The module is fully typed: require it from --!strict scripts and\n");
+    code.push_str(&format!(
+        "    use the exported {} and {}Instance types.\n",
+        module_name, module_name
+    ));
+    code.push_str("    \n");
+    code.push_str("    Learn more: https://github.com/mathtechstudio/roblox-slang\n");
+    code.push_str("--]]\n\n");
+
+    let base_translations: Vec<_> = translations
+        .iter()
+        .filter(|t| t.locale == base_locale)
+        .collect();
+
+    if base_translations.is_empty() {
+        return Ok(code + "-- No translations found\nreturn {}\n");
+    }
+
+    let analytics = analytics_config.filter(|c| c.enabled);
+    let messages = compile_messages(translations, &base_translations, formatters)?;
+
+    // Public types
+    generate_type_declarations(
+        &mut code,
+        module_name,
+        &base_translations,
+        variants,
+        analytics,
+        false,
+        formatters,
+    );
+    code.push('\n');
+
+    let embedded = runtime == Runtime::Embedded;
+    // Keys LocalizationService translators have to look up in a fallback locale
+    let keys = if embedded {
+        Default::default()
+    } else {
+        fallback_keys(
+            translations,
+            base_locale,
+            supported_locales,
+            fallbacks,
+            &messages,
+        )
+    };
+    let wrap_translator = !keys.is_empty();
+    generate_private_types(
+        &mut code,
+        module_name,
+        analytics,
+        !variants.is_empty(),
+        !messages.is_empty(),
+        embedded,
+        wrap_translator,
+    );
+
+    generate_fallback_chains(&mut code, base_locale, supported_locales, fallbacks, true);
+
+    if embedded {
+        generate_embedded_translator(
+            &mut code,
+            translations,
+            supported_locales,
+            &messages,
+            chunks,
+            module_name,
+            true,
+        );
+    } else {
+        code.push_str("local LocalizationService = game:GetService(\"LocalizationService\")\n\n");
+        if wrap_translator {
+            generate_fallback_translator(&mut code, &keys, true);
+        }
+    }
+    code.push_str("local Translations = {}\n");
+    code.push_str("Translations.__index = Translations\n\n");
+
+    generate_constructor(&mut code, base_locale, analytics, embedded, wrap_translator);
+    generate_locale_detection(&mut code, base_locale, embedded);
+
+    if let Some(config) = analytics {
+        generate_analytics_methods(&mut code, config);
+    }
+
+    if !variants.is_empty() {
+        generate_variant_methods(&mut code, variants);
+    }
+
+    if uses_format(
+        &base_translations,
+        &messages,
+        format::FormatSpecifier::is_locale_aware,
+        formatters,
+    ) {
+        generate_number_formats(&mut code, base_locale, supported_locales, true);
+    }
+
+    let (cardinal, ordinal) = plural_rule_kinds(&base_translations, &messages, formatters);
+    if cardinal || ordinal {
+        generate_plural_rules(&mut code, supported_locales, cardinal, ordinal);
+    }
+
+    let (relative, duration) = time_unit_kinds(&base_translations, &messages, formatters);
+    if relative || duration {
+        generate_time_units(
+            &mut code,
+            base_locale,
+            supported_locales,
+            relative,
+            duration,
+            true,
+        );
+    }
+
+    if !messages.is_empty() {
+        generate_message_runtime(&mut code, &messages, base_locale);
+    }
+
+    let track_usage = analytics.is_some_and(|c| c.track_usage);
+    let track_missing = analytics.is_some_and(|c| c.track_missing);
+    let mut namespaces = Namespace::default();
+    generate_methods(
+        &mut code,
+        &base_translations,
+        &messages,
+        (track_usage, track_missing),
+        !variants.is_empty(),
+        &mut namespaces,
+        formatters,
+    );
+
+    // Namespace structure (syntax sugar), as one table literal per top-level namespace
+    code.push_str("-- Namespace structure (syntax sugar)\n\n");
+    for (name, namespace) in &namespaces.namespaces {
+        code.push_str(&format!("Translations.{} = {{\n", name));
+        generate_namespace(&mut code, namespace, "    ");
+        code.push_str("}\n\n");
+    }
+
+    code.push_str(&format!(
+        "return (Translations :: any) :: {}\n",
+        module_name
+    ));
+
+    Ok(code)
+}
+
+/// Generate the types of the instance's private fields and internal methods
+fn generate_private_types(
+    code: &mut String,
+    module_name: &str,
+    analytics: Option<&AnalyticsConfig>,
+    variants: bool,
+    messages: bool,
+    embedded: bool,
+    wrap_translator: bool,
+) {
+    code.push_str("-- Private types\n");
+    code.push_str("type Params = { [string]: any }\n\n");
+    if wrap_translator {
+        code.push_str("type FallbackTranslator = {\n");
+        code.push_str("    LocaleId: string,\n");
+        code.push_str(
+            "    FormatByKey: (self: FallbackTranslator, key: string, params: Params?) -> string,\n",
+        );
+        code.push_str("}\n\n");
+    }
+    if embedded {
+        code.push_str("type EmbeddedTranslator = {\n");
+        code.push_str("    LocaleId: string,\n");
+        code.push_str(
+            "    FormatByKey: (self: EmbeddedTranslator, key: string, params: Params?) -> string,\n",
+        );
+        code.push_str("}\n\n");
+    }
+    code.push_str(&format!("type Self = {}Instance & {{\n", module_name));
+    code.push_str("    _locale: string,\n");
+    code.push_str(if embedded {
+        "    _translator: EmbeddedTranslator,\n"
+    } else if wrap_translator {
+        "    _translator: FallbackTranslator,\n"
+    } else {
+        "    _translator: Translator,\n"
+    });
+    code.push_str(
+        "    _localeChangedCallbacks: { (newLocale: string, oldLocale: string) -> () },\n",
+    );
+    if variants {
+        code.push_str("    _variant: string?,\n");
+        code.push_str("    _segments: { [string]: boolean }?,\n");
+        code.push_str("    _formatByKey: (self: Self, key: string, params: Params?) -> string,\n");
+    }
+    if messages {
+        code.push_str(
+            "    _formatMessage: (self: Self, key: string, params: Params?) -> string,\n",
+        );
+    }
+    if let Some(config) = analytics {
+        code.push_str("    _analytics_enabled: boolean,\n");
+        code.push_str("    _track_missing: boolean,\n");
+        code.push_str("    _track_usage: boolean,\n");
+        code.push_str("    _usage_stats: { [string]: number },\n");
+        code.push_str("    _analytics_callback: ((event: string, data: Params) -> ())?,\n");
+        code.push_str("    _trackMissing: (self: Self, key: string) -> (),\n");
+        if config.track_usage {
+            code.push_str("    _trackUsage: (self: Self, key: string) -> (),\n");
+        }
+    }
+    code.push_str("}\n\n");
+}
+
+/// Generate the constructor and the locale and asset methods
+///
+/// With `wrap_translator`, translators are wrapped by `withFallbacks`.
+fn generate_constructor(
+    code: &mut String,
+    base_locale: &str,
+    analytics: Option<&AnalyticsConfig>,
+    embedded: bool,
+    wrap_translator: bool,
+) {
+    let base = luau_string(base_locale);
+
+    code.push_str("--- Create a new Translations instance\n");
+    code.push_str("function Translations.new(locale: string?): Self\n");
+    code.push_str("    local self: Self = setmetatable({}, Translations) :: any\n");
+    code.push_str(&format!("    self._locale = locale or {}\n", base));
+    code.push_str("    self._localeChangedCallbacks = {}\n");
+
+    if let Some(config) = analytics {
+        code.push_str("    \n");
+        code.push_str("    -- Analytics initialization\n");
+        code.push_str("    self._analytics_enabled = true\n");
+        code.push_str(&format!(
+            "    self._track_missing = {}\n",
+            config.track_missing
+        ));
+        code.push_str(&format!("    self._track_usage = {}\n", config.track_usage));
+        code.push_str("    self._usage_stats = {}\n");
+        if let Some(callback_path) = &config.callback {
+            code.push_str(&format!(
+                "    self._analytics_callback = require({}) :: any\n",
+                callback_path
+            ));
+        }
+    }
+
+    code.push_str("    \n");
+    if embedded {
+        code.push_str("    self._translator = newTranslator(self._locale)\n");
+    } else {
+        code.push_str("    local success, translator = pcall(function()\n");
+        code.push_str(
+            "        return LocalizationService:GetTranslatorForLocaleAsync(self._locale)\n",
+        );
+        code.push_str("    end)\n");
+        code.push_str("    \n");
+        code.push_str("    if not success then\n");
+        code.push_str("        warn(\"Failed to get translator for locale: \" .. self._locale .. \", falling back to base locale\")\n");
+        code.push_str(&format!(
+            "        translator = LocalizationService:GetTranslatorForLocaleAsync({})\n",
+            base
+        ));
+        code.push_str("    end\n");
+        code.push_str("    \n");
+        if wrap_translator {
+            code.push_str("    self._translator = withFallbacks(translator, self._locale)\n");
+        } else {
+            code.push_str("    self._translator = translator\n");
+        }
+    }
+    code.push_str("    \n");
+    code.push_str("    return self\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Switch to a different locale\n");
+    code.push_str("function Translations.setLocale(self: Self, locale: string)\n");
+    code.push_str("    if self._locale == locale then\n");
+    code.push_str("        return\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    if embedded {
+        code.push_str("    local translator = newTranslator(locale)\n");
+    } else {
+        code.push_str("    local success, translator = pcall(function()\n");
+        code.push_str("        return LocalizationService:GetTranslatorForLocaleAsync(locale)\n");
+        code.push_str("    end)\n");
+        code.push_str("    \n");
+        code.push_str("    if not success then\n");
+        code.push_str("        warn(\"Failed to switch to locale: \" .. locale)\n");
+        code.push_str("        return\n");
+        code.push_str("    end\n");
+    }
+    code.push_str("    \n");
+    code.push_str("    local oldLocale = self._locale\n");
+    code.push_str("    self._locale = locale\n");
+    if wrap_translator {
+        code.push_str("    self._translator = withFallbacks(translator, locale)\n");
+    } else {
+        code.push_str("    self._translator = translator\n");
+    }
+    code.push_str("    \n");
+    code.push_str("    for _, callback in ipairs(self._localeChangedCallbacks) do\n");
+    code.push_str("        task.spawn(callback, locale, oldLocale)\n");
+    code.push_str("    end\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Get current locale\n");
+    code.push_str("function Translations.getLocale(self: Self): string\n");
+    code.push_str("    return self._locale\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Register a callback for locale changes\n");
+    code.push_str("function Translations.onLocaleChanged(self: Self, callback: (newLocale: string, oldLocale: string) -> ())\n");
+    code.push_str("    table.insert(self._localeChangedCallbacks, callback)\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Get localized asset ID\n");
+    code.push_str("function Translations.getAsset(self: Self, assetKey: string): string\n");
+    code.push_str("    for _, locale in ipairs(fallbackChain(self._locale)) do\n");
+    code.push_str("        local success, result = pcall(function()\n");
+    code.push_str(
+        "            return self._translator:FormatByKey(\"assets.\" .. assetKey .. \".\" .. locale)\n",
+    );
+    code.push_str("        end)\n");
+    code.push_str("        \n");
+    code.push_str("        if success then\n");
+    code.push_str("            return result\n");
+    code.push_str("        end\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    -- Fallback to base locale\n");
+    code.push_str(&format!(
+        "    return self._translator:FormatByKey(\"assets.\" .. assetKey .. {})\n",
+        luau_string(&format!(".{}", base_locale))
+    ));
+    code.push_str("end\n\n");
+}
+
+/// Generate locale detection methods
+///
+/// The embedded runtime only looks `LocalizationService` up here, so the module
+/// loads outside a running game.
+fn generate_locale_detection(code: &mut String, base_locale: &str, embedded: bool) {
+    let base = luau_string(base_locale);
+
+    code.push_str("local countryLocaleMap: { [string]: string } = {\n");
+    for (country, locale) in locales::get_country_locale_map() {
+        code.push_str(&format!("    [\"{}\"] = \"{}\",\n", country, locale));
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("--- Detect player's locale based on their country\n");
+    code.push_str("function Translations.detectLocale(player: Player): string\n");
+    code.push_str("    local success, countryCode = pcall(function()\n");
+    if embedded {
+        code.push_str(
+            "        local LocalizationService = game:GetService(\"LocalizationService\")\n",
+        );
+    }
+    code.push_str("        return LocalizationService:GetCountryRegionForPlayerAsync(player)\n");
+    code.push_str("    end)\n");
+    code.push_str("    \n");
+    code.push_str("    if not success then\n");
+    code.push_str(&format!("        return {}\n", base));
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str(&format!(
+        "    return countryLocaleMap[countryCode] or {}\n",
+        base
+    ));
+    code.push_str("end\n\n");
+
+    code.push_str("--- Create a new Translations instance for a player (auto-detect locale)\n");
+    code.push_str("function Translations.newForPlayer(player: Player): Self\n");
+    code.push_str("    return Translations.new(Translations.detectLocale(player))\n");
+    code.push_str("end\n\n");
+}
+
+/// Generate analytics tracking methods
+fn generate_analytics_methods(code: &mut String, config: &AnalyticsConfig) {
+    code.push_str("--- Track missing translation\n");
+    code.push_str("function Translations._trackMissing(self: Self, key: string)\n");
+    code.push_str("    if not self._analytics_enabled or not self._track_missing then\n");
+    code.push_str("        return\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    -- Try custom callback first\n");
+    code.push_str("    local callback = self._analytics_callback\n");
+    code.push_str("    if callback then\n");
+    code.push_str("        pcall(callback, \"missing_translation\", {\n");
+    code.push_str("            key = key,\n");
+    code.push_str("            locale = self._locale,\n");
+    code.push_str("            timestamp = os.time(),\n");
+    code.push_str("        })\n");
+    code.push_str("        return\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str(
+        "    warn(string.format(\"[Slang] Missing translation: %s (%s)\", key, self._locale))\n",
+    );
+    code.push_str("end\n\n");
+
+    if config.track_usage {
+        code.push_str("--- Track translation usage\n");
+        code.push_str("function Translations._trackUsage(self: Self, key: string)\n");
+        code.push_str("    if not self._analytics_enabled or not self._track_usage then\n");
+        code.push_str("        return\n");
+        code.push_str("    end\n");
+        code.push_str("    \n");
+        code.push_str("    self._usage_stats[key] = (self._usage_stats[key] or 0) + 1\n");
+        code.push_str("end\n\n");
+
+        code.push_str("--- Get usage statistics\n");
+        code.push_str("function Translations.getUsageStats(self: Self): { [string]: number }\n");
+        code.push_str("    return self._usage_stats\n");
+        code.push_str("end\n\n");
+    }
+}
+
+/// Generate runtime variant data and the methods that switch between variants
+fn generate_variant_methods(code: &mut String, variants: &[Variant]) {
+    code.push_str("type Variant = {\n");
+    code.push_str("    startTime: number?,\n");
+    code.push_str("    endTime: number?,\n");
+    code.push_str("    segments: { string }?,\n");
+    code.push_str("    values: { [string]: { [string]: string } },\n");
+    code.push_str("}\n\n");
+
+    code.push_str("-- Override variants (activate with t:setVariant(name))\n");
+    code.push_str("local variants: { [string]: Variant } = {\n");
+    for variant in variants {
+        code.push_str(&format!("    [{}] = {{\n", luau_string(&variant.name)));
+        if let Some(start) = variant.start {
+            code.push_str(&format!("        startTime = {},\n", start));
+        }
+        if let Some(end) = variant.end {
+            code.push_str(&format!("        endTime = {},\n", end));
+        }
+        if !variant.segments.is_empty() {
+            let segments: Vec<String> = variant.segments.iter().map(|s| luau_string(s)).collect();
+            code.push_str(&format!(
+                "        segments = {{ {} }},\n",
+                segments.join(", ")
+            ));
+        }
+
+        // locale -> key -> value, sorted for deterministic output
+        let mut by_locale: BTreeMap<&str, BTreeMap<&str, &str>> = BTreeMap::new();
+        for translation in &variant.translations {
+            by_locale
+                .entry(&translation.locale)
+                .or_default()
+                .insert(&translation.key, &translation.value);
+        }
+
+        code.push_str("        values = {\n");
+        for (locale, values) in &by_locale {
+            code.push_str(&format!("            [{}] = {{\n", luau_string(locale)));
+            for (key, value) in values {
+                code.push_str(&format!(
+                    "                [{}] = {},\n",
+                    luau_string(key),
+                    luau_string(value)
+                ));
+            }
+            code.push_str("            },\n");
+        }
+        code.push_str("        },\n");
+        code.push_str("    },\n");
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("--- Activate an override variant, or pass nil to use the base translations\n");
+    code.push_str("function Translations.setVariant(self: Self, name: string?)\n");
+    code.push_str("    if name ~= nil and variants[name] == nil then\n");
+    code.push_str("        warn(\"Unknown translation variant: \" .. name)\n");
+    code.push_str("        return\n");
+    code.push_str("    end\n");
+    code.push_str("    self._variant = name\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Set the player's segments, used by variants limited to segments\n");
+    code.push_str("function Translations.setSegments(self: Self, segments: { string })\n");
+    code.push_str("    local active: { [string]: boolean } = {}\n");
+    code.push_str("    for _, segment in ipairs(segments) do\n");
+    code.push_str("        active[segment] = true\n");
+    code.push_str("    end\n");
+    code.push_str("    self._segments = active\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Get the active variant (nil if none is set or it is outside its date window or segments)\n");
+    code.push_str("function Translations.getVariant(self: Self): string?\n");
+    code.push_str("    local name = self._variant\n");
+    code.push_str("    local variant = if name then variants[name] else nil\n");
+    code.push_str("    if not variant then\n");
+    code.push_str("        return nil\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    local now = os.time()\n");
+    code.push_str("    local startTime, endTime = variant.startTime, variant.endTime\n");
+    code.push_str("    if (startTime and now < startTime) or (endTime and now > endTime) then\n");
+    code.push_str("        return nil\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    local segments = variant.segments\n");
+    code.push_str("    if segments then\n");
+    code.push_str("        local active = self._segments or {}\n");
+    code.push_str("        for _, segment in ipairs(segments) do\n");
+    code.push_str("            if active[segment] then\n");
+    code.push_str("                return name\n");
+    code.push_str("            end\n");
+    code.push_str("        end\n");
+    code.push_str("        return nil\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    return name\n");
+    code.push_str("end\n\n");
+
+    code.push_str("--- Format a key, using the active variant's value when it has one\n");
+    code.push_str(
+        "function Translations._formatByKey(self: Self, key: string, params: Params?): string\n",
+    );
+    code.push_str("    local name = self:getVariant()\n");
+    code.push_str("    local values = if name then variants[name].values[self._locale] else nil\n");
+    code.push_str("    local value = if values then values[key] else nil\n");
+    code.push_str("    if value == nil then\n");
+    code.push_str("        -- Per-key fallback to the base translation\n");
+    code.push_str("        return self._translator:FormatByKey(key, params)\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    return (string.gsub(value, \"({([%w_]+)[^}]*})\", function(placeholder: string, param: string): string\n");
+    code.push_str("        local replacement = if params then params[param] else nil\n");
+    code.push_str(
+        "        return if replacement ~= nil then tostring(replacement) else placeholder\n",
+    );
+    code.push_str("    end))\n");
+    code.push_str("end\n\n");
+}
+
+/// Generate the CLDR plural rules for `locales` and `Translations.pluralCategory`
+fn generate_plural_rules(code: &mut String, locales: &[String], cardinal: bool, ordinal: bool) {
+    code.push_str("-- Plural rules (CLDR) for the supported locales\n");
+    code.push_str("-- Operands: n = absolute value, i = integer digits, v/w = number of visible\n");
+    code.push_str("-- fraction digits with/without trailing zeros, f/t = those digits\n");
+    code.push_str(
+        "type PluralRule = (n: number, i: number, v: number, w: number, f: number, t: number) -> string\n\n",
+    );
+
+    code.push_str("local function pluralOperands(count: number | string): (number, number, number, number, number, number)\n");
+    code.push_str("    local text = string.gsub(tostring(count), \"^-\", \"\")\n");
+    code.push_str("    local integer, fraction = string.match(text, \"^(%d+)%.?(%d*)$\")\n");
+    code.push_str("    if not integer or not fraction then\n");
+    code.push_str("        local n = math.abs(tonumber(count) or 0)\n");
+    code.push_str("        return n, math.floor(n), 0, 0, 0, 0\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    local trimmed = string.gsub(fraction, \"0+$\", \"\")\n");
+    code.push_str("    return tonumber(text) or 0, tonumber(integer) or 0, #fraction, #trimmed, tonumber(fraction) or 0, tonumber(trimmed) or 0\n");
+    code.push_str("end\n\n");
+
+    code.push_str(
+        "local pluralRules: { [string]: { cardinal: PluralRule?, ordinal: PluralRule? } } = {\n",
+    );
+    for locale in locales {
+        let Some(rules) = plurals::rules_for_locale(locale) else {
+            log::warn!(
+                "No CLDR plural rules for locale '{}', plurals will always use 'other'",
+                locale
+            );
+            continue;
+        };
+
+        code.push_str(&format!("    [{}] = {{\n", luau_string(locale)));
+        let kinds = [
+            ("cardinal", cardinal, rules.cardinal),
+            ("ordinal", ordinal, rules.ordinal),
+        ];
+        for (kind, _, rules) in kinds.iter().filter(|(_, used, _)| *used) {
+            code.push_str(&format!(
+                "        {} = function(n: number, i: number, v: number, w: number, f: number, t: number): string\n",
+                kind
+            ));
+            code.push_str(&plurals::rules_to_luau(rules, "            "));
+            code.push_str("        end,\n");
+        }
+        code.push_str("    },\n");
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("--- Get the CLDR plural category of a count (a string count keeps visible decimals, e.g. \"1.50\")\n");
+    code.push_str("function Translations.pluralCategory(locale: string, count: number | string, ordinal: boolean?): string\n");
+    code.push_str(
+        "    local rules = pluralRules[locale] or pluralRules[string.match(locale, \"^%a+\") or locale]\n",
+    );
+    code.push_str(
+        "    local rule = if rules then (if ordinal then rules.ordinal else rules.cardinal) else nil\n",
+    );
+    code.push_str("    if not rule then\n");
+    code.push_str("        return \"other\"\n");
+    code.push_str("    end\n");
+    code.push_str("    return rule(pluralOperands(count))\n");
+    code.push_str("end\n\n");
+}
+
+/// Generate the compiled ICU messages and the helpers they use
+fn generate_message_runtime(code: &mut String, messages: &Messages, base_locale: &str) {
+    code.push_str("-- ICU MessageFormat support\n");
+    code.push_str("local function icuPlural(locale: string, value: any, offset: number, ordinal: boolean, cases: { [string]: (string) -> string }): string\n");
+    code.push_str("    local number = tonumber(value) or 0\n");
+    code.push_str("    local shown = if offset == 0 then (value or 0) else number - offset\n");
+    code.push_str("    local case = cases[\"=\" .. tostring(number)]\n");
+    code.push_str("        or cases[Translations.pluralCategory(locale, shown, ordinal)]\n");
+    code.push_str("        or cases.other\n");
+    code.push_str("    return case(tostring(shown))\n");
+    code.push_str("end\n\n");
+
+    code.push_str(
+        "local function icuSelect(value: any, cases: { [string]: () -> string }): string\n",
+    );
+    code.push_str("    local case = cases[tostring(value)] or cases.other\n");
+    code.push_str("    return case()\n");
+    code.push_str("end\n\n");
+
+    code.push_str(
+        "local messages: { [string]: { [string]: (params: Params, locale: string) -> string } } = {\n",
+    );
+    for (key, locales) in messages {
+        code.push_str(&format!("    [{}] = {{\n", luau_string(key)));
+        for (locale, parts) in locales {
+            code.push_str(&format!(
+                "        [{}] = function(params: Params, locale: string): string\n",
+                luau_string(locale)
+            ));
+            code.push_str(&format!(
+                "            return {}\n",
+                icu::message_to_luau(parts)
+            ));
+            code.push_str("        end,\n");
+        }
+        code.push_str("    },\n");
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("--- Format an ICU message in the first locale of the current locale's fallback chain that has it\n");
+    code.push_str(
+        "function Translations._formatMessage(self: Self, key: string, params: Params?): string\n",
+    );
+    code.push_str("    local localeMessages = messages[key]\n");
+    code.push_str("    for _, locale in ipairs(fallbackChain(self._locale)) do\n");
+    code.push_str("        local message = localeMessages[locale]\n");
+    code.push_str("        if message then\n");
+    code.push_str("            return message(params or {}, locale)\n");
+    code.push_str("        end\n");
+    code.push_str("    end\n");
+    code.push_str(&format!(
+        "    return localeMessages[{}](params or {{}}, {})\n",
+        luau_string(base_locale),
+        luau_string(base_locale)
+    ));
+    code.push_str("end\n\n");
+}
+
+/// A namespace table of the module, with the methods directly in it
+#[derive(Default)]
+struct Namespace {
+    namespaces: BTreeMap<String, Namespace>,
+    /// Method name -> (signature, call of the flat method)
+    methods: BTreeMap<String, (String, String)>,
+}
+
+impl Namespace {
+    /// Add a method for a dotted key; keys without a namespace only have flat methods
+    fn insert(&mut self, key: &str, signature: &str, call: String) {
+        let Some((path, method)) = key.rsplit_once('.') else {
+            return;
+        };
+
+        let mut namespace = self;
+        for part in path.split('.') {
+            namespace = namespace.namespaces.entry(part.to_string()).or_default();
+        }
+        namespace
+            .methods
+            .insert(method.to_string(), (signature.to_string(), call));
+    }
+}
+
+/// Generate the fields of a namespace table literal
+fn generate_namespace(code: &mut String, namespace: &Namespace, indent: &str) {
+    for (method, (signature, call)) in &namespace.methods {
+        code.push_str(&format!("{}{} = function{}\n", indent, method, signature));
+        code.push_str(&format!("{}    return {}\n", indent, call));
+        code.push_str(&format!("{}end,\n", indent));
+    }
+
+    for (name, nested) in &namespace.namespaces {
+        code.push_str(&format!("{}{} = {{\n", indent, name));
+        generate_namespace(code, nested, &format!("{}    ", indent));
+        code.push_str(&format!("{}}},\n", indent));
+    }
+}
+
+/// Generate the flat methods, and collect their namespace methods
+///
+/// `tracking` is (track usage, track missing translations).
+fn generate_methods(
+    code: &mut String,
+    translations: &[&Translation],
+    messages: &Messages,
+    tracking: (bool, bool),
+    variants: bool,
+    namespaces: &mut Namespace,
+    formatters: &Formatters,
+) {
+    code.push_str("-- Internal methods (flat keys)\n\n");
+
+    let (track_usage, track_missing) = tracking;
+    let lookup = lookup_fn(variants);
+
+    // Separate plural, select, list and regular translations
+    let mut plural_groups: BTreeMap<String, Vec<&Translation>> = BTreeMap::new();
+    let mut select_groups: BTreeMap<String, Vec<&Translation>> = BTreeMap::new();
+    let mut list_groups: BTreeMap<String, Vec<&Translation>> = BTreeMap::new();
+    let mut regular_translations = Vec::new();
+
+    for translation in translations {
+        if plurals::is_plural_key(&translation.key) {
+            let base_key = plurals::extract_base_key(&translation.key);
+            plural_groups.entry(base_key).or_default().push(translation);
+        } else if selects::is_select_key(&translation.key) {
+            let base_key = selects::extract_base_key(&translation.key);
+            select_groups.entry(base_key).or_default().push(translation);
+        } else if lists::is_list_item_key(&translation.key) {
+            let list_key = lists::extract_list_key(&translation.key);
+            list_groups.entry(list_key).or_default().push(translation);
+        } else {
+            regular_translations.push(*translation);
+        }
+    }
+
+    regular_translations.sort_by(|a, b| a.key.cmp(&b.key));
+
+    for translation in regular_translations {
+        let key = &translation.key;
+        let method_name = key.replace(".", "_");
+        let params_with_format =
+            format::extract_parameters_with_formatters(&translation.value, formatters);
+
+        generate_doc_comment(code, translation);
+
+        if messages.contains_key(key) {
+            // ICU message, formatted by the compiled message functions
+            let signature = "(self: Self, params: Params?): string";
+            code.push_str(&format!(
+                "function Translations.{}{}\n",
+                method_name, signature
+            ));
+            if track_usage {
+                code.push_str(&format!("    self:_trackUsage({})\n", luau_string(key)));
+            }
+            code.push_str(&format!(
+                "    return self:_formatMessage({}, params)\n",
+                luau_string(key)
+            ));
+            namespaces.insert(
+                key,
+                signature,
+                format!("Translations.{}(self, params)", method_name),
+            );
+        } else {
+            let (signature, arguments) = if params_with_format.is_empty() {
+                ("(self: Self): string", luau_string(key))
+            } else {
+                (
+                    "(self: Self, params: Params): string",
+                    format!("{}, params", luau_string(key)),
+                )
+            };
+            code.push_str(&format!(
+                "function Translations.{}{}\n",
+                method_name, signature
+            ));
+            if track_usage {
+                code.push_str(&format!("    self:_trackUsage({})\n", luau_string(key)));
+            }
+
+            // Apply format specifiers to a copy, so the caller's table can be reused
+            let mut specifiers: Vec<_> = params_with_format.iter().collect();
+            specifiers.sort_by(|a, b| a.0.cmp(b.0));
+            let format_codes: Vec<String> = specifiers
+                .into_iter()
+                .map(|(param_name, specifier)| format::generate_format_code(param_name, specifier))
+                .filter(|format_code| !format_code.is_empty())
+                .collect();
+            if !format_codes.is_empty() {
+                code.push_str("    params = table.clone(params)\n");
+            }
+            for format_code in &format_codes {
+                code.push_str(&format!("    {}\n", format_code));
+            }
+
+            if track_missing {
+                code.push_str(&format!("    local value = {}({})\n", lookup, arguments));
+                code.push_str("    if value == \"\" or value == \"{}\" then\n");
+                code.push_str(&format!(
+                    "        self:_trackMissing({})\n",
+                    luau_string(key)
+                ));
+                code.push_str(&format!(
+                    "        return {}  -- Return key as fallback\n",
+                    luau_string(key)
+                ));
+                code.push_str("    end\n");
+                code.push_str("    return value\n");
+            } else {
+                code.push_str(&format!("    return {}({})\n", lookup, arguments));
+            }
+
+            let call = if params_with_format.is_empty() {
+                format!("Translations.{}(self)", method_name)
+            } else {
+                format!("Translations.{}(self, params)", method_name)
+            };
+            namespaces.insert(key, signature, call);
+        }
+
+        code.push_str("end\n\n");
+    }
+
+    for (base_key, forms) in &plural_groups {
+        generate_plural_method(code, base_key, forms, variants);
+        namespaces.insert(
+            base_key,
+            "(self: Self, count: number | string, params: Params?): string",
+            format!(
+                "Translations.{}(self, count, params)",
+                base_key.replace(".", "_")
+            ),
+        );
+    }
+
+    for (base_key, cases) in &select_groups {
+        generate_select_method(code, base_key, cases, track_usage, variants);
+        namespaces.insert(
+            base_key,
+            "(self: Self, case: string, params: Params?): string",
+            format!(
+                "Translations.{}(self, case, params)",
+                base_key.replace(".", "_")
+            ),
+        );
+    }
+
+    for (list_key, items) in &list_groups {
+        generate_list_method(code, list_key, items, track_usage, variants);
+        let method_name = list_key.replace(".", "_");
+        namespaces.insert(
+            list_key,
+            "(self: Self, params: Params?): { string }",
+            format!("Translations.{}(self, params)", method_name),
+        );
+        namespaces.insert(
+            &format!("{}_random", list_key),
+            "(self: Self, params: Params?): string",
+            format!("Translations.{}_random(self, params)", method_name),
+        );
+    }
+}
+
+/// Generate a plural method
+fn generate_plural_method(
+    code: &mut String,
+    base_key: &str,
+    translations: &[&Translation],
+    variants: bool,
+) {
+    let method_name = base_key.replace(".", "_");
+
+    // Metadata may be attached to any of the plural forms
+    let mut forms = translations.to_vec();
+    forms.sort_by(|a, b| a.key.cmp(&b.key));
+    if let Some(documented) = forms.iter().find(|t| has_metadata(t)) {
+        generate_doc_comment(code, documented);
+    }
+
+    // Ordinal forms (`rank(ordinal:one)`) pick 1st/2nd/3rd-style categories
+    let ordinal = forms.iter().any(|t| plurals::is_ordinal_key(&t.key));
+    let (form_prefix, ordinal_arg) = if ordinal {
+        (plurals::ORDINAL_PREFIX, ", true")
+    } else {
+        ("", "")
+    };
+
+    code.push_str(&format!(
+        "function Translations.{}(self: Self, count: number | string, params: Params?): string\n",
+        method_name
+    ));
+    code.push_str("    local args: Params = table.clone(params or {})\n");
+    code.push_str("    args.count = count\n");
+    code.push_str("    \n");
+    code.push_str(&format!(
+        "    local category = Translations.pluralCategory(self._locale, count{})\n",
+        ordinal_arg
+    ));
+    code.push_str(&format!(
+        "    local key = {} .. category .. \")\"\n",
+        luau_string(&format!("{}({}", base_key, form_prefix))
+    ));
+    code.push_str("    local success, result = pcall(function()\n");
+    code.push_str(&format!(
+        "        return {}(key, args)\n",
+        lookup_fn(variants)
+    ));
+    code.push_str("    end)\n");
+    code.push_str("    \n");
+    code.push_str("    if success then\n");
+    code.push_str("        return result\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    -- Fallback to 'other' category\n");
+    code.push_str(&format!(
+        "    return {}({}, args)\n",
+        lookup_fn(variants),
+        luau_string(&format!("{}({}other)", base_key, form_prefix))
+    ));
+    code.push_str("end\n\n");
+}
+
+/// Generate a select method, which picks a `(select:case)` key by name
+fn generate_select_method(
+    code: &mut String,
+    base_key: &str,
+    translations: &[&Translation],
+    track_usage: bool,
+    variants: bool,
+) {
+    let method_name = base_key.replace(".", "_");
+
+    let mut cases = translations.to_vec();
+    cases.sort_by(|a, b| a.key.cmp(&b.key));
+    if let Some(documented) = cases.iter().find(|t| has_metadata(t)) {
+        generate_doc_comment(code, documented);
+    }
+
+    code.push_str(&format!(
+        "function Translations.{}(self: Self, case: string, params: Params?): string\n",
+        method_name
+    ));
+    if track_usage {
+        code.push_str(&format!(
+            "    self:_trackUsage({})\n",
+            luau_string(base_key)
+        ));
+    }
+    code.push_str(&format!(
+        "    local key = {} .. case .. \")\"\n",
+        luau_string(&format!("{}({}", base_key, selects::SELECT_PREFIX))
+    ));
+    code.push_str("    local success, result = pcall(function()\n");
+    code.push_str(&format!(
+        "        return {}(key, params)\n",
+        lookup_fn(variants)
+    ));
+    code.push_str("    end)\n");
+    code.push_str("    \n");
+    code.push_str("    if success then\n");
+    code.push_str("        return result\n");
+    code.push_str("    end\n");
+    code.push_str("    \n");
+    code.push_str("    -- Fallback to 'other' case\n");
+    code.push_str(&format!(
+        "    return {}({}, params)\n",
+        lookup_fn(variants),
+        luau_string(&format!(
+            "{}({}{})",
+            base_key,
+            selects::SELECT_PREFIX,
+            selects::FALLBACK_CASE
+        ))
+    ));
+    code.push_str("end\n\n");
+}
+
+/// Generate a list method returning every item, and one returning a random item
+fn generate_list_method(
+    code: &mut String,
+    list_key: &str,
+    translations: &[&Translation],
+    track_usage: bool,
+    variants: bool,
+) {
+    let method_name = list_key.replace(".", "_");
+
+    let mut items = translations.to_vec();
+    items.sort_by_key(|t| lists::list_index(&t.key));
+    if let Some(documented) = items.iter().find(|t| has_metadata(t)) {
+        generate_doc_comment(code, documented);
+    }
+
+    code.push_str(&format!(
+        "function Translations.{}(self: Self, params: Params?): {{ string }}\n",
+        method_name
+    ));
+    if track_usage {
+        code.push_str(&format!(
+            "    self:_trackUsage({})\n",
+            luau_string(list_key)
+        ));
+    }
+    code.push_str("    return {\n");
+    for item in &items {
+        code.push_str(&format!(
+            "        {}({}, params),\n",
+            lookup_fn(variants),
+            luau_string(&item.key)
+        ));
+    }
+    code.push_str("    }\n");
+    code.push_str("end\n\n");
+
+    code.push_str(&format!("--- Random entry of {}\n", list_key));
+    code.push_str(&format!(
+        "function Translations.{}_random(self: Self, params: Params?): string\n",
+        method_name
+    ));
+    code.push_str(&format!(
+        "    local items = Translations.{}(self, params)\n",
+        method_name
+    ));
+    code.push_str("    return items[math.random(#items)]\n");
+    code.push_str("end\n\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(key: &str, value: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_generate_strict_luau() {
+        let translations = vec![
+            translation("ui.buttons.buy", "Buy"),
+            translation("ui.greeting", "Hello, {name}!"),
+            translation("ui.items(one)", "{count} item"),
+            translation("ui.items(other)", "{count} items"),
+        ];
+
+        let code = generate_strict_luau(&translations, &Config::default(), &[]).unwrap();
+
+        assert!(code.starts_with("--!strict\n"));
+        assert!(code.contains("export type Translations = {"));
+        assert!(code.contains("export type TranslationsInstance = {"));
+        assert!(code.contains(
+            "    ui_greeting: (self: TranslationsInstance, params: { name: string | number }) -> string,"
+        ));
+        assert!(code.contains("function Translations.ui_buttons_buy(self: Self): string"));
+        assert!(code.contains("    buttons = {\n        buy = function(self: Self): string\n"));
+        assert!(code.ends_with("return (Translations :: any) :: Translations\n"));
+
+        // `any` stays out of the exported types
+        let public_types =
+            &code[code.find("export type").unwrap()..code.find("-- Private types").unwrap()];
+        assert!(!public_types.contains("any"));
+    }
+
+    #[test]
+    fn test_generate_strict_luau_embedded() {
+        let translations = vec![
+            translation("ui.buttons.buy", "Buy"),
+            translation("ui.greeting", "Hello, {name}!"),
+        ];
+        let config = Config {
+            runtime: Runtime::Embedded,
+            ..Config::default()
+        };
+
+        let code = generate_strict_luau(&translations, &config, &[]).unwrap();
+
+        assert!(code.contains("    _translator: EmbeddedTranslator,\n"));
+        assert!(code.contains("        [\"ui.greeting\"] = \"Hello, {name}!\",\n"));
+        assert!(code.contains("    self._translator = newTranslator(self._locale)\n"));
+        assert!(!code.contains("GetTranslatorForLocaleAsync"));
+        // LocalizationService is only needed by detectLocale
+        assert_eq!(
+            code.matches("game:GetService(\"LocalizationService\")")
+                .count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Relative time and durations
+//!
+//! `relative` parameters ("in 3 minutes", "2 hours ago") and `duration`
+//! parameters ("2h 05m") are formatted at runtime with the CLDR time units of
+//! the player's locale. Relative times pick their form with the plural rules and
+//! their count with `formatNumber`, so both are generated alongside them.
+
+use crate::utils::format::luau_string;
+use crate::utils::time_units::{self, UNIT_SECONDS};
+
+/// Generate the time units of `locales` and the `formatRelative` (with
+/// `relative`) and `formatDuration` (with `duration`) helpers
+///
+/// Locales without CLDR data use English units. Other locales use their
+/// language's units, or the base locale's.
+pub(super) fn generate_time_units(
+    code: &mut String,
+    base_locale: &str,
+    locales: &[String],
+    relative: bool,
+    duration: bool,
+    typed: bool,
+) {
+    code.push_str(
+        "-- Time units (CLDR) for the supported locales: days, hours, minutes, seconds\n",
+    );
+    if typed {
+        code.push_str("type TimeUnit = {\n");
+        code.push_str("    seconds: number,\n");
+        code.push_str("    narrow: string,\n");
+        code.push_str("    future: { [string]: string },\n");
+        code.push_str("    past: { [string]: string },\n");
+        code.push_str("}\n\n");
+        code.push_str("local timeUnits: { [string]: { TimeUnit } } = {\n");
+    } else {
+        code.push_str("local timeUnits = {\n");
+    }
+    for locale in locales {
+        let units = time_units::units_for_locale(locale).unwrap_or_else(|| {
+            log::warn!(
+                "No CLDR time units for locale '{}', relative times and durations will be in English",
+                locale
+            );
+            time_units::units_for_locale("en").expect("English time units")
+        });
+
+        code.push_str(&format!("    [{}] = {{\n", luau_string(locale)));
+        for ((seconds, forms), narrow) in UNIT_SECONDS.iter().zip(units.relative).zip(units.narrow)
+        {
+            let future: Vec<String> = forms
+                .iter()
+                .map(|(category, future, _)| format!("{} = {}", category, luau_string(future)))
+                .collect();
+            let past: Vec<String> = forms
+                .iter()
+                .map(|(category, _, past)| format!("{} = {}", category, luau_string(past)))
+                .collect();
+
+            code.push_str("        {\n");
+            code.push_str(&format!("            seconds = {},\n", seconds));
+            code.push_str(&format!("            narrow = {},\n", luau_string(narrow)));
+            code.push_str(&format!(
+                "            future = {{ {} }},\n",
+                future.join(", ")
+            ));
+            code.push_str(&format!("            past = {{ {} }},\n", past.join(", ")));
+            code.push_str("        },\n");
+        }
+        code.push_str("    },\n");
+    }
+    code.push_str("}\n\n");
+
+    code.push_str(
+        "--- Time units of a locale (other locales use their language's, then the base locale's)\n",
+    );
+    if typed {
+        code.push_str("local function localeTimeUnits(locale: string): { TimeUnit }\n");
+    } else {
+        code.push_str("local function localeTimeUnits(locale)\n");
+    }
+    code.push_str("    return timeUnits[locale]\n");
+    code.push_str("        or timeUnits[string.match(locale, \"^%a+\") or locale]\n");
+    code.push_str(&format!(
+        "        or timeUnits[{}]\n",
+        luau_string(base_locale)
+    ));
+    code.push_str("end\n\n");
+
+    if relative {
+        code.push_str("--- Format seconds from now (negative for the past) in the largest whole unit (\"in 3 minutes\")\n");
+        if typed {
+            code.push_str("local function formatRelative(locale: string, value: any): string\n");
+        } else {
+            code.push_str("local function formatRelative(locale, value)\n");
+        }
+        code.push_str("    local seconds = tonumber(value) or 0\n");
+        code.push_str("    local units = localeTimeUnits(locale)\n");
+        code.push_str("    local unit = units[#units]\n");
+        code.push_str("    for _, candidate in ipairs(units) do\n");
+        code.push_str("        if math.abs(seconds) >= candidate.seconds then\n");
+        code.push_str("            unit = candidate\n");
+        code.push_str("            break\n");
+        code.push_str("        end\n");
+        code.push_str("    end\n");
+        code.push_str("    \n");
+        code.push_str("    local count = math.abs(seconds) // unit.seconds\n");
+        code.push_str("    local forms = if seconds < 0 then unit.past else unit.future\n");
+        code.push_str(
+            "    local pattern = forms[Translations.pluralCategory(locale, count)] or forms.other\n",
+        );
+        code.push_str(
+            "    return (string.gsub(pattern, \"{0}\", formatNumber(locale, count, 0, 0)))\n",
+        );
+        code.push_str("end\n\n");
+    }
+
+    if duration {
+        code.push_str("--- Format seconds as the largest unit and the next one, zero-padded (\"2h 05m\", \"45s\")\n");
+        if typed {
+            code.push_str("local function formatDuration(locale: string, value: any): string\n");
+        } else {
+            code.push_str("local function formatDuration(locale, value)\n");
+        }
+        code.push_str("    local remaining = math.max((tonumber(value) or 0) // 1, 0)\n");
+        code.push_str("    local units = localeTimeUnits(locale)\n");
+        code.push_str("    for index, unit in ipairs(units) do\n");
+        code.push_str("        local smaller = units[index + 1]\n");
+        code.push_str("        if remaining >= unit.seconds or smaller == nil then\n");
+        code.push_str("            local text = string.gsub(unit.narrow, \"{0}\", tostring(remaining // unit.seconds))\n");
+        code.push_str("            if smaller == nil then\n");
+        code.push_str("                return text\n");
+        code.push_str("            end\n");
+        code.push_str("            local rest = string.format(\"%02d\", remaining % unit.seconds // smaller.seconds)\n");
+        code.push_str(
+            "            return text .. \" \" .. string.gsub(smaller.narrow, \"{0}\", rest)\n",
+        );
+        code.push_str("        end\n");
+        code.push_str("    end\n");
+        code.push_str("    return \"\"\n");
+        code.push_str("end\n\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_time_units() {
+        let locales = vec!["en".to_string(), "ru".to_string(), "xx".to_string()];
+
+        let mut code = String::new();
+        generate_time_units(&mut code, "en", &locales, true, false, false);
+
+        assert!(code.contains(
+            "            seconds = 86400,\n            narrow = \"{0}d\",\n            future = { one = \"in {0} day\", other = \"in {0} days\" },\n"
+        ));
+        assert!(code.contains("many = \"через {0} минут\""));
+        // Locales without data use English units
+        assert!(code.contains("    [\"xx\"] = {\n        {\n            seconds = 86400,\n            narrow = \"{0}d\",\n"));
+        assert!(code.contains("local function formatRelative(locale, value)\n"));
+        assert!(!code.contains("formatDuration"));
+
+        let mut code = String::new();
+        generate_time_units(&mut code, "en", &locales, false, true, true);
+        assert!(code.contains("local timeUnits: { [string]: { TimeUnit } } = {\n"));
+        assert!(
+            code.contains("local function formatDuration(locale: string, value: any): string\n")
+        );
+        assert!(!code.contains("formatRelative"));
+    }
+}
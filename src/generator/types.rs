@@ -1,12 +1,36 @@
-use crate::parser::Translation;
-use crate::utils::plurals;
+use crate::config::{AnalyticsConfig, DEFAULT_MODULE_NAME};
+use crate::parser::{Translation, Variant};
+use crate::utils::format::{self, Formatters};
+use crate::utils::{icu, lists, plurals, selects};
 use anyhow::Result;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Generate Luau type definitions (.d.luau)
+#[allow(dead_code)] // Public API for library users
 pub fn generate_type_definitions(
     translations: &[Translation],
     base_locale: &str,
+) -> Result<String> {
+    generate_type_definitions_for_module(
+        translations,
+        base_locale,
+        &[],
+        DEFAULT_MODULE_NAME,
+        &Formatters::new(),
+    )
+}
+
+/// Generate Luau type definitions for the module named `module_name`
+///
+/// The types are named after the module (`GameStrings` and
+/// `GameStringsInstance`), so definitions of several modules don't clash.
+/// Parameters of the custom specifiers in `formatters` have their input type.
+pub fn generate_type_definitions_for_module(
+    translations: &[Translation],
+    base_locale: &str,
+    variants: &[Variant],
+    module_name: &str,
+    formatters: &Formatters,
 ) -> Result<String> {
     let mut code = String::new();
 
@@ -22,7 +46,10 @@ pub fn generate_type_definitions(
     code.push_str("        roblox-slang build\n");
     code.push_str("    \n");
     code.push_str("    Usage:\n");
-    code.push_str("    Place this file alongside your Translations.lua module.\n");
+    code.push_str(&format!(
+        "    Place this file alongside your {}.lua module.\n",
+        module_name
+    ));
     code.push_str("    Your IDE/LSP will automatically provide autocomplete and type checking.\n");
     code.push_str("    \n");
     code.push_str("    Learn more: https://github.com/mathtechstudio/roblox-slang\n");
@@ -35,88 +62,401 @@ pub fn generate_type_definitions(
         .collect();
 
     if base_translations.is_empty() {
-        return Ok(code + "export type Translations = {}\n");
+        return Ok(code + &format!("export type {} = {{}}\n", module_name));
     }
 
+    generate_type_declarations(
+        &mut code,
+        module_name,
+        &base_translations,
+        variants,
+        None,
+        true,
+        formatters,
+    );
+
+    Ok(code)
+}
+
+/// Generate the module type and the instance type, named after the module
+/// (`Translations` and `TranslationsInstance` by default)
+///
+/// `internal_fields` adds the instance's private fields, loosely typed, for the
+/// standalone type definitions; the strict module declares them itself.
+pub(super) fn generate_type_declarations(
+    code: &mut String,
+    module_name: &str,
+    base_translations: &[&Translation],
+    variants: &[Variant],
+    analytics_config: Option<&AnalyticsConfig>,
+    internal_fields: bool,
+    formatters: &Formatters,
+) {
+    let instance = format!("{}Instance", module_name);
+
     // Build namespace type structure
-    code.push_str("export type Translations = {\n");
-    code.push_str("    new: (locale: string?) -> TranslationsInstance,\n");
+    code.push_str(&format!("export type {} = {{\n", module_name));
+    code.push_str(&format!("    new: (locale: string?) -> {},\n", instance));
+    code.push_str("    detectLocale: (player: Player) -> string,\n");
+    code.push_str(&format!(
+        "    newForPlayer: (player: Player) -> {},\n",
+        instance
+    ));
+    if base_translations
+        .iter()
+        .any(|t| plurals::is_plural_key(&t.key))
+    {
+        code.push_str(
+            "    pluralCategory: (locale: string, count: number | string, ordinal: boolean?) -> string,\n",
+        );
+    }
     code.push_str("}\n\n");
 
     // Build instance type
-    code.push_str("export type TranslationsInstance = {\n");
+    code.push_str(&format!("export type {} = {{\n", instance));
 
     // Add internal fields
-    code.push_str("    _locale: string,\n");
-    code.push_str("    _translator: any,\n");
-    code.push_str("    _localeChangedCallbacks: {any},\n\n");
+    if internal_fields {
+        code.push_str("    _locale: string,\n");
+        code.push_str("    _translator: any,\n");
+        code.push_str("    _localeChangedCallbacks: {any},\n\n");
+    }
 
     // Add methods
-    code.push_str("    setLocale: (self: TranslationsInstance, locale: string) -> (),\n");
-    code.push_str("    getLocale: (self: TranslationsInstance) -> string,\n");
-    code.push_str("    onLocaleChanged: (self: TranslationsInstance, callback: (newLocale: string, oldLocale: string) -> ()) -> (),\n");
-    code.push_str("    getAsset: (self: TranslationsInstance, assetKey: string) -> string,\n\n");
+    code.push_str(&format!(
+        "    setLocale: (self: {}, locale: string) -> (),\n",
+        instance
+    ));
+    code.push_str(&format!("    getLocale: (self: {}) -> string,\n", instance));
+    code.push_str(&format!("    onLocaleChanged: (self: {}, callback: (newLocale: string, oldLocale: string) -> ()) -> (),\n", instance));
+    code.push_str(&format!(
+        "    getAsset: (self: {}, assetKey: string) -> string,\n\n",
+        instance
+    ));
+
+    if !variants.is_empty() {
+        code.push_str(&format!(
+            "    setVariant: (self: {}, name: string?) -> (),\n",
+            instance
+        ));
+        code.push_str(&format!(
+            "    getVariant: (self: {}) -> string?,\n",
+            instance
+        ));
+        code.push_str(&format!(
+            "    setSegments: (self: {}, segments: {{string}}) -> (),\n\n",
+            instance
+        ));
+    }
+
+    if analytics_config.is_some_and(|c| c.enabled && c.track_usage) {
+        code.push_str(&format!(
+            "    getUsageStats: (self: {}) -> {{ [string]: number }},\n\n",
+            instance
+        ));
+    }
 
-    // Separate plural and non-plural translations
+    // Separate plural, select, list and regular translations
     let mut plural_base_keys: HashSet<String> = HashSet::new();
+    let mut ordinal_keys: HashSet<String> = HashSet::new();
+    let mut select_cases: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    let mut list_keys: HashSet<String> = HashSet::new();
+    let mut grouped_values: HashMap<String, Vec<&str>> = HashMap::new();
     let mut regular_translations = Vec::new();
 
-    for translation in &base_translations {
-        if plurals::is_plural_key(&translation.key) {
+    for translation in base_translations {
+        let group_key = if plurals::is_plural_key(&translation.key) {
             let base_key = plurals::extract_base_key(&translation.key);
-            plural_base_keys.insert(base_key);
+            if plurals::is_ordinal_key(&translation.key) {
+                ordinal_keys.insert(base_key.clone());
+            }
+            plural_base_keys.insert(base_key.clone());
+            base_key
+        } else if let Some(case) = selects::select_case(&translation.key) {
+            let base_key = selects::extract_base_key(&translation.key);
+            select_cases.entry(base_key.clone()).or_default().push(case);
+            base_key
+        } else if lists::is_list_item_key(&translation.key) {
+            let list_key = lists::extract_list_key(&translation.key);
+            list_keys.insert(list_key.clone());
+            list_key
         } else {
             regular_translations.push(*translation);
-        }
+            continue;
+        };
+
+        grouped_values
+            .entry(group_key)
+            .or_default()
+            .push(&translation.value);
     }
 
     // Sort for deterministic output
     regular_translations.sort_by(|a, b| a.key.cmp(&b.key));
 
-    // Add flat methods for regular translations
-    for translation in &regular_translations {
-        let method_name = translation.key.replace(".", "_");
-        let params = super::luau::extract_parameters(&translation.value);
-
-        if params.is_empty() {
-            code.push_str(&format!(
-                "    {}: (self: TranslationsInstance) -> string,\n",
-                method_name
-            ));
-        } else {
-            code.push_str(&format!(
-                "    {}: (self: TranslationsInstance, params: {{}}) -> string,\n",
-                method_name
-            ));
+    // Method types of plurals, selects and lists, with params from all their forms
+    let grouped_params = |key: &str| {
+        let mut params = parameter_types(grouped_values[key].iter().copied(), formatters);
+        if plural_base_keys.contains(key) {
+            // `count` is the method's first argument
+            params.remove("count");
         }
+        optional_params_table(&params)
+    };
+    let plural_types: BTreeMap<String, String> = plural_base_keys
+        .iter()
+        .map(|key| {
+            let method_type = if ordinal_keys.contains(key) {
+                ordinal_method_type(&instance, &grouped_params(key))
+            } else {
+                plural_method_type(&instance, &grouped_params(key))
+            };
+            (key.clone(), method_type)
+        })
+        .collect();
+    let select_types: BTreeMap<String, String> = select_cases
+        .iter()
+        .map(|(key, cases)| {
+            (
+                key.clone(),
+                select_method_type(&instance, cases, &grouped_params(key)),
+            )
+        })
+        .collect();
+    let list_params: BTreeMap<String, String> = list_keys
+        .iter()
+        .map(|key| (key.clone(), grouped_params(key)))
+        .collect();
+
+    let regular_types: BTreeMap<String, String> = regular_translations
+        .iter()
+        .map(|t| {
+            (
+                t.key.clone(),
+                regular_method_type(&instance, &t.value, formatters),
+            )
+        })
+        .collect();
+
+    // Add flat methods for regular translations
+    for (key, method_type) in &regular_types {
+        code.push_str(&format!(
+            "    {}: {},\n",
+            key.replace(".", "_"),
+            method_type
+        ));
     }
 
     // Add flat methods for plural translations
-    let mut plural_keys_sorted: Vec<_> = plural_base_keys.iter().collect();
-    plural_keys_sorted.sort();
-
-    for base_key in &plural_keys_sorted {
+    for (base_key, method_type) in &plural_types {
         let method_name = base_key.replace(".", "_");
+        code.push_str(&format!("    {}: {},\n", method_name, method_type));
+    }
+
+    // Add flat methods for selects
+    for (select_key, method_type) in &select_types {
         code.push_str(&format!(
-            "    {}: (self: TranslationsInstance, count: number, params: {{}}?) -> string,\n",
-            method_name
+            "    {}: {},\n",
+            select_key.replace(".", "_"),
+            method_type
+        ));
+    }
+
+    // Add flat methods for lists
+    for (list_key, params) in &list_params {
+        let method_name = list_key.replace(".", "_");
+        code.push_str(&format!(
+            "    {}: {},\n",
+            method_name,
+            list_method_type(&instance, params)
+        ));
+        code.push_str(&format!(
+            "    {}_random: {},\n",
+            method_name,
+            list_random_method_type(&instance, params)
         ));
     }
 
     code.push('\n');
 
-    // Add namespace structure
-    let namespaces = build_namespace_tree(&regular_translations, &plural_base_keys);
+    // Add namespace structure (selects and lists open namespaces just like plurals)
+    let grouped_keys: HashSet<String> = plural_base_keys
+        .iter()
+        .chain(select_cases.keys())
+        .chain(&list_keys)
+        .cloned()
+        .collect();
+    let namespaces = build_namespace_tree(&regular_translations, &grouped_keys);
     generate_namespace_types(
-        &mut code,
+        code,
+        &instance,
         &namespaces,
-        &regular_translations,
-        &plural_base_keys,
+        &regular_types,
+        &plural_types,
+        &select_types,
+        &list_params,
     );
 
     code.push_str("}\n");
+}
 
-    Ok(code)
+/// Parameter type used when there is no format specifier to go by
+const UNTYPED_PARAMETER: &str = "string | number";
+
+/// Luau types of the parameters used by `values`, by name
+///
+/// ICU arguments are typed by how the message uses them, other parameters by
+/// their format specifier (`{count:int}` is a `number`, `{date:datetime}` a
+/// `DateTime`). If the values disagree, a specifier wins over no specifier.
+fn parameter_types<'a>(
+    values: impl IntoIterator<Item = &'a str>,
+    formatters: &Formatters,
+) -> BTreeMap<String, String> {
+    let mut types: BTreeMap<String, String> = BTreeMap::new();
+
+    for value in values {
+        let value_types: Vec<(String, String)> = match icu::is_icu_message(value)
+            .then(|| icu::parse_message_with_formatters(value, formatters).ok())
+            .flatten()
+        {
+            Some(parts) => icu::parameters(&parts)
+                .into_iter()
+                .map(|(name, kind)| (name, kind.luau_type().to_string()))
+                .collect(),
+            None => format::extract_parameters_with_formatters(value, formatters)
+                .into_iter()
+                .map(|(name, specifier)| (name, specifier.luau_type().to_string()))
+                .collect(),
+        };
+
+        for (name, luau_type) in value_types {
+            let entry = types.entry(name).or_insert_with(|| luau_type.clone());
+            if *entry == UNTYPED_PARAMETER {
+                *entry = luau_type;
+            }
+        }
+    }
+
+    types
+}
+
+/// Luau table type of `params`: `{ count: number, name: string | number }`
+fn params_table(types: &BTreeMap<String, String>) -> String {
+    let fields: Vec<String> = types
+        .iter()
+        .map(|(name, luau_type)| format!("{}: {}", name, luau_type))
+        .collect();
+    format!("{{ {} }}", fields.join(", "))
+}
+
+/// Type of an optional `params` argument: `{}?` if there are no parameters
+fn optional_params_table(types: &BTreeMap<String, String>) -> String {
+    if types.is_empty() {
+        "{}?".to_string()
+    } else {
+        params_table(types)
+    }
+}
+
+/// Type of a regular translation's method
+fn regular_method_type(instance: &str, value: &str, formatters: &Formatters) -> String {
+    let types = parameter_types([value], formatters);
+    if types.is_empty() {
+        format!("(self: {}) -> string", instance)
+    } else {
+        format!(
+            "(self: {}, params: {}) -> string",
+            instance,
+            params_table(&types)
+        )
+    }
+}
+
+/// Type of a plural method (cardinal forms: "1 item", "5 items")
+fn plural_method_type(instance: &str, params: &str) -> String {
+    format!(
+        "(self: {}, count: number | string, params: {}) -> string",
+        instance, params
+    )
+}
+
+/// Type of an ordinal plural method ("1st", "2nd"), which takes a position
+fn ordinal_method_type(instance: &str, params: &str) -> String {
+    format!(
+        "(self: {}, position: number, params: {}) -> string",
+        instance, params
+    )
+}
+
+/// Type of a select method, taking one of the base locale's case names
+fn select_method_type(instance: &str, cases: &[&str], params: &str) -> String {
+    let mut cases = cases.to_vec();
+    selects::sort_cases(&mut cases);
+    let union: Vec<String> = cases.iter().map(|case| format::luau_string(case)).collect();
+
+    format!(
+        "(self: {}, case: {}, params: {}) -> string",
+        instance,
+        union.join(" | "),
+        params
+    )
+}
+
+/// Generate the type lines for the grouped methods that are direct children of `namespace`
+fn generate_grouped_method_types(
+    code: &mut String,
+    indent: &str,
+    namespace: &str,
+    method_types: &BTreeMap<String, String>,
+) {
+    for (key, method_type) in method_types {
+        if let Some((parent, method)) = key.rsplit_once('.') {
+            if parent == namespace {
+                code.push_str(&format!("{}{}: {},\n", indent, method, method_type));
+            }
+        }
+    }
+}
+
+/// Type of a list method (every item)
+fn list_method_type(instance: &str, params: &str) -> String {
+    format!("(self: {}, params: {}) -> {{string}}", instance, params)
+}
+
+/// Type of a list's random-pick method
+fn list_random_method_type(instance: &str, params: &str) -> String {
+    format!("(self: {}, params: {}) -> string", instance, params)
+}
+
+/// Generate the type lines for the lists' methods that are direct children of `namespace`
+fn generate_list_method_types(
+    code: &mut String,
+    instance: &str,
+    indent: &str,
+    namespace: &str,
+    list_params: &BTreeMap<String, String>,
+) {
+    for (list_key, params) in list_params {
+        let Some((parent, method)) = list_key.rsplit_once('.') else {
+            continue;
+        };
+        if parent != namespace {
+            continue;
+        }
+
+        code.push_str(&format!(
+            "{}{}: {},\n",
+            indent,
+            method,
+            list_method_type(instance, params)
+        ));
+        code.push_str(&format!(
+            "{}{}_random: {},\n",
+            indent,
+            method,
+            list_random_method_type(instance, params)
+        ));
+    }
 }
 
 /// Build namespace tree from translations
@@ -150,9 +490,12 @@ fn build_namespace_tree(
 /// Generate namespace type definitions
 fn generate_namespace_types(
     code: &mut String,
+    instance: &str,
     namespaces: &HashSet<String>,
-    translations: &[&Translation],
-    plural_base_keys: &HashSet<String>,
+    regular_types: &BTreeMap<String, String>,
+    plural_types: &BTreeMap<String, String>,
+    select_types: &BTreeMap<String, String>,
+    list_params: &BTreeMap<String, String>,
 ) {
     let mut sorted_namespaces: Vec<_> = namespaces.iter().collect();
     sorted_namespaces.sort();
@@ -167,30 +510,23 @@ fn generate_namespace_types(
             code.push_str(&format!("    {}: {{\n", last_part));
 
             // Add methods for this namespace (direct children only)
-            for translation in translations {
-                if translation.key.starts_with(&format!("{}.", namespace)) {
-                    let key_parts: Vec<&str> = translation.key.split('.').collect();
+            for (key, method_type) in regular_types {
+                if key.starts_with(&format!("{}.", namespace)) {
+                    let key_parts: Vec<&str> = key.split('.').collect();
 
                     // Only add if this is a direct child
                     if key_parts.len() == 2 {
                         let method = key_parts[1];
-                        let params = super::luau::extract_parameters(&translation.value);
-
-                        if params.is_empty() {
-                            code.push_str(&format!(
-                                "        {}: (self: TranslationsInstance) -> string,\n",
-                                method
-                            ));
-                        } else {
-                            code.push_str(&format!(
-                                "        {}: (self: TranslationsInstance, params: {{}}) -> string,\n",
-                                method
-                            ));
-                        }
+                        code.push_str(&format!("        {}: {},\n", method, method_type));
                     }
                 }
             }
 
+            // Add plurals, selects and lists for this namespace
+            generate_grouped_method_types(code, "        ", namespace, plural_types);
+            generate_grouped_method_types(code, "        ", namespace, select_types);
+            generate_list_method_types(code, instance, "        ", namespace, list_params);
+
             // Add nested namespaces
             for nested in sorted_namespaces.iter() {
                 if nested.starts_with(&format!("{}.", namespace)) {
@@ -200,45 +536,31 @@ fn generate_namespace_types(
                         code.push_str(&format!("        {}: {{\n", nested_name));
 
                         // Add methods for nested namespace
-                        for translation in translations {
-                            if translation.key.starts_with(&format!("{}.", nested)) {
-                                let key_parts: Vec<&str> = translation.key.split('.').collect();
-
-                                if key_parts.len() == 3 {
-                                    let method = key_parts[2];
-                                    let params =
-                                        super::luau::extract_parameters(&translation.value);
-
-                                    if params.is_empty() {
-                                        code.push_str(&format!(
-                                            "            {}: (self: TranslationsInstance) -> string,\n",
-                                            method
-                                        ));
-                                    } else {
-                                        code.push_str(&format!(
-                                            "            {}: (self: TranslationsInstance, params: {{}}) -> string,\n",
-                                            method
-                                        ));
-                                    }
-                                }
-                            }
-                        }
-
-                        // Add plural methods for nested namespace
-                        for base_key in plural_base_keys {
-                            if base_key.starts_with(&format!("{}.", nested)) {
-                                let key_parts: Vec<&str> = base_key.split('.').collect();
+                        for (key, method_type) in regular_types {
+                            if key.starts_with(&format!("{}.", nested)) {
+                                let key_parts: Vec<&str> = key.split('.').collect();
 
                                 if key_parts.len() == 3 {
                                     let method = key_parts[2];
                                     code.push_str(&format!(
-                                        "            {}: (self: TranslationsInstance, count: number, params: {{}}?) -> string,\n",
-                                        method
+                                        "            {}: {},\n",
+                                        method, method_type
                                     ));
                                 }
                             }
                         }
 
+                        // Add plurals, selects and lists for nested namespace
+                        generate_grouped_method_types(code, "            ", nested, plural_types);
+                        generate_grouped_method_types(code, "            ", nested, select_types);
+                        generate_list_method_types(
+                            code,
+                            instance,
+                            "            ",
+                            nested,
+                            list_params,
+                        );
+
                         code.push_str("        },\n");
                     }
                 }
@@ -261,12 +583,18 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.labels.welcome".to_string(),
                 value: "Welcome".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -287,12 +615,18 @@ mod tests {
                 value: "{count} item".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.messages.items(other)".to_string(),
                 value: "{count} items".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -300,15 +634,255 @@ mod tests {
 
         // Should have flat method with count parameter
         assert!(code.contains(
-            "ui_messages_items: (self: TranslationsInstance, count: number, params: {}?) -> string"
+            "ui_messages_items: (self: TranslationsInstance, count: number | string, params: {}?) -> string"
         ));
 
         // Should have namespace method with count parameter
-        assert!(code
-            .contains("items: (self: TranslationsInstance, count: number, params: {}?) -> string"));
+        assert!(code.contains(
+            "items: (self: TranslationsInstance, count: number | string, params: {}?) -> string"
+        ));
 
         // Should NOT have invalid syntax like items(one) or items(other)
         assert!(!code.contains("items(one)"));
         assert!(!code.contains("items(other)"));
     }
+
+    #[test]
+    fn test_generate_type_definitions_with_ordinals() {
+        let translations: Vec<_> = [
+            "race.results.place(ordinal:one)",
+            "race.results.place(ordinal:other)",
+        ]
+        .iter()
+        .map(|key| Translation {
+            key: key.to_string(),
+            value: "{count}".to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        })
+        .collect();
+
+        let code = generate_type_definitions(&translations, "en").unwrap();
+
+        assert!(code.contains(
+            "race_results_place: (self: TranslationsInstance, position: number, params: {}?) -> string"
+        ));
+        assert!(code.contains(
+            "            place: (self: TranslationsInstance, position: number, params: {}?) -> string"
+        ));
+        assert!(!code.contains("(ordinal:"));
+    }
+
+    #[test]
+    fn test_generate_type_definitions_with_typed_params() {
+        let translations: Vec<_> = [
+            (
+                "shop.receipt",
+                "{name} paid {price:fixed(2)} for {amount:int} on {date:datetime}",
+            ),
+            ("shop.items(one)", "{name} has {count} item"),
+            (
+                "shop.items(other)",
+                "{name} has {count:int} items in {bag:translate}",
+            ),
+        ]
+        .iter()
+        .map(|(key, value)| Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        })
+        .collect();
+
+        let code = generate_type_definitions(&translations, "en").unwrap();
+
+        assert!(code.contains(
+            "        receipt: (self: TranslationsInstance, params: { amount: number, date: DateTime, name: string | number, price: number }) -> string,"
+        ));
+        assert!(code.contains(
+            "    shop_items: (self: TranslationsInstance, count: number | string, params: { bag: string, name: string | number }) -> string,"
+        ));
+    }
+
+    #[test]
+    fn test_generate_type_definitions_with_custom_formatters() {
+        let translations = vec![Translation {
+            key: "hud.status".to_string(),
+            value: "{hp:health} {clock:gametime} {name}".to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }];
+        let formatter = |input: &str| crate::config::FormatterConfig {
+            module: "game.ReplicatedStorage.Formatters".to_string(),
+            function: "format".to_string(),
+            input: input.to_string(),
+        };
+        let formatters = Formatters::from([
+            ("health".to_string(), formatter("number")),
+            ("gametime".to_string(), formatter("unknown")),
+        ]);
+
+        let code = generate_type_definitions_for_module(
+            &translations,
+            "en",
+            &[],
+            DEFAULT_MODULE_NAME,
+            &formatters,
+        )
+        .unwrap();
+
+        assert!(code.contains(
+            "        status: (self: TranslationsInstance, params: { clock: unknown, hp: number, name: string | number }) -> string,"
+        ));
+    }
+
+    #[test]
+    fn test_generate_type_definitions_with_icu_messages() {
+        let translations = vec![Translation {
+            key: "party.invite".to_string(),
+            value: "{host} invited {guests, plural, one {# guest} other {# guests}} \
+                    to {gender, select, male {his} female {her} other {their}} party"
+                .to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }];
+
+        let code = generate_type_definitions(&translations, "en").unwrap();
+
+        assert!(code.contains(
+            "party_invite: (self: TranslationsInstance, params: { gender: string, guests: number, host: string | number }) -> string"
+        ));
+        assert!(code.contains(
+            "        invite: (self: TranslationsInstance, params: { gender: string, guests: number, host: string | number }) -> string"
+        ));
+    }
+
+    #[test]
+    fn test_generate_type_definitions_with_selects() {
+        let translations: Vec<_> = [
+            "ui.hud.greeting(select:other)",
+            "ui.hud.greeting(select:male)",
+            "ui.hud.greeting(select:female)",
+        ]
+        .iter()
+        .map(|key| Translation {
+            key: key.to_string(),
+            value: "Hi".to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        })
+        .collect();
+
+        let code = generate_type_definitions(&translations, "en").unwrap();
+
+        let method_type = "(self: TranslationsInstance, case: \"female\" | \"male\" | \"other\", params: {}?) -> string";
+        assert!(code.contains(&format!("    ui_hud_greeting: {},", method_type)));
+        assert!(code.contains(&format!("            greeting: {},", method_type)));
+        assert!(!code.contains("(select:"));
+    }
+
+    #[test]
+    fn test_generate_type_definitions_with_lists() {
+        let translations: Vec<_> = ["tips.0", "tips.1", "ui.hints.0"]
+            .iter()
+            .map(|key| Translation {
+                key: key.to_string(),
+                value: "Hint".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            })
+            .collect();
+
+        let code = generate_type_definitions(&translations, "en").unwrap();
+
+        assert!(code.contains("    tips: (self: TranslationsInstance, params: {}?) -> {string},"));
+        assert!(
+            code.contains("    tips_random: (self: TranslationsInstance, params: {}?) -> string,")
+        );
+        assert!(code.contains("ui_hints: (self: TranslationsInstance, params: {}?) -> {string},"));
+        assert!(
+            code.contains("        hints: (self: TranslationsInstance, params: {}?) -> {string},")
+        );
+        assert!(!code.contains("tips_0"));
+    }
+
+    #[test]
+    fn test_generate_type_definitions_with_variants() {
+        let translations = vec![Translation {
+            key: "ui.buy".to_string(),
+            value: "Buy".to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }];
+        let variants = vec![Variant {
+            name: "halloween".to_string(),
+            start: None,
+            end: None,
+            segments: Vec::new(),
+            translations: Vec::new(),
+        }];
+
+        let code = generate_type_definitions_for_module(
+            &translations,
+            "en",
+            &variants,
+            DEFAULT_MODULE_NAME,
+            &Formatters::new(),
+        )
+        .unwrap();
+        assert!(code.contains("    setVariant: (self: TranslationsInstance, name: string?) -> (),"));
+        assert!(code.contains("    getVariant: (self: TranslationsInstance) -> string?,"));
+
+        let plain = generate_type_definitions(&translations, "en").unwrap();
+        assert!(!plain.contains("setVariant"));
+    }
+
+    #[test]
+    fn test_generate_type_definitions_for_module() {
+        let translations = vec![Translation {
+            key: "ui.buy".to_string(),
+            value: "Buy".to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }];
+
+        let code = generate_type_definitions_for_module(
+            &translations,
+            "en",
+            &[],
+            "GameStrings",
+            &Formatters::new(),
+        )
+        .unwrap();
+        assert!(code.contains("export type GameStrings = {\n"));
+        assert!(code.contains("    new: (locale: string?) -> GameStringsInstance,\n"));
+        assert!(code.contains("export type GameStringsInstance = {\n"));
+        assert!(code.contains("        buy: (self: GameStringsInstance) -> string,\n"));
+        assert!(!code.contains("TranslationsInstance"));
+    }
 }
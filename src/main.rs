@@ -61,7 +61,7 @@ enum Commands {
 
     /// Validate translations for errors and inconsistencies
     ///
-    /// Checks for missing translations, unused keys, conflicts, and coverage.
+    /// Checks for missing translations, unused keys, conflicts, overrides, plural forms, select cases, placeholders, links, and coverage.
     /// Use --all to run all checks at once.
     Validate {
         /// Check for missing translations across locales
@@ -76,6 +76,26 @@ enum Commands {
         #[arg(long, help = "Check for conflicts")]
         conflicts: bool,
 
+        /// Check overrides for unknown keys, unsupported locales and parameter drift
+        #[arg(long, help = "Check overrides")]
+        overrides: bool,
+
+        /// Check that plural keys have every form each locale's plural rules need
+        #[arg(long, help = "Check plural forms")]
+        plurals: bool,
+
+        /// Check that select keys have an `other` case and the same cases in every locale
+        #[arg(long, help = "Check select cases")]
+        selects: bool,
+
+        /// Check placeholders for unknown format specifiers and unbalanced braces
+        #[arg(long, help = "Check placeholders")]
+        placeholders: bool,
+
+        /// Check that `@:key` links point to existing keys and don't loop
+        #[arg(long, help = "Check links")]
+        links: bool,
+
         /// Show translation coverage report per locale
         #[arg(long, help = "Show coverage report")]
         coverage: bool,
@@ -241,6 +261,11 @@ fn main() -> Result<()> {
             missing,
             unused,
             conflicts,
+            overrides,
+            plurals,
+            selects,
+            placeholders,
+            links,
             coverage,
             source,
             all,
@@ -248,10 +273,17 @@ fn main() -> Result<()> {
             let config_path = Path::new("slang-roblox.yaml");
 
             // If --all is specified, enable all checks
-            let check_missing = all || missing;
-            let check_unused = all || unused;
-            let check_conflicts = all || conflicts;
-            let show_coverage = all || coverage;
+            let checks = cli::Checks {
+                missing: all || missing,
+                unused: all || unused,
+                conflicts: all || conflicts,
+                overrides: all || overrides,
+                plurals: all || plurals,
+                selects: all || selects,
+                placeholders: all || placeholders,
+                links: all || links,
+                coverage: all || coverage,
+            };
 
             let source_dir = if let Some(ref s) = source {
                 let path = Path::new(s.as_str());
@@ -262,14 +294,7 @@ fn main() -> Result<()> {
                 None
             };
 
-            cli::validate(
-                config_path,
-                check_missing,
-                check_unused,
-                check_conflicts,
-                show_coverage,
-                source_dir,
-            )?;
+            cli::validate(config_path, checks, source_dir)?;
         }
         Commands::Migrate {
             from,
@@ -0,0 +1,431 @@
+//! Roblox localization table CSV sources
+//!
+//! A CSV file in the input directory holds every locale of a table in one file,
+//! one row per key. Columns are matched by header name, so both the layout written
+//! by `build` (`Source,Context,Key,Example,<locales>`) and the layout exported by
+//! Roblox (`Key,Source,Context,Example,<locales>`) are accepted. Every column that
+//! isn't one of [`CSV_METADATA_COLUMNS`] is a locale.
+//!
+//! Reading and writing follow RFC 4180: quoted cells may contain commas, quotes
+//! and line breaks. A leading UTF-8 BOM and CRLF line endings are accepted and
+//! kept when a table is written back, and cells are only quoted when needed, as
+//! in Roblox's own export.
+
+use super::types::*;
+use anyhow::{bail, Context, Result};
+use csv::{QuoteStyle, ReaderBuilder, Terminator, WriterBuilder};
+use std::path::Path;
+
+/// Non-locale columns of a Roblox localization CSV
+pub const CSV_METADATA_COLUMNS: &[&str] = &["Key", "Source", "Context", "Example"];
+
+/// A parsed localization table CSV
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvTable {
+    /// Column names in file order
+    pub headers: Vec<String>,
+
+    /// Data rows, each padded to the header length
+    pub rows: Vec<CsvRow>,
+
+    /// Whether the file starts with a UTF-8 byte order mark
+    pub bom: bool,
+
+    /// Whether records end with `\r\n` instead of `\n`
+    pub crlf: bool,
+}
+
+/// A single row of a localization table CSV
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvRow {
+    /// Line the row starts on in the source file (1-based, 0 for rows added in memory)
+    pub line: usize,
+
+    /// Cell values in header order
+    pub cells: Vec<String>,
+}
+
+impl CsvTable {
+    /// Create an empty table with the given columns
+    pub fn new(headers: Vec<String>) -> Self {
+        CsvTable {
+            headers,
+            rows: Vec::new(),
+            bom: false,
+            crlf: false,
+        }
+    }
+
+    /// Parse CSV content
+    pub fn parse(content: &str) -> Result<Self> {
+        let (body, bom) = match content.strip_prefix('\u{feff}') {
+            Some(body) => (body, true),
+            None => (content, false),
+        };
+
+        let mut reader = ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(body.as_bytes());
+        let mut records = reader.records();
+
+        let header = match records.next() {
+            Some(header) => header.context("Invalid CSV header")?,
+            None => bail!("CSV file is empty"),
+        };
+
+        let mut table = CsvTable::new(header.iter().map(|h| h.trim().to_string()).collect());
+        table.bom = bom;
+        table.crlf = body
+            .find('\n')
+            .map(|end| body[..end].ends_with('\r'))
+            .unwrap_or(false);
+
+        if table.column("Key").is_none() {
+            bail!(
+                "Invalid CSV header: missing 'Key' column\n\
+                 Found columns: {}\n\
+                 \n\
+                 Hint: Localization CSVs need Source, Context and Key columns followed by\n\
+                 one column per locale (e.g., Source,Context,Key,en,id)",
+                table.headers.join(", ")
+            );
+        }
+
+        for record in records {
+            let record = record.context("Invalid CSV row")?;
+
+            if record.iter().all(|cell| cell.trim().is_empty()) {
+                continue;
+            }
+
+            let mut cells: Vec<String> = record.iter().map(str::to_string).collect();
+            cells.resize(table.headers.len(), String::new());
+
+            table.rows.push(CsvRow {
+                line: record.position().map(|p| p.line() as usize).unwrap_or(0),
+                cells,
+            });
+        }
+
+        Ok(table)
+    }
+
+    /// Read and parse a CSV file
+    pub fn read(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .context(format!("Failed to read CSV file: {}", path.display()))?;
+
+        CsvTable::parse(&content).context(format!("Failed to parse CSV: {}", path.display()))
+    }
+
+    /// Write the table back to disk
+    pub fn write(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_csv()?).context(format!("Failed to write {}", path.display()))
+    }
+
+    /// Serialize the table, keeping column and row order
+    pub fn to_csv(&self) -> Result<String> {
+        let terminator = if self.crlf {
+            Terminator::CRLF
+        } else {
+            Terminator::Any(b'\n')
+        };
+
+        let mut writer = WriterBuilder::new()
+            .quote_style(QuoteStyle::Necessary)
+            .terminator(terminator)
+            .from_writer(Vec::new());
+
+        writer.write_record(&self.headers)?;
+        for row in &self.rows {
+            writer.write_record(&row.cells)?;
+        }
+
+        let mut csv = if self.bom {
+            String::from('\u{feff}')
+        } else {
+            String::new()
+        };
+        csv.push_str(&String::from_utf8(writer.into_inner()?)?);
+
+        Ok(csv)
+    }
+
+    /// Find a column by name (case-insensitive)
+    pub fn column(&self, name: &str) -> Option<usize> {
+        self.headers
+            .iter()
+            .position(|h| h.trim().eq_ignore_ascii_case(name))
+    }
+
+    /// Locale columns, in file order
+    pub fn locales(&self) -> Vec<String> {
+        self.headers
+            .iter()
+            .filter(|h| {
+                !CSV_METADATA_COLUMNS
+                    .iter()
+                    .any(|m| h.trim().eq_ignore_ascii_case(m))
+            })
+            .map(|h| h.trim().to_string())
+            .collect()
+    }
+
+    /// Whether the table provides text for a locale
+    ///
+    /// The base locale is also served by the `Source` column, which is the only
+    /// column Roblox exports for the source language.
+    pub fn has_locale(&self, locale: &str, base_locale: Option<&str>) -> bool {
+        self.column(locale).is_some()
+            || (base_locale == Some(locale) && self.column("Source").is_some())
+    }
+
+    /// Translations for one locale
+    ///
+    /// Rows without a key (e.g. auto-captured strings) are skipped. When `path`
+    /// is given, each translation records the row it came from.
+    pub fn translations(
+        &self,
+        locale: &str,
+        base_locale: Option<&str>,
+        path: Option<&Path>,
+    ) -> Vec<Translation> {
+        let key_column = self.column("Key");
+        let locale_column = self.column(locale);
+        let source_column = if base_locale == Some(locale) {
+            self.column("Source")
+        } else {
+            None
+        };
+
+        let mut translations = Vec::new();
+
+        for row in &self.rows {
+            let key = match cell(row, key_column) {
+                Some(key) => key,
+                None => continue,
+            };
+
+            let value = match cell(row, locale_column).or_else(|| cell(row, source_column)) {
+                Some(value) => value,
+                None => continue,
+            };
+
+            translations.push(Translation {
+                key: key.to_string(),
+                value: value.to_string(),
+                locale: locale.to_string(),
+                context: cell(row, self.column("Context")).map(str::to_string),
+                description: cell(row, self.column("Example")).map(str::to_string),
+                max_length: None,
+                location: path.map(|p| SourceLocation {
+                    file: p.to_path_buf(),
+                    line: row.line,
+                    column: 1,
+                }),
+            });
+        }
+
+        translations
+    }
+
+    /// Whether a row exists for a key
+    pub fn contains_key(&self, key: &str) -> bool {
+        let key_column = self.column("Key");
+        self.rows
+            .iter()
+            .any(|row| cell(row, key_column) == Some(key))
+    }
+
+    /// Set the text of a key for a locale, adding the locale column if needed
+    ///
+    /// For the base locale the `Source` column is kept in sync as well. Adds a new
+    /// row (with context and description) if the key isn't in the table yet.
+    pub fn set(&mut self, translation: &Translation, base_locale: Option<&str>) {
+        let key_column = self.column("Key");
+        let is_base = base_locale == Some(translation.locale.as_str());
+
+        let mut locale_column = self.column(&translation.locale);
+        if locale_column.is_none() && !(is_base && self.column("Source").is_some()) {
+            self.headers.push(translation.locale.clone());
+            for row in &mut self.rows {
+                row.cells.push(String::new());
+            }
+            locale_column = Some(self.headers.len() - 1);
+        }
+
+        let index = match self
+            .rows
+            .iter()
+            .position(|row| cell(row, key_column) == Some(translation.key.as_str()))
+        {
+            Some(index) => index,
+            None => {
+                let mut cells = vec![String::new(); self.headers.len()];
+                let metadata = [
+                    ("Key", Some(&translation.key)),
+                    ("Context", translation.context.as_ref()),
+                    ("Example", translation.description.as_ref()),
+                ];
+                for (name, value) in metadata {
+                    if let (Some(column), Some(value)) = (self.column(name), value) {
+                        cells[column] = value.clone();
+                    }
+                }
+                self.rows.push(CsvRow { line: 0, cells });
+                self.rows.len() - 1
+            }
+        };
+
+        let source_column = if is_base { self.column("Source") } else { None };
+        let row = &mut self.rows[index];
+        for column in [locale_column, source_column].into_iter().flatten() {
+            row.cells[column] = translation.value.clone();
+        }
+    }
+}
+
+/// Non-empty cell value of a row
+fn cell(row: &CsvRow, column: Option<usize>) -> Option<&str> {
+    column
+        .and_then(|c| row.cells.get(c))
+        .map(|v| v.as_str())
+        .filter(|v| !v.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(key: &str, locale: &str, value: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_roblox_export_layout() {
+        let content = "Key,Source,Context,Example,es,id\n\
+                       ui.buy,Buy,button,Shop button,Comprar,Beli\n\
+                       ,Auto captured,,,,\n\
+                       ui.sell,Sell,,,Vender,\n";
+
+        let table = CsvTable::parse(content).unwrap();
+
+        assert_eq!(table.locales(), vec!["es", "id"]);
+        assert!(table.has_locale("en", Some("en")));
+        assert!(!table.has_locale("en", None));
+
+        let en = table.translations("en", Some("en"), None);
+        assert_eq!(en.len(), 2);
+        assert_eq!(en[0].value, "Buy");
+        assert_eq!(en[0].context.as_deref(), Some("button"));
+        assert_eq!(en[0].description.as_deref(), Some("Shop button"));
+
+        let id = table.translations("id", Some("en"), Some(Path::new("t.csv")));
+        assert_eq!(id.len(), 1);
+        assert_eq!(id[0].location.as_ref().unwrap().to_string(), "t.csv:2:1");
+    }
+
+    #[test]
+    fn test_parse_requires_key_column() {
+        let err = CsvTable::parse("Source,Context\n").unwrap_err();
+        assert!(err.to_string().contains("missing 'Key' column"));
+    }
+
+    #[test]
+    fn test_set_updates_existing_and_adds_rows_and_columns() {
+        let content = "Key,Source,Context,Example,es\nui.buy,Buy,,,Comprar\n";
+        let mut table = CsvTable::parse(content).unwrap();
+
+        table.set(&translation("ui.buy", "en", "Purchase"), Some("en"));
+        table.set(&translation("ui.buy", "fr", "Acheter"), Some("en"));
+        table.set(&translation("ui.sell", "es", "Vender"), Some("en"));
+
+        assert_eq!(
+            table.to_csv().unwrap(),
+            "Key,Source,Context,Example,es,fr\n\
+             ui.buy,Purchase,,,Comprar,Acheter\n\
+             ui.sell,,,,Vender,\n"
+        );
+    }
+
+    const ROBLOX_EXPORT: &str = include_str!("../../tests/fixtures/csv/roblox_export.csv");
+    const ROBLOX_EXPORT_BOM_CRLF: &str =
+        include_str!("../../tests/fixtures/csv/roblox_export_bom_crlf.csv");
+
+    #[test]
+    fn test_roblox_export_roundtrip() {
+        for fixture in [ROBLOX_EXPORT, ROBLOX_EXPORT_BOM_CRLF] {
+            let table = CsvTable::parse(fixture).unwrap();
+            assert_eq!(table.to_csv().unwrap(), fixture);
+        }
+    }
+
+    #[test]
+    fn test_roblox_export_values() {
+        let table = CsvTable::parse(ROBLOX_EXPORT).unwrap();
+
+        assert!(!table.bom);
+        assert!(!table.crlf);
+        assert_eq!(table.locales(), vec!["es", "id", "pt"]);
+
+        let en = table.translations("en", Some("en"), Some(Path::new("t.csv")));
+        assert_eq!(en.len(), 4);
+        assert_eq!(en[1].value, "{price} coins, {discount}% off");
+        assert_eq!(
+            en[1].description.as_deref(),
+            Some("Shown under each item, e.g. \"100 coins, 10% off\"")
+        );
+        assert_eq!(en[2].key, "dialog.intro");
+        assert_eq!(en[2].value, "Welcome, traveler!\nThe shop opens at dawn.");
+        assert_eq!(en[2].context.as_deref(), Some("NPC.Merchant"));
+        assert_eq!(en[3].value, "Press \"E\" to talk");
+
+        // The row after the multi-line cell still reports its own line
+        assert_eq!(en[3].location.as_ref().unwrap().to_string(), "t.csv:9:1");
+
+        let id = table.translations("id", Some("en"), None);
+        assert_eq!(id.len(), 3);
+        assert_eq!(
+            id[2].value,
+            "Selamat datang, pengembara!\nToko buka saat fajar."
+        );
+    }
+
+    #[test]
+    fn test_roblox_export_bom_crlf_values() {
+        let table = CsvTable::parse(ROBLOX_EXPORT_BOM_CRLF).unwrap();
+
+        assert!(table.bom);
+        assert!(table.crlf);
+        assert_eq!(table.headers[0], "Key");
+
+        let es = table.translations("es", Some("en"), None);
+        assert_eq!(es.len(), 4);
+        assert_eq!(es[0].key, "shop.buy");
+        assert_eq!(
+            es[2].value,
+            "¡Bienvenido, viajero!\r\nLa tienda abre al amanecer."
+        );
+    }
+
+    #[test]
+    fn test_set_keeps_bom_and_crlf() {
+        let mut table = CsvTable::parse(ROBLOX_EXPORT_BOM_CRLF).unwrap();
+        table.set(&translation("shop.buy", "es", "Adquirir"), Some("en"));
+
+        let csv = table.to_csv().unwrap();
+        assert!(csv.starts_with("\u{feff}Key,Source"));
+        assert!(
+            csv.contains("shop.buy,Buy,,Label on the purchase button,Adquirir,Beli,Comprar\r\n")
+        );
+    }
+}
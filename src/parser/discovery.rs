@@ -0,0 +1,680 @@
+//! Translation source discovery
+//!
+//! Locates every translation file that belongs to a locale. Two layouts are
+//! supported and can be combined:
+//!
+//! - `translations/<locale>.json` (or `.jsonc`/`.json5`/`.yaml`/`.yml`) - keys are
+//!   used as-is
+//! - `translations/<locale>/**/<name>.json` - the path relative to the locale
+//!   directory becomes a key prefix (`shop/items.yaml` → `shop.items.*`)
+//!
+//! Localization table CSVs directly inside the input directory hold every locale
+//! in one file and are read for each locale that has a column in them.
+
+use super::types::*;
+use super::{parse_json_file_with_policy, parse_yaml_file_with_policy, CsvTable, SourceDocument};
+use crate::config::ScalarPolicy;
+use crate::utils::flatten;
+use crate::utils::validation;
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// File extensions recognized as translation sources, in lookup priority order
+pub const SOURCE_EXTENSIONS: &[&str] = &["json", "jsonc", "json5", "yaml", "yml"];
+
+/// The sources looked up for a locale, for "not found" messages
+/// Example: "id" -> "id.json/.jsonc/.json5/.yaml/.yml, id/ and *.csv"
+pub fn locale_source_patterns(locale: &str) -> String {
+    format!(
+        "{}.{}, {}/ and *.csv",
+        locale,
+        SOURCE_EXTENSIONS.join("/."),
+        locale
+    )
+}
+
+/// A translation file discovered for a locale
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    /// Path to the file on disk
+    pub path: PathBuf,
+
+    /// Key prefix derived from the file's location (None for `<locale>.json`)
+    pub prefix: Option<String>,
+}
+
+/// Check if a path has a supported translation file extension
+pub fn is_source_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| SOURCE_EXTENSIONS.contains(&ext))
+        .unwrap_or(false)
+}
+
+/// Discover all translation files for a locale
+///
+/// Returns the single-file source (if any) first, followed by the files of the
+/// locale directory sorted by path. Only the first existing single file is used,
+/// in the order of [`SOURCE_EXTENSIONS`].
+pub fn discover_locale_files(input_dir: &Path, locale: &str) -> Result<Vec<SourceFile>> {
+    let mut files = Vec::new();
+
+    if let Some(path) = SOURCE_EXTENSIONS
+        .iter()
+        .map(|ext| input_dir.join(format!("{}.{}", locale, ext)))
+        .find(|p| p.is_file())
+    {
+        files.push(SourceFile { path, prefix: None });
+    }
+
+    let locale_dir = input_dir.join(locale);
+    if locale_dir.is_dir() {
+        let mut nested = Vec::new();
+        collect_source_files(&locale_dir, &mut nested)?;
+        nested.sort();
+
+        for path in nested {
+            let prefix = key_prefix_for(&locale_dir, &path)?;
+            files.push(SourceFile {
+                path,
+                prefix: Some(prefix),
+            });
+        }
+    }
+
+    Ok(files)
+}
+
+/// Discover localization table CSVs (`translations/*.csv`), sorted by path
+pub fn discover_csv_sources(input_dir: &Path) -> Result<Vec<PathBuf>> {
+    if !input_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let entries = std::fs::read_dir(input_dir)
+        .context(format!("Failed to read directory {}", input_dir.display()))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let is_csv = path.extension().and_then(|ext| ext.to_str()) == Some("csv");
+        if is_csv && path.is_file() && !is_hidden(&path) {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .map(|n| n.starts_with('.'))
+        .unwrap_or(false)
+}
+
+/// Recursively collect translation files in a directory
+fn collect_source_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let entries =
+        std::fs::read_dir(dir).context(format!("Failed to read directory {}", dir.display()))?;
+
+    for entry in entries {
+        let path = entry?.path();
+
+        // Skip hidden files and directories (.DS_Store, .git, ...)
+        if is_hidden(&path) {
+            continue;
+        }
+
+        if path.is_dir() {
+            collect_source_files(&path, files)?;
+        } else if is_source_file(&path) {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Derive the key prefix for a file inside a locale directory
+/// Example: `translations/en/shop/items.yaml` -> "shop.items"
+fn key_prefix_for(locale_dir: &Path, path: &Path) -> Result<String> {
+    let relative = path.strip_prefix(locale_dir).unwrap_or(path);
+    let mut parts: Vec<String> = relative
+        .parent()
+        .map(|p| {
+            p.components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let Some(stem) = relative.file_stem() {
+        parts.push(stem.to_string_lossy().to_string());
+    }
+
+    let prefix = parts.join(".");
+
+    validation::validate_translation_key(&prefix).map_err(|e| {
+        anyhow::anyhow!(
+            "Invalid translation file location: {}\n\
+             {}\n\
+             \n\
+             Hint: Folder and file names inside a locale directory become key prefixes,\n\
+             so they must be valid key segments (e.g., 'shop/items.json' → 'shop.items')",
+            path.display(),
+            e
+        )
+    })?;
+
+    Ok(prefix)
+}
+
+/// Parse a single translation file, choosing the parser from its extension
+pub fn parse_source_file(
+    path: &Path,
+    locale: &str,
+    policy: ScalarPolicy,
+) -> Result<Vec<Translation>> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") | Some("jsonc") | Some("json5") => {
+            parse_json_file_with_policy(path, locale, policy)
+                .context(format!("Failed to parse JSON for {}", locale))
+        }
+        Some("yaml") | Some("yml") => parse_yaml_file_with_policy(path, locale, policy)
+            .context(format!("Failed to parse YAML for {}", locale)),
+        _ => bail!("Unsupported translation file: {}", path.display()),
+    }
+}
+
+/// Parse the rows of a localization table CSV for a locale
+fn parse_csv_source(
+    table: &CsvTable,
+    path: &Path,
+    locale: &str,
+    base_locale: &str,
+) -> Result<Vec<Translation>> {
+    let translations = table.translations(locale, Some(base_locale), Some(path));
+
+    for translation in &translations {
+        validation::validate_translation_key(&translation.key).map_err(|e| {
+            anyhow::anyhow!(
+                "Invalid translation key in: {}\n\
+                 {}\n\
+                 \n\
+                 Hint: Translation keys should use dot notation (e.g., 'ui.button.buy')",
+                translation
+                    .location
+                    .as_ref()
+                    .map(|l| l.to_string())
+                    .unwrap_or_else(|| path.display().to_string()),
+                e
+            )
+        })?;
+    }
+
+    Ok(translations)
+}
+
+/// Load and merge all translation files for a locale
+///
+/// Returns `None` if the locale has no translation files at all. Keys defined in
+/// more than one file are reported as an error naming both files. For the base
+/// locale, a CSV's `Source` column is used when it has no column of its own.
+/// Numbers and booleans in JSON/YAML files are handled according to `policy`.
+pub fn load_locale_translations(
+    input_dir: &Path,
+    locale: &str,
+    base_locale: &str,
+    policy: ScalarPolicy,
+) -> Result<Option<Vec<Translation>>> {
+    let mut sources: Vec<(PathBuf, Vec<Translation>)> = Vec::new();
+
+    for file in discover_locale_files(input_dir, locale)? {
+        let mut parsed = parse_source_file(&file.path, locale, policy)?;
+        if let Some(prefix) = &file.prefix {
+            for translation in &mut parsed {
+                translation.key = format!("{}.{}", prefix, translation.key);
+            }
+        }
+        sources.push((file.path, parsed));
+    }
+
+    for path in discover_csv_sources(input_dir)? {
+        let table = CsvTable::read(&path)?;
+        if table.has_locale(locale, Some(base_locale)) {
+            let parsed = parse_csv_source(&table, &path, locale, base_locale)
+                .context(format!("Failed to parse CSV for {}", locale))?;
+            sources.push((path, parsed));
+        }
+    }
+
+    if sources.is_empty() {
+        return Ok(None);
+    }
+
+    let mut translations = Vec::new();
+    // Key -> where it was first defined (file:line:column when known)
+    let mut owners: HashMap<String, String> = HashMap::new();
+
+    for (path, parsed) in sources {
+        for translation in parsed {
+            let defined_at = match &translation.location {
+                Some(location) => location.to_string(),
+                None => path.display().to_string(),
+            };
+
+            if let Some(first) = owners.get(&translation.key) {
+                bail!(
+                    "Duplicate translation key '{}' for locale '{}'\n\
+                     First defined in: {}\n\
+                     Defined again in: {}\n\
+                     \n\
+                     Hint: Each key may only be defined once per locale. Remove one of the\n\
+                     definitions or move it to a different namespace.",
+                    translation.key,
+                    locale,
+                    first,
+                    defined_at
+                );
+            }
+
+            owners.insert(translation.key.clone(), defined_at);
+            translations.push(translation);
+        }
+    }
+
+    Ok(Some(translations))
+}
+
+/// Check if a locale has at least one translation file
+pub fn locale_has_sources(input_dir: &Path, locale: &str, base_locale: &str) -> bool {
+    let has_files = discover_locale_files(input_dir, locale)
+        .map(|files| !files.is_empty())
+        .unwrap_or(false);
+
+    has_files
+        || discover_csv_sources(input_dir)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|path| CsvTable::read(path).ok())
+            .any(|table| table.has_locale(locale, Some(base_locale)))
+}
+
+/// Write translations for a locale back to its source files
+///
+/// Keys that already have a row in a localization table CSV are updated in place.
+/// Other keys are routed to the existing file with the longest matching prefix,
+/// with the prefix stripped. Keys that match no file go to the single-file source,
+/// which is created if the locale doesn't have one yet (in the base locale's format,
+/// e.g. `<locale>.yaml`, falling back to `.json`) - unless the project only uses
+/// CSVs, in which case they are added to the first CSV.
+///
+/// Files are updated in place: existing keys keep their position, new keys are
+/// added next to their siblings, keys that aren't passed in are left alone, and
+/// the file's indentation and line endings are preserved.
+pub fn write_locale_translations(
+    input_dir: &Path,
+    locale: &str,
+    base_locale: &str,
+    translations: &[Translation],
+) -> Result<()> {
+    let files = discover_locale_files(input_dir, locale)?;
+
+    let mut tables: Vec<(PathBuf, CsvTable, bool)> = Vec::new();
+    for path in discover_csv_sources(input_dir)? {
+        let table = CsvTable::read(&path)?;
+        tables.push((path, table, false));
+    }
+    let csv_only = files.is_empty() && !tables.is_empty();
+
+    let root = files
+        .iter()
+        .find(|f| f.prefix.is_none())
+        .cloned()
+        .unwrap_or_else(|| SourceFile {
+            path: input_dir.join(format!(
+                "{}.{}",
+                locale,
+                root_extension(input_dir, base_locale)
+            )),
+            prefix: None,
+        });
+
+    // Longest prefix first so `shop.items` wins over `shop`
+    let mut nested: Vec<&SourceFile> = files.iter().filter(|f| f.prefix.is_some()).collect();
+    nested.sort_by_key(|f| std::cmp::Reverse(f.prefix.as_ref().map(|p| p.len())));
+
+    let mut routed: Vec<(SourceFile, Vec<Translation>)> = Vec::new();
+
+    for translation in translations {
+        let table = match tables
+            .iter()
+            .position(|(_, table, _)| table.contains_key(&translation.key))
+        {
+            Some(index) => Some(index),
+            None if csv_only => Some(0),
+            None => None,
+        };
+
+        if let Some(index) = table {
+            let (_, table, changed) = &mut tables[index];
+            table.set(translation, Some(base_locale));
+            *changed = true;
+            continue;
+        }
+
+        let owner = nested.iter().find(|f| {
+            let prefix = f.prefix.as_deref().unwrap_or_default();
+            translation.key.starts_with(&format!("{}.", prefix))
+        });
+
+        let (file, key) = match owner {
+            Some(file) => {
+                let prefix_len = file.prefix.as_ref().map(|p| p.len() + 1).unwrap_or(0);
+                ((*file).clone(), translation.key[prefix_len..].to_string())
+            }
+            None => (root.clone(), translation.key.clone()),
+        };
+
+        let mut local = translation.clone();
+        local.key = key;
+
+        match routed.iter_mut().find(|(f, _)| f.path == file.path) {
+            Some((_, group)) => group.push(local),
+            None => routed.push((file, vec![local])),
+        }
+    }
+
+    for (file, group) in &routed {
+        write_source_file(&file.path, group)?;
+    }
+
+    for (path, table, changed) in &tables {
+        if *changed {
+            table.write(path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Extension for a new locale's single-file source, following the base locale
+fn root_extension(input_dir: &Path, base_locale: &str) -> String {
+    let files = discover_locale_files(input_dir, base_locale).unwrap_or_default();
+
+    files
+        .iter()
+        .find(|f| f.prefix.is_none())
+        .or_else(|| files.first())
+        .and_then(|f| f.path.extension())
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("json")
+        .to_string()
+}
+
+/// Merge translations into a single file in nested form, keeping its format and style
+///
+/// Descriptions a JSONC/JSON5 comment already gives are left to the comment.
+fn write_source_file(path: &Path, translations: &[Translation]) -> Result<()> {
+    let mut document = SourceDocument::read(path)?;
+    let translations: Vec<Translation> = translations
+        .iter()
+        .map(|translation| {
+            let mut translation = translation.clone();
+            if translation
+                .description
+                .as_deref()
+                .is_some_and(|d| document.has_comment_description(&translation.key, d))
+            {
+                translation.description = None;
+            }
+            translation
+        })
+        .collect();
+    flatten::merge_translations(&mut document.value, &translations);
+    document.write(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_locale_source_patterns() {
+        assert_eq!(
+            locale_source_patterns("id"),
+            "id.json/.jsonc/.json5/.yaml/.yml, id/ and *.csv"
+        );
+    }
+
+    #[test]
+    fn test_discover_single_file() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("en.json"), r#"{"ui": {"buy": "Buy"}}"#).unwrap();
+
+        let files = discover_locale_files(temp.path(), "en").unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].prefix, None);
+    }
+
+    #[test]
+    fn test_discover_prefers_json_over_yaml() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("en.json"), r#"{"a": "A"}"#).unwrap();
+        fs::write(temp.path().join("en.yaml"), "a: B\n").unwrap();
+
+        let files = discover_locale_files(temp.path(), "en").unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert!(files[0].path.ends_with("en.json"));
+    }
+
+    #[test]
+    fn test_discover_jsonc_and_json5() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("en")).unwrap();
+        fs::write(
+            temp.path().join("en.jsonc"),
+            "{\n  // Buy\n  \"a\": \"A\",\n}",
+        )
+        .unwrap();
+        fs::write(temp.path().join("en/shop.json5"), "{buy: 'Buy'}").unwrap();
+
+        let translations =
+            load_locale_translations(temp.path(), "en", "en", ScalarPolicy::default())
+                .unwrap()
+                .unwrap();
+
+        assert_eq!(translations.len(), 2);
+        assert!(translations
+            .iter()
+            .any(|t| t.key == "a" && t.description.as_deref() == Some("Buy")));
+        assert!(translations.iter().any(|t| t.key == "shop.buy"));
+    }
+
+    #[test]
+    fn test_discover_locale_directory() {
+        let temp = TempDir::new().unwrap();
+        let shop_dir = temp.path().join("en/shop");
+        fs::create_dir_all(&shop_dir).unwrap();
+        fs::write(temp.path().join("en/ui.json"), r#"{"buy": "Buy"}"#).unwrap();
+        fs::write(shop_dir.join("items.yaml"), "sword: Sword\n").unwrap();
+        fs::write(shop_dir.join("notes.txt"), "ignored").unwrap();
+
+        let files = discover_locale_files(temp.path(), "en").unwrap();
+
+        let prefixes: Vec<_> = files.iter().map(|f| f.prefix.clone()).collect();
+        assert_eq!(
+            prefixes,
+            vec![Some("shop.items".to_string()), Some("ui".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_load_locale_merges_files_with_prefix() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("en/shop")).unwrap();
+        fs::write(temp.path().join("en.json"), r#"{"common": {"ok": "OK"}}"#).unwrap();
+        fs::write(temp.path().join("en/shop/items.yaml"), "sword: Sword\n").unwrap();
+
+        let translations =
+            load_locale_translations(temp.path(), "en", "en", ScalarPolicy::default())
+                .unwrap()
+                .unwrap();
+
+        assert_eq!(translations.len(), 2);
+        assert!(translations.iter().any(|t| t.key == "common.ok"));
+        assert!(translations
+            .iter()
+            .any(|t| t.key == "shop.items.sword" && t.value == "Sword"));
+    }
+
+    #[test]
+    fn test_load_locale_missing() {
+        let temp = TempDir::new().unwrap();
+        assert!(
+            load_locale_translations(temp.path(), "en", "en", ScalarPolicy::default())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_load_locale_reports_collision_with_both_paths() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("en")).unwrap();
+        fs::write(temp.path().join("en.json"), r#"{"shop": {"buy": "Buy"}}"#).unwrap();
+        fs::write(temp.path().join("en/shop.json"), r#"{"buy": "Purchase"}"#).unwrap();
+
+        let err = load_locale_translations(temp.path(), "en", "en", ScalarPolicy::default())
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("Duplicate translation key 'shop.buy'"));
+        assert!(err.contains("en.json:1:18"));
+        assert!(err.contains("shop.json:1:9"));
+    }
+
+    #[test]
+    fn test_write_routes_keys_to_owning_files() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("en")).unwrap();
+        fs::write(temp.path().join("en/shop.yaml"), "buy: Buy\n").unwrap();
+
+        let translations = vec![
+            Translation {
+                key: "shop.buy".to_string(),
+                value: "Purchase".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+            Translation {
+                key: "ui.title".to_string(),
+                value: "Title".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+        ];
+
+        write_locale_translations(temp.path(), "en", "en", &translations).unwrap();
+
+        let shop = fs::read_to_string(temp.path().join("en/shop.yaml")).unwrap();
+        assert!(shop.contains("buy: Purchase"));
+
+        // The new root file follows the locale's existing YAML format
+        let root = fs::read_to_string(temp.path().join("en.yaml")).unwrap();
+        assert!(root.contains("title: Title"));
+        assert!(!root.contains("Purchase"));
+    }
+
+    #[test]
+    fn test_write_updates_files_in_place() {
+        let temp = TempDir::new().unwrap();
+        fs::write(
+            temp.path().join("en.yaml"),
+            "zeta: Z\nshop:\n    sell: Sell\n    buy: Buy\n",
+        )
+        .unwrap();
+
+        let translation = |key: &str, value: &str, locale: &str| Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+
+        let translations = vec![
+            translation("shop.buy", "Purchase", "en"),
+            translation("shop.cancel", "Cancel", "en"),
+        ];
+        write_locale_translations(temp.path(), "en", "en", &translations).unwrap();
+
+        let en = fs::read_to_string(temp.path().join("en.yaml")).unwrap();
+        assert_eq!(
+            en,
+            "zeta: Z\nshop:\n    sell: Sell\n    buy: Purchase\n    cancel: Cancel\n"
+        );
+
+        // A new locale follows the base locale's format
+        let translations = vec![translation("shop.buy", "Beli", "id")];
+        write_locale_translations(temp.path(), "id", "en", &translations).unwrap();
+
+        assert!(temp.path().join("id.yaml").exists());
+        assert!(!temp.path().join("id.json").exists());
+    }
+
+    #[test]
+    fn test_write_keeps_jsonc_comments() {
+        let temp = TempDir::new().unwrap();
+        let content = "// Shop strings\n{\n  \"shop\": {\n    // Shown on the shop button\n    \"buy\": \"Buy\",\n    \"sell\": \"Sell\" // short\n  }\n}\n";
+        fs::write(temp.path().join("en.jsonc"), content).unwrap();
+
+        // A download carries the comment back as the description
+        let translations = vec![
+            Translation {
+                key: "shop.buy".to_string(),
+                value: "Buy".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: Some("Shown on the shop button".to_string()),
+                max_length: None,
+                location: None,
+            },
+            Translation {
+                key: "shop.sell".to_string(),
+                value: "Sell all".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+        ];
+        write_locale_translations(temp.path(), "en", "en", &translations).unwrap();
+
+        let en = fs::read_to_string(temp.path().join("en.jsonc")).unwrap();
+        assert_eq!(en, content.replace("\"Sell\"", "\"Sell all\""));
+
+        let translations =
+            load_locale_translations(temp.path(), "en", "en", ScalarPolicy::default())
+                .unwrap()
+                .unwrap();
+        let buy = translations.iter().find(|t| t.key == "shop.buy").unwrap();
+        assert_eq!(buy.description.as_deref(), Some("Shown on the shop button"));
+    }
+}
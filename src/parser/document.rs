@@ -0,0 +1,446 @@
+//! Format-preserving translation documents
+//!
+//! Commands that write translations back (`download`, `import`, `sync`) load the
+//! existing file as a [`SourceDocument`], merge their changes into it and save it
+//! again. Key order comes from the file itself, and the indentation, line endings
+//! and trailing newline are detected on read and reused on write, so an update
+//! only touches the lines whose values changed. The comments of JSONC/JSON5 and
+//! YAML files, which hold translator descriptions, are written back where they
+//! were.
+
+use super::locate::{self, CommentMap, Trivia};
+use super::yaml::yaml_to_json;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+/// A nested translation document together with its on-disk style
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceDocument {
+    /// Nested content, in file order
+    pub value: Value,
+    style: Style,
+    /// Comments of a JSONC/JSON5 or YAML document
+    trivia: Trivia,
+    /// `//` comments above keys, which are their descriptions
+    descriptions: CommentMap,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Style {
+    indent: String,
+    crlf: bool,
+    trailing_newline: bool,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            indent: "  ".to_string(),
+            crlf: false,
+            trailing_newline: true,
+        }
+    }
+}
+
+impl SourceDocument {
+    /// Load a document, or start an empty one if the file doesn't exist
+    ///
+    /// `.yaml`/`.yml` files are read as YAML, `.jsonc`/`.json5` as JSON5 and
+    /// anything else as JSON.
+    pub fn read(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self {
+                value: Value::Object(serde_json::Map::new()),
+                style: Style::default(),
+                trivia: Trivia::default(),
+                descriptions: CommentMap::new(),
+            });
+        }
+
+        let content =
+            std::fs::read_to_string(path).context(format!("Failed to read {}", path.display()))?;
+        Self::parse(&content, Format::of(path))
+            .context(format!("Failed to parse {}", path.display()))
+    }
+
+    /// Parse document content, detecting its style
+    fn parse(content: &str, format: Format) -> Result<Self> {
+        let value = if content.trim().is_empty() {
+            Value::Null
+        } else {
+            match format {
+                Format::Json => serde_json::from_str(content)?,
+                Format::Json5 => json5::from_str(content)?,
+                Format::Yaml => yaml_to_json(&serde_yaml::from_str(content)?)?,
+            }
+        };
+
+        // Empty files and `---`-only YAML documents start out as an empty object
+        let value = match value {
+            Value::Null => Value::Object(serde_json::Map::new()),
+            value => value,
+        };
+
+        let default = Style::default();
+        let style = Style {
+            indent: detect_indent(content).unwrap_or(default.indent),
+            crlf: content.contains("\r\n"),
+            trailing_newline: content.is_empty() || content.ends_with('\n'),
+        };
+
+        let (trivia, descriptions) = match format {
+            Format::Json5 => (
+                locate::locate_json_trivia(content),
+                locate::locate_json_comments(content),
+            ),
+            Format::Yaml => (locate::locate_yaml_trivia(content), CommentMap::new()),
+            _ => (Trivia::default(), CommentMap::new()),
+        };
+
+        Ok(Self {
+            value,
+            style,
+            trivia,
+            descriptions,
+        })
+    }
+
+    /// Whether `description` is what the `//` comment above `key` already says
+    ///
+    /// Such descriptions don't need an `@description` entry when writing back.
+    pub fn has_comment_description(&self, key: &str, description: &str) -> bool {
+        self.descriptions.get(key).map(String::as_str) == Some(description)
+    }
+
+    /// Serialize the document in `format` and the detected style
+    ///
+    /// JSON5 documents are written as plain JSON, which is valid JSON5, with
+    /// their comments kept above, after or at the end of the same keys. YAML
+    /// comments are kept the same way.
+    fn render(&self, format: Format) -> Result<String> {
+        let mut content = if format == Format::Yaml {
+            let content = serde_yaml::to_string(&self.value)?;
+            let content = reindent_yaml(&content, self.style.indent.len());
+            write_yaml_comments(&content, &self.trivia)
+        } else if format == Format::Json5 {
+            let mut content = String::new();
+            write_comment_lines(&mut content, self.trivia.leading.get(""), "");
+            write_json5(
+                &mut content,
+                &self.value,
+                "",
+                0,
+                &self.style.indent,
+                &self.trivia,
+            )?;
+            content.push('\n');
+            write_comment_lines(&mut content, Some(&self.trivia.end), "");
+            content
+        } else {
+            let mut buffer = Vec::new();
+            let formatter =
+                serde_json::ser::PrettyFormatter::with_indent(self.style.indent.as_bytes());
+            let mut serializer = serde_json::Serializer::with_formatter(&mut buffer, formatter);
+            self.value.serialize(&mut serializer)?;
+            String::from_utf8(buffer)?
+        };
+
+        let trimmed = content.trim_end_matches('\n').len();
+        content.truncate(trimmed);
+        if self.style.trailing_newline {
+            content.push('\n');
+        }
+        if self.style.crlf {
+            content = content.replace('\n', "\r\n");
+        }
+
+        Ok(content)
+    }
+
+    /// Save the document, creating parent directories as needed
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let content = self
+            .render(Format::of(path))
+            .context("Failed to serialize translations")?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .context(format!("Failed to create directory {}", parent.display()))?;
+        }
+
+        std::fs::write(path, content).context(format!("Failed to write {}", path.display()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Json,
+    Json5,
+    Yaml,
+}
+
+impl Format {
+    fn of(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => Format::Yaml,
+            Some("jsonc") | Some("json5") => Format::Json5,
+            _ => Format::Json,
+        }
+    }
+}
+
+/// An object member or array item: its path, key (members only) and value
+type Entry<'a> = (String, Option<&'a String>, &'a Value);
+
+/// Write `value` as pretty-printed JSON, with the comments of `trivia` for the
+/// keys and items under `path`
+fn write_json5(
+    content: &mut String,
+    value: &Value,
+    path: &str,
+    depth: usize,
+    indent: &str,
+    trivia: &Trivia,
+) -> Result<()> {
+    let (open, close, entries): (char, char, Vec<Entry>) = match value {
+        Value::Object(map) => (
+            '{',
+            '}',
+            map.iter()
+                .map(|(key, value)| (locate::join_path(path, key), Some(key), value))
+                .collect(),
+        ),
+        Value::Array(items) => (
+            '[',
+            ']',
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, value)| (locate::join_path(path, &index.to_string()), None, value))
+                .collect(),
+        ),
+        scalar => {
+            content.push_str(&serde_json::to_string(scalar)?);
+            return Ok(());
+        }
+    };
+
+    let closing = trivia.closing.get(path);
+    if entries.is_empty() && closing.is_none() {
+        content.push(open);
+        content.push(close);
+        return Ok(());
+    }
+
+    let inner = indent.repeat(depth + 1);
+    content.push(open);
+    content.push('\n');
+    for (index, (entry_path, key, value)) in entries.iter().enumerate() {
+        write_comment_lines(content, trivia.leading.get(entry_path), &inner);
+        content.push_str(&inner);
+        if let Some(key) = key {
+            content.push_str(&serde_json::to_string(key)?);
+            content.push_str(": ");
+        }
+        write_json5(content, value, entry_path, depth + 1, indent, trivia)?;
+        if index + 1 < entries.len() {
+            content.push(',');
+        }
+        if let Some(comment) = trivia.trailing.get(entry_path) {
+            content.push(' ');
+            content.push_str(comment);
+        }
+        content.push('\n');
+    }
+    write_comment_lines(content, closing, &inner);
+    content.push_str(&indent.repeat(depth));
+    content.push(close);
+
+    Ok(())
+}
+
+/// Put the comments of `trivia` back into emitted YAML
+///
+/// The emitter writes every scalar on one line or as a block scalar, so a
+/// trailing comment can always follow the line its key is on.
+fn write_yaml_comments(content: &str, trivia: &Trivia) -> String {
+    let mut output = String::with_capacity(content.len());
+    write_comment_lines(&mut output, trivia.leading.get(""), "");
+    for (line, path) in content.lines().zip(locate::locate_yaml_entries(content)) {
+        if let Some(path) = &path {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            write_comment_lines(&mut output, trivia.leading.get(path), indent);
+        }
+        output.push_str(line);
+        if let Some(comment) = path.and_then(|path| trivia.trailing.get(&path)) {
+            output.push(' ');
+            output.push_str(comment);
+        }
+        output.push('\n');
+    }
+    write_comment_lines(&mut output, Some(&trivia.end), "");
+    output
+}
+
+/// Write comment lines at `indent`, leaving blank lines empty
+fn write_comment_lines(content: &mut String, lines: Option<&Vec<String>>, indent: &str) {
+    for line in lines.into_iter().flatten() {
+        if !line.is_empty() {
+            content.push_str(indent);
+            content.push_str(line);
+        }
+        content.push('\n');
+    }
+}
+
+/// The leading whitespace of the first indented line
+fn detect_indent(content: &str) -> Option<String> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .find(|indent| !indent.is_empty())
+        .map(str::to_string)
+}
+
+/// Rescale serde_yaml's two-space indentation to `width` spaces
+///
+/// The body of a block scalar (`|`, `>`) keeps its own relative indentation;
+/// only its base is shifted.
+fn reindent_yaml(content: &str, width: usize) -> String {
+    if width == 2 || width == 0 {
+        return content.to_string();
+    }
+
+    let mut output = String::with_capacity(content.len());
+    // (emitted base indent, rescaled base indent) of the block scalar being copied
+    let mut block: Option<(usize, usize)> = None;
+
+    for line in content.lines() {
+        let spaces = line.len() - line.trim_start_matches(' ').len();
+
+        if let Some((from, to)) = block {
+            if line.trim().is_empty() {
+                output.push('\n');
+                continue;
+            }
+            if spaces >= from {
+                output.push_str(&" ".repeat(to));
+                output.push_str(&line[from..]);
+                output.push('\n');
+                continue;
+            }
+            block = None;
+        }
+
+        let scaled = spaces / 2 * width;
+        output.push_str(&" ".repeat(scaled));
+        output.push_str(&line[spaces..]);
+        output.push('\n');
+
+        let header = line.trim_end();
+        if ["|", "|-", "|+", ">", ">-", ">+"]
+            .iter()
+            .any(|indicator| header.ends_with(&format!(" {}", indicator)))
+        {
+            block = Some((spaces + 2, scaled + width));
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_round_trip_keeps_order_and_style() {
+        let content = "{\n    \"zeta\": \"Z\",\n    \"alpha\": {\n        \"b\": \"B\",\n        \"a\": \"A\"\n    }\n}";
+
+        let document = SourceDocument::parse(content, Format::Json).unwrap();
+
+        assert_eq!(document.style.indent, "    ");
+        assert!(!document.style.trailing_newline);
+        assert_eq!(document.render(Format::Json).unwrap(), content);
+    }
+
+    #[test]
+    fn test_json_detects_tabs_and_crlf() {
+        let content = "{\r\n\t\"ui\": {\r\n\t\t\"buy\": \"Buy\"\r\n\t}\r\n}\r\n";
+
+        let document = SourceDocument::parse(content, Format::Json).unwrap();
+
+        assert_eq!(document.style.indent, "\t");
+        assert!(document.style.crlf);
+        assert_eq!(document.render(Format::Json).unwrap(), content);
+    }
+
+    #[test]
+    fn test_json5_round_trip_keeps_comments() {
+        let content = "// Shop strings\n{\n    // Shown on the shop button\n    \"buy\": \"Buy\", // short\n    \"tips\": [\n        /* first */\n        \"Jump\"\n    ],\n    // Detached\n\n    \"ok\": \"OK\"\n    // closing\n}\n// end\n";
+
+        let mut document = SourceDocument::parse(content, Format::Json5).unwrap();
+        assert_eq!(document.render(Format::Json5).unwrap(), content);
+        assert!(document.has_comment_description("buy", "Shown on the shop button"));
+        assert!(!document.has_comment_description("ok", "Detached"));
+
+        document.value["buy"] = json!("Purchase");
+        document.value.as_object_mut().unwrap().remove("tips");
+        assert_eq!(
+            document.render(Format::Json5).unwrap(),
+            "// Shop strings\n{\n    // Shown on the shop button\n    \"buy\": \"Purchase\", // short\n    // Detached\n\n    \"ok\": \"OK\"\n    // closing\n}\n// end\n"
+        );
+    }
+
+    #[test]
+    fn test_yaml_round_trip_keeps_order_and_indent() {
+        let content = "zeta: Z\nalpha:\n    b: B\n    a: A\n    tips:\n    - Jump\n    - Run\n";
+
+        let document = SourceDocument::parse(content, Format::Yaml).unwrap();
+
+        assert_eq!(document.value["alpha"]["tips"], json!(["Jump", "Run"]));
+        assert_eq!(document.render(Format::Yaml).unwrap(), content);
+    }
+
+    #[test]
+    fn test_yaml_round_trip_keeps_comments() {
+        let content = "# Shop strings\n\nshop:\n    # Shown on the shop button\n    buy: Buy # short\n    tips:\n    # first\n    - Jump\n    intro: |-\n        Hello\n        # there\n# Detached\n\nok: OK\n# end\n";
+
+        let mut document = SourceDocument::parse(content, Format::Yaml).unwrap();
+        assert_eq!(document.render(Format::Yaml).unwrap(), content);
+
+        document.value["shop"]["buy"] = json!("Purchase: now");
+        document.value["shop"]
+            .as_object_mut()
+            .unwrap()
+            .remove("tips");
+        assert_eq!(
+            document.render(Format::Yaml).unwrap(),
+            "# Shop strings\n\nshop:\n    # Shown on the shop button\n    buy: 'Purchase: now' # short\n    intro: |-\n        Hello\n        # there\n# Detached\n\nok: OK\n# end\n"
+        );
+    }
+
+    #[test]
+    fn test_reindent_yaml_block_scalar() {
+        let emitted = "dialog:\n  intro: |-\n    Hello\n      there\n  outro: Bye\n";
+
+        let reindented = reindent_yaml(emitted, 4);
+
+        assert_eq!(
+            reindented,
+            "dialog:\n    intro: |-\n        Hello\n          there\n    outro: Bye\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_document_defaults() {
+        let document = SourceDocument::parse("---\n", Format::Yaml).unwrap();
+
+        assert_eq!(document.value, json!({}));
+        assert_eq!(document.style.indent, "  ");
+    }
+}
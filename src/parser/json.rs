@@ -1,12 +1,24 @@
+use super::locate;
 use super::types::*;
+use crate::config::ScalarPolicy;
 use crate::utils::flatten;
 use crate::utils::validation;
 use anyhow::{bail, Result};
 use serde_json::Value;
 use std::path::Path;
 
-/// Parse a JSON translation file
+/// Parse a JSON translation file (`.jsonc`/`.json5` files may use JSON5 syntax)
+#[allow(dead_code)] // Public API for library users
 pub fn parse_json_file(path: &Path, locale: &str) -> Result<Vec<Translation>> {
+    parse_json_file_with_policy(path, locale, ScalarPolicy::default())
+}
+
+/// Parse a JSON translation file, handling numbers and booleans per `policy`
+pub fn parse_json_file_with_policy(
+    path: &Path,
+    locale: &str,
+    policy: ScalarPolicy,
+) -> Result<Vec<Translation>> {
     // Read file with better error context
     let content = std::fs::read_to_string(path).map_err(|e| {
         anyhow::anyhow!(
@@ -35,54 +47,53 @@ pub fn parse_json_file(path: &Path, locale: &str) -> Result<Vec<Translation>> {
         );
     }
 
-    // Parse JSON with detailed error messages
-    let json: Value = serde_json::from_str(&content).map_err(|e| {
-        let line = e.line();
-        let column = e.column();
+    // JSONC and JSON5 allow comments, trailing commas and other relaxed syntax
+    let relaxed = matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("jsonc") | Some("json5")
+    );
 
-        // Try to extract the problematic line
-        let lines: Vec<&str> = content.lines().collect();
-        let context_line = if line > 0 && line <= lines.len() {
-            lines[line - 1]
-        } else {
-            ""
-        };
+    let json: Value = if relaxed {
+        parse_json5(&content, path)?
+    } else {
+        parse_strict_json(&content, path)?
+    };
+
+    let positions = locate::locate_json_values(&content);
 
+    let flattened = flatten::flatten_entries(&json, String::new(), policy).map_err(|e| {
         anyhow::anyhow!(
-            "Failed to parse JSON in: {}\n\
-             Error at line {}, column {}: {}\n\
-             \n\
-             Problematic line:\n\
-             {}\n\
-             {}^\n\
-             \n\
-             Common JSON errors:\n\
-             - Missing or extra commas\n\
-             - Missing closing brackets or braces\n\
-             - Unquoted keys or values\n\
-             - Trailing commas (not allowed in strict JSON)\n\
-             \n\
-             Hint: Use a JSON validator or linter to check your file.",
-            path.display(),
-            line,
-            column,
-            e,
-            context_line,
-            " ".repeat(column.saturating_sub(1))
+            "Invalid translation entry in: {}\n{}",
+            locate::describe_location(path, &positions, &e.key),
+            e
         )
     })?;
 
-    let flattened = flatten::flatten_json(&json, String::new());
-
     // Check if any translations were found - just return empty vector if none
     if flattened.is_empty() {
         log::warn!("No translations found in: {}", path.display());
         return Ok(Vec::new());
     }
 
+    let comments = if relaxed {
+        locate::locate_json_comments(&content)
+    } else {
+        locate::CommentMap::new()
+    };
+
     let translations = flattened
         .into_iter()
-        .map(|(key, value)| {
+        .map(|(key, entry)| {
+            // Point at the text itself for entries in the extended `@value` form
+            let position = positions
+                .get(&format!("{}.@value", key))
+                .or_else(|| positions.get(&key));
+            let location = position.map(|&(line, column)| SourceLocation {
+                file: path.to_path_buf(),
+                line,
+                column,
+            });
+
             // Validate translation key format
             validation::validate_translation_key(&key).map_err(|e| {
                 anyhow::anyhow!(
@@ -90,16 +101,25 @@ pub fn parse_json_file(path: &Path, locale: &str) -> Result<Vec<Translation>> {
                      {}\n\
                      \n\
                      Hint: Translation keys should use dot notation (e.g., 'ui.button.buy')",
-                    path.display(),
+                    location
+                        .as_ref()
+                        .map(|l| l.to_string())
+                        .unwrap_or_else(|| path.display().to_string()),
                     e
                 )
             })?;
 
+            // A `//` comment above the key is its translator description
+            let description = entry.description.or_else(|| comments.get(&key).cloned());
+
             Ok(Translation {
                 key,
-                value,
+                value: entry.value,
                 locale: locale.to_string(),
-                context: None,
+                context: entry.context,
+                description,
+                max_length: entry.max_length,
+                location,
             })
         })
         .collect::<Result<Vec<_>>>()?;
@@ -107,10 +127,78 @@ pub fn parse_json_file(path: &Path, locale: &str) -> Result<Vec<Translation>> {
     Ok(translations)
 }
 
+/// Parse strict JSON with detailed error messages
+fn parse_strict_json(content: &str, path: &Path) -> Result<Value> {
+    serde_json::from_str(content).map_err(|e| {
+        let line = e.line();
+        let column = e.column();
+
+        anyhow::anyhow!(
+            "Failed to parse JSON in: {}\n\
+             Error at line {}, column {}: {}\n\
+             \n\
+             Problematic line:\n\
+             {}\n\
+             {}^\n\
+             \n\
+             Common JSON errors:\n\
+             - Missing or extra commas\n\
+             - Missing closing brackets or braces\n\
+             - Unquoted keys or values\n\
+             - Trailing commas (not allowed in strict JSON)\n\
+             \n\
+             Hint: Use a JSON validator or linter to check your file.\n\
+             To use comments or trailing commas, rename it to .jsonc or .json5.",
+            path.display(),
+            line,
+            column,
+            e,
+            problematic_line(content, line),
+            " ".repeat(column.saturating_sub(1))
+        )
+    })
+}
+
+/// Parse JSONC/JSON5 with detailed error messages
+fn parse_json5(content: &str, path: &Path) -> Result<Value> {
+    json5::from_str(content).map_err(|e| {
+        let json5::Error::Message { msg, location } = e;
+        let (line, column) = location.map(|l| (l.line, l.column)).unwrap_or_default();
+
+        anyhow::anyhow!(
+            "Failed to parse JSON5 in: {}\n\
+             Error at line {}, column {}: {}\n\
+             \n\
+             Problematic line:\n\
+             {}\n\
+             {}^\n\
+             \n\
+             Hint: Comments (// and /* */), trailing commas, single-quoted strings\n\
+             and unquoted keys are allowed; check for missing commas or brackets.",
+            path.display(),
+            line,
+            column,
+            msg.lines().next().unwrap_or_default(),
+            problematic_line(content, line),
+            " ".repeat(column.saturating_sub(1))
+        )
+    })
+}
+
+/// Extract a line (1-based) of the source for error messages
+fn problematic_line(content: &str, line: usize) -> &str {
+    if line == 0 {
+        return "";
+    }
+    content.lines().nth(line - 1).unwrap_or("")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
 
     #[test]
     fn test_parse_json_file_structure() {
@@ -157,4 +245,121 @@ mod tests {
         assert_eq!(result.get("ui.buttons.buy"), Some(&"Buy".to_string()));
         assert_eq!(result.get("ui.buttons.sell"), Some(&"Sell".to_string()));
     }
+
+    #[test]
+    fn test_parse_json_records_locations() {
+        let content = "{\n  \"ui\": {\n    \"buy\": \"Buy\"\n  },\n  \"title\": \"Shop\"\n}\n";
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
+
+        let translations = parse_json_file(temp_file.path(), "en").unwrap();
+
+        let buy = translations.iter().find(|t| t.key == "ui.buy").unwrap();
+        let location = buy.location.as_ref().unwrap();
+        assert_eq!(location.file, temp_file.path());
+        assert_eq!((location.line, location.column), (3, 12));
+
+        let title = translations.iter().find(|t| t.key == "title").unwrap();
+        let location = title.location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (5, 12));
+    }
+
+    #[test]
+    fn test_parse_jsonc_comments_as_descriptions() {
+        let content = r#"{
+  "shop": {
+    // Label on the purchase button
+    "buy": "Buy",
+    "sell": {"@value": "Sell", "@description": "Explicit wins"}, // ignored
+    // Overridden by @description
+    "cancel": {"@value": "Cancel", "@description": "Explicit"},
+  },
+}
+"#;
+
+        let mut temp_file = tempfile::Builder::new()
+            .suffix(".jsonc")
+            .tempfile()
+            .unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
+
+        let translations = parse_json_file(temp_file.path(), "en").unwrap();
+        let description = |key: &str| {
+            translations
+                .iter()
+                .find(|t| t.key == key)
+                .and_then(|t| t.description.clone())
+        };
+
+        assert_eq!(translations.len(), 3);
+        assert_eq!(
+            description("shop.buy").as_deref(),
+            Some("Label on the purchase button")
+        );
+        assert_eq!(description("shop.sell").as_deref(), Some("Explicit wins"));
+        assert_eq!(description("shop.cancel").as_deref(), Some("Explicit"));
+    }
+
+    #[test]
+    fn test_parse_json5_syntax() {
+        let content = "{ui: {buy: 'Buy', sell: \"Sell\",},}";
+
+        let mut temp_file = tempfile::Builder::new()
+            .suffix(".json5")
+            .tempfile()
+            .unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
+
+        let translations = parse_json_file(temp_file.path(), "en").unwrap();
+
+        assert_eq!(translations.len(), 2);
+        let buy = translations.iter().find(|t| t.key == "ui.buy").unwrap();
+        assert_eq!(buy.value, "Buy");
+        assert_eq!(buy.location.as_ref().map(|l| l.column), Some(12));
+    }
+
+    #[test]
+    fn test_parse_strict_json_rejects_comments() {
+        let content = "{\n  // comment\n  \"buy\": \"Buy\"\n}\n";
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
+
+        let err = parse_json_file(temp_file.path(), "en")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("rename it to .jsonc or .json5"));
+    }
+
+    #[test]
+    fn test_parse_json_null_reports_location() {
+        let content = "{\n  \"ui\": {\n    \"title\": null\n  }\n}\n";
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
+
+        let err = parse_json_file(temp_file.path(), "en")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains(&format!("{}:3:14", temp_file.path().display())));
+        assert!(err.contains("Null value at key 'ui.title'"));
+    }
+
+    #[test]
+    fn test_parse_json_arrays_become_indexed_keys() {
+        let content = r#"{"tips": ["Jump", "Run"], "maxPlayers": 8}"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
+
+        let translations = parse_json_file(temp_file.path(), "en").unwrap();
+
+        assert_eq!(translations.len(), 3);
+        let tip = translations.iter().find(|t| t.key == "tips.1").unwrap();
+        assert_eq!(tip.value, "Run");
+        assert_eq!(tip.location.as_ref().map(|l| l.column), Some(19));
+    }
 }
@@ -0,0 +1,839 @@
+//! Source positions for translation values
+//!
+//! `serde_json` and `serde_yaml` don't expose spans for parsed values, so these
+//! scanners walk the (already validated) source text and record where each value
+//! starts, keyed by the same dot-separated path that `flatten_json` produces.
+//!
+//! The JSON scanner also understands the JSONC/JSON5 extensions (comments, trailing
+//! commas, single-quoted strings and unquoted keys) and collects the `//` comments
+//! written directly above each key, as well as every comment's position, so a
+//! JSONC/JSON5 file can be written back with its comments. The YAML scanner does
+//! the same for `#` comments.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Line and column (both 1-based) of a value, keyed by flattened key path
+pub type PositionMap = HashMap<String, (usize, usize)>;
+
+/// Comment text written above a key, keyed by flattened key path
+pub type CommentMap = HashMap<String, String>;
+
+/// Describe where a key is defined as `file:line:column`, or just the file if unknown
+pub fn describe_location(path: &Path, positions: &PositionMap, key: &str) -> String {
+    match positions.get(key) {
+        Some((line, column)) => format!("{}:{}:{}", path.display(), line, column),
+        None => path.display().to_string(),
+    }
+}
+
+/// Locate every value in a JSON document
+///
+/// Array items are keyed by index (`tips.0`). Malformed input is scanned on a
+/// best-effort basis; positions are simply missing for anything unrecognized.
+pub fn locate_json_values(content: &str) -> PositionMap {
+    scan_json(content).positions
+}
+
+/// Collect the `//` comments directly above each key of a JSONC/JSON5 document
+///
+/// Consecutive comment lines are joined with a space. A blank line between the
+/// comment and the key, or a comment trailing other content on its line, is not
+/// attached to any key. Block comments (`/* */`) are ignored.
+pub fn locate_json_comments(content: &str) -> CommentMap {
+    scan_json(content).comments
+}
+
+/// Comments of a JSONC/JSON5 or YAML document, keyed by the flattened path they
+/// belong to
+///
+/// Lines are kept as written (`// note`, `/* note */`, `# note`), with an empty
+/// line for a blank line between a comment and what follows it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Trivia {
+    /// Comment lines above a key or array item (`""` is the whole document)
+    pub leading: HashMap<String, Vec<String>>,
+    /// Comment after a value on the same line
+    pub trailing: HashMap<String, String>,
+    /// Comment lines before the closing brace or bracket of an object or array
+    pub closing: HashMap<String, Vec<String>>,
+    /// Comment lines after the document
+    pub end: Vec<String>,
+}
+
+/// Collect every comment of a JSONC/JSON5 document with its position
+///
+/// Comments between a key and its value are not kept.
+pub fn locate_json_trivia(content: &str) -> Trivia {
+    let mut scanner = scan_json(content);
+    scanner.skip_whitespace();
+    scanner.take_trailing_as_trivia();
+    scanner.trivia.end = std::mem::take(&mut scanner.pending_trivia);
+    scanner.trivia
+}
+
+fn scan_json(content: &str) -> JsonScanner {
+    let mut scanner = JsonScanner {
+        chars: content.chars().collect(),
+        pos: 0,
+        line: 1,
+        column: 1,
+        positions: HashMap::new(),
+        comments: HashMap::new(),
+        pending_comment: Vec::new(),
+        trivia: Trivia::default(),
+        pending_trivia: Vec::new(),
+        pending_trailing: None,
+    };
+
+    scanner.skip_whitespace();
+    scanner.take_trailing_as_trivia();
+    scanner.take_leading(String::new());
+    scanner.scan_value(String::new());
+    scanner
+}
+
+struct JsonScanner {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+    positions: PositionMap,
+    comments: CommentMap,
+    /// `//` comment lines seen since the last token
+    pending_comment: Vec<String>,
+    trivia: Trivia,
+    /// Comment and blank lines seen since the last token, as written
+    pending_trivia: Vec<String>,
+    /// Comment on the line of the last token
+    pending_trailing: Option<String>,
+}
+
+impl JsonScanner {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_next(&self) -> Option<char> {
+        self.chars.get(self.pos + 1).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += 1;
+        if ch == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(ch)
+    }
+
+    /// Skip whitespace and comments up to the next token
+    fn skip_whitespace(&mut self) {
+        self.pending_comment.clear();
+        self.pending_trivia.clear();
+        self.pending_trailing = None;
+        // Whether only whitespace has been seen on the current line
+        let mut line_start = false;
+
+        loop {
+            match (self.peek(), self.peek_next()) {
+                (Some('\n'), _) => {
+                    self.advance();
+                    if line_start {
+                        // Blank line: detach earlier comments
+                        self.pending_comment.clear();
+                        if self.pending_trivia.last().is_some_and(|l| !l.is_empty()) {
+                            self.pending_trivia.push(String::new());
+                        }
+                    }
+                    line_start = true;
+                }
+                (Some(c), _) if c.is_whitespace() => {
+                    self.advance();
+                }
+                (Some('/'), Some('/')) => {
+                    self.advance();
+                    self.advance();
+                    let mut text = String::new();
+                    while matches!(self.peek(), Some(c) if c != '\n') {
+                        text.extend(self.advance());
+                    }
+                    if line_start {
+                        self.pending_comment.push(text.trim().to_string());
+                    }
+                    self.push_trivia(format!("//{}", text.trim_end()), line_start);
+                    line_start = false;
+                }
+                (Some('/'), Some('*')) => {
+                    let mut text = String::new();
+                    text.extend(self.advance());
+                    text.extend(self.advance());
+                    while self.peek().is_some()
+                        && (self.peek(), self.peek_next()) != (Some('*'), Some('/'))
+                    {
+                        text.extend(self.advance());
+                    }
+                    text.extend(self.advance());
+                    text.extend(self.advance());
+                    self.push_trivia(text, line_start);
+                    line_start = false;
+                }
+                _ => break,
+            }
+        }
+    }
+
+    /// Record a comment: trailing if it's the first thing after the last token
+    /// on its line, a line of its own otherwise
+    fn push_trivia(&mut self, comment: String, line_start: bool) {
+        if !line_start && self.pending_trivia.is_empty() && self.pending_trailing.is_none() {
+            self.pending_trailing = Some(comment);
+        } else {
+            self.pending_trivia.push(comment);
+        }
+    }
+
+    /// Turn a trailing comment with no value to follow into a comment line
+    fn take_trailing_as_trivia(&mut self) {
+        if let Some(comment) = self.pending_trailing.take() {
+            self.pending_trivia.insert(0, comment);
+        }
+    }
+
+    /// Attach a trailing comment to the value at `path`
+    fn take_trailing(&mut self, path: Option<&str>) {
+        match path {
+            Some(path) if !self.trivia.trailing.contains_key(path) => {
+                if let Some(comment) = self.pending_trailing.take() {
+                    self.trivia.trailing.insert(path.to_string(), comment);
+                }
+            }
+            _ => self.take_trailing_as_trivia(),
+        }
+    }
+
+    /// Attach the comment lines seen to the key or item at `path`
+    fn take_leading(&mut self, path: String) {
+        if !self.pending_trivia.is_empty() {
+            let lines = std::mem::take(&mut self.pending_trivia);
+            self.trivia.leading.insert(path, lines);
+        }
+    }
+
+    /// Attach the comment lines seen to the end of the object or array at `path`
+    fn take_closing(&mut self, path: &str) {
+        while self.pending_trivia.last().is_some_and(|l| l.is_empty()) {
+            self.pending_trivia.pop();
+        }
+        if !self.pending_trivia.is_empty() {
+            let lines = std::mem::take(&mut self.pending_trivia);
+            self.trivia.closing.insert(path.to_string(), lines);
+        }
+    }
+
+    fn scan_value(&mut self, path: String) {
+        if !path.is_empty() {
+            self.positions
+                .insert(path.clone(), (self.line, self.column));
+        }
+
+        match self.peek() {
+            Some('{') => self.scan_object(&path),
+            Some('[') => self.scan_array(&path),
+            Some('"') | Some('\'') => {
+                self.scan_string();
+            }
+            Some(_) => {
+                // Number, boolean or null
+                while matches!(self.peek(), Some(c) if !c.is_whitespace() && !",}]/".contains(c)) {
+                    self.advance();
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn scan_object(&mut self, path: &str) {
+        self.advance(); // {
+        let mut previous: Option<String> = None;
+        // Without a comma, the comments after the last value are already pending
+        let mut skip = true;
+
+        loop {
+            if skip {
+                self.skip_whitespace();
+                self.take_trailing(previous.as_deref());
+            }
+            skip = true;
+            let key = match self.peek() {
+                Some('}') => {
+                    self.take_closing(path);
+                    self.advance();
+                    return;
+                }
+                Some('"') | Some('\'') => self.scan_string(),
+                Some(c) if is_identifier_char(c) => self.scan_identifier(),
+                _ => return,
+            };
+
+            let key_path = join_path(path, &key);
+            if !self.pending_comment.is_empty() {
+                self.comments
+                    .insert(key_path.clone(), self.pending_comment.join(" "));
+            }
+            self.take_leading(key_path.clone());
+
+            self.skip_whitespace();
+            if self.peek() != Some(':') {
+                return;
+            }
+            self.advance();
+            self.skip_whitespace();
+
+            self.scan_value(key_path.clone());
+
+            self.skip_whitespace();
+            self.take_trailing(Some(&key_path));
+            previous = Some(key_path);
+            if self.peek() == Some(',') {
+                self.advance();
+            } else {
+                skip = false;
+            }
+        }
+    }
+
+    fn scan_array(&mut self, path: &str) {
+        self.advance(); // [
+        let mut index = 0;
+        let mut previous: Option<String> = None;
+        // Without a comma, the comments after the last value are already pending
+        let mut skip = true;
+
+        loop {
+            if skip {
+                self.skip_whitespace();
+                self.take_trailing(previous.as_deref());
+            }
+            skip = true;
+            match self.peek() {
+                Some(']') => {
+                    self.take_closing(path);
+                    self.advance();
+                    return;
+                }
+                None => return,
+                _ => {}
+            }
+
+            let start = self.pos;
+            let item_path = join_path(path, &index.to_string());
+            self.take_leading(item_path.clone());
+            self.scan_value(item_path.clone());
+            index += 1;
+
+            self.skip_whitespace();
+            self.take_trailing(Some(&item_path));
+            previous = Some(item_path);
+            if self.peek() == Some(',') {
+                self.advance();
+            } else if self.pos == start {
+                return;
+            } else {
+                skip = false;
+            }
+        }
+    }
+
+    /// Scan an unquoted JSON5 object key
+    fn scan_identifier(&mut self) -> String {
+        let mut key = String::new();
+        while matches!(self.peek(), Some(c) if is_identifier_char(c)) {
+            key.extend(self.advance());
+        }
+        key
+    }
+
+    /// Scan a string literal (double- or single-quoted) and return its unescaped content
+    fn scan_string(&mut self) -> String {
+        let mut value = String::new();
+        let quote = self.advance(); // opening quote
+
+        while let Some(ch) = self.advance() {
+            match ch {
+                c if Some(c) == quote => break,
+                '\\' => {
+                    if let Some(escaped) = self.advance() {
+                        match escaped {
+                            'n' => value.push('\n'),
+                            't' => value.push('\t'),
+                            'r' => value.push('\r'),
+                            'b' => value.push('\u{8}'),
+                            'f' => value.push('\u{c}'),
+                            'u' => {
+                                let hex: String = (0..4).filter_map(|_| self.advance()).collect();
+                                if let Some(c) =
+                                    u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32)
+                                {
+                                    value.push(c);
+                                }
+                            }
+                            other => value.push(other),
+                        }
+                    }
+                }
+                _ => value.push(ch),
+            }
+        }
+
+        value
+    }
+}
+
+/// Locate every value in a block-style YAML document
+///
+/// Handles nested mappings, quoted keys, block scalars (`|`, `>`) and sequences
+/// of scalars. Flow collections (`{a: b}`) are treated as a single value.
+pub fn locate_yaml_values(content: &str) -> PositionMap {
+    let mut positions = HashMap::new();
+    for (line_index, line) in scan_yaml(content).into_iter().enumerate() {
+        if let YamlLine::Entry { path, column, .. } = line {
+            positions.insert(path, (line_index + 1, column));
+        }
+    }
+    positions
+}
+
+/// The path of the key or sequence item on each line of a YAML document
+pub fn locate_yaml_entries(content: &str) -> Vec<Option<String>> {
+    scan_yaml(content)
+        .into_iter()
+        .map(|line| match line {
+            YamlLine::Entry { path, .. } => Some(path),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collect every comment of a YAML document with its position
+///
+/// Comment lines belong to the key or item below them; those before the first
+/// key that end in a blank line head the document (`""`). Comments are not
+/// kept inside block scalars, which have no comments.
+pub fn locate_yaml_trivia(content: &str) -> Trivia {
+    let mut trivia = Trivia::default();
+    let mut pending: Vec<String> = Vec::new();
+    let mut first_entry = true;
+
+    for line in scan_yaml(content) {
+        match line {
+            YamlLine::Comment(comment) => pending.push(comment),
+            YamlLine::Blank => {
+                if pending.last().is_some_and(|l| !l.is_empty()) {
+                    pending.push(String::new());
+                }
+            }
+            YamlLine::Entry { path, comment, .. } => {
+                if first_entry {
+                    if let Some(blank) = pending.iter().rposition(String::is_empty) {
+                        let rest = pending.split_off(blank + 1);
+                        trivia
+                            .leading
+                            .insert(String::new(), std::mem::replace(&mut pending, rest));
+                    }
+                    first_entry = false;
+                }
+                if !pending.is_empty() {
+                    trivia
+                        .leading
+                        .insert(path.clone(), std::mem::take(&mut pending));
+                }
+                if let Some(comment) = comment {
+                    trivia.trailing.insert(path, comment);
+                }
+            }
+            YamlLine::Content => {}
+        }
+    }
+
+    while pending.last().is_some_and(|l| l.is_empty()) {
+        pending.pop();
+    }
+    trivia.end = pending;
+    trivia
+}
+
+/// What a line of a YAML document holds
+enum YamlLine {
+    /// A key or sequence item, with the 1-based column of its value (or of the
+    /// key, for nested mappings and sequences) and the comment after it
+    Entry {
+        path: String,
+        column: usize,
+        comment: Option<String>,
+    },
+    /// A `#` comment on its own line
+    Comment(String),
+    Blank,
+    /// Part of a multi-line value, a document marker or anything unrecognized
+    Content,
+}
+
+fn scan_yaml(content: &str) -> Vec<YamlLine> {
+    let mut lines = Vec::new();
+
+    // Open mappings: (indent, key path)
+    let mut stack: Vec<(usize, String)> = Vec::new();
+    // Sequence counters: key path -> next index
+    let mut sequence_index: HashMap<String, usize> = HashMap::new();
+    // Lines indented deeper than this belong to the previous value
+    let mut continuation_indent: Option<usize> = None;
+    // Whether that value is a block scalar, whose lines are never comments
+    let mut block_scalar = false;
+
+    for raw_line in content.lines() {
+        let trimmed = raw_line.trim_start();
+        let indent = raw_line.len() - trimmed.len();
+
+        if trimmed.is_empty() {
+            lines.push(YamlLine::Blank);
+            continue;
+        }
+
+        let continues = continuation_indent.is_some_and(|limit| indent > limit);
+        if trimmed.starts_with('#') && !(continues && block_scalar) {
+            lines.push(YamlLine::Comment(trimmed.trim_end().to_string()));
+            continue;
+        }
+        if continues {
+            lines.push(YamlLine::Content);
+            continue;
+        }
+        continuation_indent = None;
+
+        if trimmed.starts_with("---") || trimmed.starts_with("...") {
+            stack.clear();
+            lines.push(YamlLine::Content);
+            continue;
+        }
+
+        // Sequence items may sit at the same indent as their parent key
+        let is_sequence_item = trimmed == "-" || trimmed.starts_with("- ");
+        while matches!(stack.last(), Some((open_indent, _))
+            if *open_indent > indent || (*open_indent == indent && !is_sequence_item))
+        {
+            stack.pop();
+        }
+        let parent = stack.last().map(|(_, p)| p.clone()).unwrap_or_default();
+
+        // Sequence item: `- value`
+        if is_sequence_item {
+            let counter = sequence_index.entry(parent.clone()).or_insert(0);
+            let path = join_path(&parent, &counter.to_string());
+            *counter += 1;
+
+            let item = trimmed[1..].trim_start();
+            let (value, comment) = split_yaml_comment(item);
+            continuation_indent = Some(indent);
+            block_scalar = value.starts_with(['|', '>']);
+            lines.push(YamlLine::Entry {
+                path,
+                column: char_column(raw_line, raw_line.len() - item.len()),
+                comment,
+            });
+            continue;
+        }
+
+        let (key, rest_offset) = match split_yaml_key(trimmed) {
+            Some(split) => split,
+            None => {
+                lines.push(YamlLine::Content);
+                continue;
+            }
+        };
+
+        let path = join_path(&parent, &key);
+        let rest = &trimmed[rest_offset..];
+        let value = rest.trim_start();
+        let (scalar, comment) = split_yaml_comment(value);
+
+        if scalar.is_empty() {
+            // Nested mapping or sequence follows
+            stack.push((indent, path.clone()));
+            lines.push(YamlLine::Entry {
+                path,
+                column: indent + 1,
+                comment,
+            });
+        } else {
+            let offset = indent + rest_offset + (rest.len() - value.len());
+            continuation_indent = Some(indent);
+            block_scalar = scalar.starts_with(['|', '>']);
+            lines.push(YamlLine::Entry {
+                path,
+                column: char_column(raw_line, offset),
+                comment,
+            });
+        }
+    }
+
+    lines
+}
+
+/// Split the comment off a YAML value: `Buy # short` is `Buy` and `# short`
+fn split_yaml_comment(value: &str) -> (&str, Option<String>) {
+    // A `#` inside a quoted scalar doesn't start a comment
+    let quoted = match value.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let mut chars = value.char_indices().skip(1).peekable();
+            let mut end = value.len();
+            while let Some((i, ch)) = chars.next() {
+                if ch == '\\' && quote == '"' {
+                    chars.next();
+                } else if ch == quote {
+                    // '' is an escaped quote in single-quoted YAML
+                    if quote == '\'' && matches!(chars.peek(), Some((_, '\''))) {
+                        chars.next();
+                        continue;
+                    }
+                    end = i + 1;
+                    break;
+                }
+            }
+            end
+        }
+        _ => 0,
+    };
+
+    let comment = value[quoted..].char_indices().find(|&(i, ch)| {
+        ch == '#' && (quoted + i == 0 || value[..quoted + i].ends_with([' ', '\t']))
+    });
+    match comment {
+        Some((i, _)) => (
+            value[..quoted + i].trim_end(),
+            Some(value[quoted + i..].trim_end().to_string()),
+        ),
+        None => (value.trim_end(), None),
+    }
+}
+
+/// Split a `key: value` line into the unquoted key and the offset after the colon
+fn split_yaml_key(line: &str) -> Option<(String, usize)> {
+    let first = line.chars().next()?;
+
+    if first == '"' || first == '\'' {
+        let mut key = String::new();
+        let mut chars = line.char_indices().skip(1).peekable();
+
+        while let Some((i, ch)) = chars.next() {
+            if ch == first {
+                // '' is an escaped quote in single-quoted YAML
+                if first == '\'' && matches!(chars.peek(), Some((_, '\''))) {
+                    chars.next();
+                    key.push('\'');
+                    continue;
+                }
+                let after = &line[i + 1..];
+                let after_trimmed = after.trim_start();
+                if !after_trimmed.starts_with(':') {
+                    return None;
+                }
+                let colon = i + 1 + (after.len() - after_trimmed.len());
+                return Some((key, colon + 1));
+            }
+            if ch == '\\' && first == '"' {
+                if let Some((_, escaped)) = chars.next() {
+                    key.push(escaped);
+                }
+                continue;
+            }
+            key.push(ch);
+        }
+
+        return None;
+    }
+
+    // Plain key: ends at the first ": " or a trailing ":"
+    let bytes = line.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b':' && (i + 1 == bytes.len() || bytes[i + 1] == b' ' || bytes[i + 1] == b'\t') {
+            return Some((line[..i].trim_end().to_string(), i + 1));
+        }
+        if b == b'#' && i > 0 && bytes[i - 1] == b' ' {
+            return None;
+        }
+    }
+
+    None
+}
+
+/// Characters allowed in an unquoted JSON5 key
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Convert a byte offset within a line to a 1-based character column
+fn char_column(line: &str, byte_offset: usize) -> usize {
+    line[..byte_offset].chars().count() + 1
+}
+
+pub(super) fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate_json_nested() {
+        let content = "{\n  \"ui\": {\n    \"buy\": \"Buy\",\n    \"sell\": \"Sell\"\n  }\n}";
+        let positions = locate_json_values(content);
+
+        assert_eq!(positions.get("ui.buy"), Some(&(3, 12)));
+        assert_eq!(positions.get("ui.sell"), Some(&(4, 13)));
+    }
+
+    #[test]
+    fn test_locate_json_escaped_key_and_array() {
+        let content = r#"{"say \"hi\"": "x", "tips": ["a", "b"]}"#;
+        let positions = locate_json_values(content);
+
+        assert_eq!(positions.get("say \"hi\""), Some(&(1, 16)));
+        assert_eq!(positions.get("tips.0"), Some(&(1, 30)));
+        assert_eq!(positions.get("tips.1"), Some(&(1, 35)));
+    }
+
+    #[test]
+    fn test_locate_json5_syntax() {
+        let content =
+            "{\n  // note\n  ui: {\n    'buy': 'Buy', /* inline */\n    sell: \"Sell\",\n  },\n}\n";
+        let positions = locate_json_values(content);
+
+        assert_eq!(positions.get("ui.buy"), Some(&(4, 12)));
+        assert_eq!(positions.get("ui.sell"), Some(&(5, 11)));
+    }
+
+    #[test]
+    fn test_locate_json_comments() {
+        let content = r#"{
+  // Shown on the shop button,
+  // keep it short
+  "buy": "Buy",
+  "sell": "Sell", // trailing, not a description
+  "cancel": "Cancel",
+  // Detached by the blank line
+
+  "ok": "OK",
+  /* block comments are ignored */
+  "back": "Back"
+}"#;
+        let comments = locate_json_comments(content);
+
+        assert_eq!(
+            comments.get("buy").map(String::as_str),
+            Some("Shown on the shop button, keep it short")
+        );
+        assert!(!comments.contains_key("sell"));
+        assert!(!comments.contains_key("cancel"));
+        assert!(!comments.contains_key("ok"));
+        assert!(!comments.contains_key("back"));
+    }
+
+    #[test]
+    fn test_locate_json_trivia() {
+        let content = r#"// Shop strings
+{
+  // Shown on the shop button
+  "buy": "Buy", // keep it short
+  "tips": [
+    /* first */ "Jump",
+    "Run" // last
+  ],
+  // Detached by the blank line
+
+  "ok": "OK"
+  // closing
+}
+// end
+"#;
+        let trivia = locate_json_trivia(content);
+
+        assert_eq!(trivia.leading[""], vec!["// Shop strings"]);
+        assert_eq!(trivia.leading["buy"], vec!["// Shown on the shop button"]);
+        assert_eq!(trivia.trailing["buy"], "// keep it short");
+        assert_eq!(trivia.leading["tips.0"], vec!["/* first */"]);
+        assert_eq!(trivia.trailing["tips.1"], "// last");
+        assert_eq!(
+            trivia.leading["ok"],
+            vec!["// Detached by the blank line", ""]
+        );
+        assert_eq!(trivia.closing[""], vec!["// closing"]);
+        assert_eq!(trivia.end, vec!["// end"]);
+        assert!(!trivia.trailing.contains_key("tips"));
+    }
+
+    #[test]
+    fn test_locate_yaml_nested() {
+        let content = "ui:\n  buttons:\n    buy: Buy\n    sell: \"Sell\"\n  title: Shop\n";
+        let positions = locate_yaml_values(content);
+
+        assert_eq!(positions.get("ui.buttons.buy"), Some(&(3, 10)));
+        assert_eq!(positions.get("ui.buttons.sell"), Some(&(4, 11)));
+        assert_eq!(positions.get("ui.title"), Some(&(5, 10)));
+    }
+
+    #[test]
+    fn test_locate_yaml_block_scalar_and_comments() {
+        let content =
+            "# header\nintro: |\n  line one\n  key: not a key\nouter:\n  inner: x # note\n";
+        let positions = locate_yaml_values(content);
+
+        assert_eq!(positions.get("intro"), Some(&(2, 8)));
+        assert!(!positions.contains_key("key"));
+        assert_eq!(positions.get("outer.inner"), Some(&(6, 10)));
+    }
+
+    #[test]
+    fn test_locate_yaml_dotted_and_quoted_keys() {
+        let content = "en:\n  ui.buttons.buy: \"Purchase\"\n  'it''s': x\n";
+        let positions = locate_yaml_values(content);
+
+        assert_eq!(positions.get("en.ui.buttons.buy"), Some(&(2, 19)));
+        assert_eq!(positions.get("en.it's"), Some(&(3, 12)));
+    }
+
+    #[test]
+    fn test_locate_yaml_trivia() {
+        let content = "# Shop strings\n\n# Shown on the shop button\nbuy: Buy # short\ntips:\n  # first\n  - \"Jump # high\"\nintro: |\n  # not a comment\n\n# Detached\n\nok: 'It''s # fine' # done\n# end\n";
+        let trivia = locate_yaml_trivia(content);
+
+        assert_eq!(trivia.leading[""], vec!["# Shop strings", ""]);
+        assert_eq!(trivia.leading["buy"], vec!["# Shown on the shop button"]);
+        assert_eq!(trivia.trailing["buy"], "# short");
+        assert_eq!(trivia.leading["tips.0"], vec!["# first"]);
+        assert!(!trivia.trailing.contains_key("tips.0"));
+        assert!(!trivia.leading.contains_key("intro"));
+        assert_eq!(trivia.leading["ok"], vec!["# Detached", ""]);
+        assert_eq!(trivia.trailing["ok"], "# done");
+        assert_eq!(trivia.end, vec!["# end"]);
+    }
+
+    #[test]
+    fn test_locate_yaml_sequence() {
+        let content = "tips:\n  - First\n  - Second\nrules:\n- One\nnext: x\n";
+        let positions = locate_yaml_values(content);
+
+        assert_eq!(positions.get("tips.0"), Some(&(2, 5)));
+        assert_eq!(positions.get("tips.1"), Some(&(3, 5)));
+        assert_eq!(positions.get("rules.0"), Some(&(5, 3)));
+        assert_eq!(positions.get("next"), Some(&(6, 7)));
+    }
+}
@@ -2,12 +2,20 @@
 //!
 //! This module handles parsing translation files in various formats
 //! (JSON, YAML, CSV) and converting them to internal representation.
+//! Source files are located per locale by the [`discovery`] layer.
 
+pub mod csv_table;
+pub mod discovery;
+pub mod document;
 pub mod json;
+pub mod locate;
 pub mod overrides;
 pub mod types;
 pub mod yaml;
 
+pub use csv_table::*;
+pub use discovery::*;
+pub use document::*;
 pub use json::*;
 pub use overrides::*;
 pub use types::*;
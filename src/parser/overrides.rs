@@ -1,9 +1,32 @@
+use super::locate;
 use super::types::*;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use serde_yaml::Value;
 use std::collections::HashMap;
 use std::path::Path;
 
+/// Contents of an override file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Overrides {
+    /// Plain overrides, merged into the translations at build time
+    pub translations: Vec<Translation>,
+    /// Named variants, activated at runtime with `t:setVariant(name)`
+    pub variants: Vec<Variant>,
+}
+
+/// A named set of overrides that the runtime can switch on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    pub name: String,
+    /// First second the variant applies (Unix time, UTC)
+    pub start: Option<i64>,
+    /// Last second the variant applies (Unix time, UTC)
+    pub end: Option<i64>,
+    /// Player segments the variant is limited to (empty = everyone)
+    pub segments: Vec<String>,
+    pub translations: Vec<Translation>,
+}
+
 /// Parse override file (YAML format)
 ///
 /// Format:
@@ -14,10 +37,33 @@ use std::path::Path;
 /// id:
 ///   ui.buttons.buy: "Beli Sekarang!"
 /// ```
+///
+/// Variants in the file are ignored; use [`parse_override_file`] to read them.
+#[allow(dead_code)] // Public API for library users
 pub fn parse_overrides(path: &Path) -> Result<Vec<Translation>> {
+    Ok(parse_override_file(path)?.translations)
+}
+
+/// Parse override file (YAML format), including runtime variants
+///
+/// Format:
+/// ```yaml
+/// en:
+///   ui.buttons.buy: "Purchase Now!"
+///
+/// variants:
+///   halloween:
+///     start: 2025-10-20        # optional, inclusive (UTC)
+///     end: 2025-11-01          # optional, inclusive (UTC)
+///     segments: [vip, groupB]  # optional
+///     translations:
+///       en:
+///         ui.buttons.buy: "Trick or Treat!"
+/// ```
+pub fn parse_override_file(path: &Path) -> Result<Overrides> {
     if !path.exists() {
         // Override file is optional
-        return Ok(Vec::new());
+        return Ok(Overrides::default());
     }
 
     let content = std::fs::read_to_string(path)
@@ -26,29 +72,260 @@ pub fn parse_overrides(path: &Path) -> Result<Vec<Translation>> {
     let yaml: Value = serde_yaml::from_str(&content)
         .context(format!("Failed to parse override YAML: {}", path.display()))?;
 
-    let mut translations = Vec::new();
+    let positions = locate::locate_yaml_values(&content);
+    let mut overrides = Overrides::default();
 
-    // Parse structure: locale -> key -> value
+    // Parse structure: locale -> key -> value, plus the `variants` section
     if let Value::Mapping(locales) = yaml {
         for (locale_key, locale_value) in locales {
-            if let Value::String(locale) = locale_key {
-                if let Value::Mapping(keys) = locale_value {
-                    for (key, value) in keys {
-                        if let (Value::String(k), Value::String(v)) = (key, value) {
-                            translations.push(Translation {
-                                key: k.clone(),
-                                value: v.clone(),
-                                locale: locale.clone(),
-                                context: None,
-                            });
-                        }
+            match locale_key {
+                Value::String(name) if name == "variants" => {
+                    overrides.variants = parse_variants(path, &positions, &locale_value)?;
+                }
+                Value::String(locale) => {
+                    overrides.translations.extend(parse_locale_overrides(
+                        path,
+                        &positions,
+                        &locale,
+                        &locale,
+                        &locale_value,
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(overrides)
+}
+
+/// Parse a `key -> value` map for one locale; `path_prefix` locates its entries
+fn parse_locale_overrides(
+    path: &Path,
+    positions: &locate::PositionMap,
+    locale: &str,
+    path_prefix: &str,
+    value: &Value,
+) -> Vec<Translation> {
+    let mut translations = Vec::new();
+
+    if let Value::Mapping(keys) = value {
+        for (key, value) in keys {
+            if let (Value::String(k), Value::String(v)) = (key, value) {
+                let location =
+                    positions
+                        .get(&format!("{}.{}", path_prefix, k))
+                        .map(|&(line, column)| SourceLocation {
+                            file: path.to_path_buf(),
+                            line,
+                            column,
+                        });
+
+                translations.push(Translation {
+                    key: k.clone(),
+                    value: v.clone(),
+                    locale: locale.to_string(),
+                    context: None,
+                    description: None,
+                    max_length: None,
+                    location,
+                });
+            }
+        }
+    }
+
+    translations
+}
+
+/// Parse the `variants` section: name -> {start, end, segments, translations}
+fn parse_variants(
+    path: &Path,
+    positions: &locate::PositionMap,
+    value: &Value,
+) -> Result<Vec<Variant>> {
+    let Value::Mapping(variants) = value else {
+        bail!(
+            "Invalid 'variants' section in: {}\n\
+             \n\
+             Hint: 'variants' maps variant names to their settings, e.g.\n\
+             variants:\n\
+             \x20 halloween:\n\
+             \x20   translations:\n\
+             \x20     en:\n\
+             \x20       ui.buttons.buy: \"Trick or Treat!\"",
+            path.display()
+        );
+    };
+
+    let mut result = Vec::new();
+
+    for (name, settings) in variants {
+        let name = match name {
+            Value::String(name) if is_variant_name(name) => name.clone(),
+            _ => bail!(
+                "Invalid variant name {:?} in: {}\n\
+                 \n\
+                 Hint: Variant names may only contain letters, digits, '_' and '-'.",
+                name,
+                path.display()
+            ),
+        };
+
+        let Value::Mapping(settings) = settings else {
+            bail!(
+                "Variant '{}' in {} must be a mapping with a 'translations' section",
+                name,
+                path.display()
+            );
+        };
+
+        let mut variant = Variant {
+            name: name.clone(),
+            start: None,
+            end: None,
+            segments: Vec::new(),
+            translations: Vec::new(),
+        };
+
+        for (field, value) in settings {
+            let field = field.as_str().unwrap_or_default();
+            let invalid = |expected: &str| {
+                anyhow::anyhow!(
+                    "Invalid '{}' for variant '{}' in: {}\n\
+                     \n\
+                     Hint: Expected {}.",
+                    field,
+                    name,
+                    path.display(),
+                    expected
+                )
+            };
+
+            match field {
+                "start" | "end" => {
+                    let text = value
+                        .as_str()
+                        .ok_or_else(|| invalid("a date like 2025-10-20 or 2025-10-20T18:00:00Z"))?;
+                    let time = parse_variant_time(text, field == "end")
+                        .ok_or_else(|| invalid("a date like 2025-10-20 or 2025-10-20T18:00:00Z"))?;
+                    if field == "start" {
+                        variant.start = Some(time);
+                    } else {
+                        variant.end = Some(time);
+                    }
+                }
+                "segments" => {
+                    let segments = value
+                        .as_sequence()
+                        .ok_or_else(|| invalid("a list of segment names"))?;
+                    for segment in segments {
+                        let segment = segment
+                            .as_str()
+                            .ok_or_else(|| invalid("a list of segment names"))?;
+                        variant.segments.push(segment.to_string());
                     }
                 }
+                "translations" => {
+                    let locales = value
+                        .as_mapping()
+                        .ok_or_else(|| invalid("a map of locale -> key -> value"))?;
+                    for (locale, keys) in locales {
+                        let locale = locale
+                            .as_str()
+                            .ok_or_else(|| invalid("a map of locale -> key -> value"))?;
+                        variant.translations.extend(parse_locale_overrides(
+                            path,
+                            positions,
+                            locale,
+                            &format!("variants.{}.translations.{}", name, locale),
+                            keys,
+                        ));
+                    }
+                }
+                _ => bail!(
+                    "Unknown field '{}' for variant '{}' in: {}\n\
+                     \n\
+                     Hint: Supported fields are 'start', 'end', 'segments' and 'translations'.",
+                    field,
+                    name,
+                    path.display()
+                ),
+            }
+        }
+
+        if let (Some(start), Some(end)) = (variant.start, variant.end) {
+            if start > end {
+                bail!(
+                    "Variant '{}' in {} ends before it starts",
+                    name,
+                    path.display()
+                );
             }
         }
+
+        result.push(variant);
     }
 
-    Ok(translations)
+    Ok(result)
+}
+
+fn is_variant_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Parse `YYYY-MM-DD` or `YYYY-MM-DDTHH:MM[:SS][Z]` (UTC) to Unix time
+///
+/// A date without a time covers the whole day, so it means midnight for a start
+/// and the last second of the day for an end.
+fn parse_variant_time(text: &str, end_of_day: bool) -> Option<i64> {
+    let (date, time) = match text.split_once(['T', ' ']) {
+        Some((date, time)) => (date, Some(time.trim_end_matches('Z'))),
+        None => (text, None),
+    };
+
+    let mut parts = date.splitn(3, '-').map(|p| p.parse::<i64>().ok());
+    let (year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => return None,
+    };
+    if day < 1 || day > days_in_month {
+        return None;
+    }
+
+    let seconds = match time {
+        Some(time) => {
+            let mut parts = time.split(':').map(|p| p.parse::<i64>().ok());
+            let hour = parts.next()??;
+            let minute = parts.next()??;
+            let second = parts.next().unwrap_or(Some(0))?;
+            if parts.next().is_some() || hour > 23 || minute > 59 || second > 59 {
+                return None;
+            }
+            hour * 3600 + minute * 60 + second
+        }
+        None if end_of_day => 86_399,
+        None => 0,
+    };
+
+    Some(days_from_civil(year, month, day) * 86_400 + seconds)
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
 }
 
 /// Merge overrides with main translations
@@ -121,6 +398,13 @@ id:
         assert!(overrides
             .iter()
             .any(|t| t.locale == "id" && t.key == "ui.buttons.buy" && t.value == "Beli Sekarang!"));
+
+        let id_buy = overrides
+            .iter()
+            .find(|t| t.locale == "id" && t.key == "ui.buttons.buy")
+            .unwrap();
+        let location = id_buy.location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (6, 19));
     }
 
     #[test]
@@ -131,12 +415,18 @@ id:
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.buttons.sell".to_string(),
                 value: "Sell".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -145,6 +435,9 @@ id:
             value: "Purchase Now!".to_string(),
             locale: "en".to_string(),
             context: None,
+            description: None,
+            max_length: None,
+            location: None,
         }];
 
         let merged = merge_translations(main, overrides);
@@ -165,4 +458,103 @@ id:
         let result = parse_overrides(Path::new("nonexistent.yaml")).unwrap();
         assert_eq!(result.len(), 0);
     }
+
+    #[test]
+    fn test_parse_override_variants() {
+        let yaml_content = r#"
+en:
+  ui.buttons.buy: "Purchase Now!"
+variants:
+  halloween:
+    start: 2025-10-20
+    end: "2025-11-01"
+    segments: [vip, groupB]
+    translations:
+      en:
+        ui.buttons.buy: "Trick or Treat!"
+      id:
+        ui.buttons.buy: "Permen atau Jebakan!"
+  spring-sale:
+    translations:
+      en:
+        ui.buttons.sell: "Sell for more!"
+"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+
+        let overrides = parse_override_file(temp_file.path()).unwrap();
+
+        assert_eq!(overrides.translations.len(), 1);
+        assert_eq!(overrides.variants.len(), 2);
+
+        let halloween = &overrides.variants[0];
+        assert_eq!(halloween.name, "halloween");
+        assert_eq!(halloween.start, Some(1_760_918_400));
+        assert_eq!(halloween.end, Some(1_762_041_599));
+        assert_eq!(halloween.segments, vec!["vip", "groupB"]);
+        assert_eq!(halloween.translations.len(), 2);
+
+        let id_buy = halloween
+            .translations
+            .iter()
+            .find(|t| t.locale == "id")
+            .unwrap();
+        assert_eq!(id_buy.value, "Permen atau Jebakan!");
+        let location = id_buy.location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (13, 25));
+
+        let sale = &overrides.variants[1];
+        assert_eq!(sale.start, None);
+        assert!(sale.segments.is_empty());
+
+        // The plain API keeps returning only the build-time overrides
+        assert_eq!(parse_overrides(temp_file.path()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_override_variants_errors() {
+        let parse = |yaml_content: &str| {
+            let mut temp_file = NamedTempFile::new().unwrap();
+            temp_file.write_all(yaml_content.as_bytes()).unwrap();
+            parse_override_file(temp_file.path())
+                .unwrap_err()
+                .to_string()
+        };
+
+        let err = parse("variants:\n  halloween:\n    start: 2025-13-01\n");
+        assert!(err.contains("Invalid 'start' for variant 'halloween'"));
+        assert!(err.ends_with("\n\nHint: Expected a date like 2025-10-20 or 2025-10-20T18:00:00Z."));
+
+        let err = parse("variants:\n  halloween:\n    start: 2025-11-02\n    end: 2025-11-01\n");
+        assert!(err.contains("ends before it starts"));
+
+        let err = parse("variants:\n  halloween:\n    audience: vip\n");
+        assert!(err.contains("Unknown field 'audience'"));
+
+        let err = parse("variants:\n  \"big sale\":\n    translations: {}\n");
+        assert!(err.contains("Invalid variant name"));
+
+        let err = parse("variants: [halloween]\n");
+        assert!(err.contains(
+            "Hint: 'variants' maps variant names to their settings, e.g.\nvariants:\n  halloween:\n    translations:\n"
+        ));
+    }
+
+    #[test]
+    fn test_parse_variant_time() {
+        assert_eq!(parse_variant_time("1970-01-01", false), Some(0));
+        assert_eq!(parse_variant_time("1970-01-01", true), Some(86_399));
+        assert_eq!(
+            parse_variant_time("2024-02-29T18:30:00Z", false),
+            Some(1_709_231_400)
+        );
+        assert_eq!(
+            parse_variant_time("2024-02-29T18:30", true),
+            Some(1_709_231_400)
+        );
+        assert_eq!(parse_variant_time("2023-02-29", false), None);
+        assert_eq!(parse_variant_time("2024-01-01T24:00", false), None);
+        assert_eq!(parse_variant_time("soon", false), None);
+    }
 }
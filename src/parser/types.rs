@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
 
 /// A single translation entry
 #[derive(Debug, Clone, PartialEq)]
@@ -14,6 +16,35 @@ pub struct Translation {
 
     /// Optional context for disambiguation
     pub context: Option<String>,
+
+    /// Optional note for translators (`@description`)
+    pub description: Option<String>,
+
+    /// Optional maximum length hint for translators (`@maxLength`)
+    pub max_length: Option<usize>,
+
+    /// Where the value was defined (None for entries not read from a file)
+    pub location: Option<SourceLocation>,
+}
+
+/// Position of a value in a translation source file
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// Path to the source file
+    pub file: PathBuf,
+
+    /// Line number (1-based)
+    pub line: usize,
+
+    /// Column number (1-based)
+    pub column: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    /// Formats as `file:line:column`, like compiler diagnostics
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.display(), self.line, self.column)
+    }
 }
 
 /// Map of translation keys to values
@@ -31,6 +62,9 @@ mod tests {
             value: "Buy".to_string(),
             locale: "en".to_string(),
             context: None,
+            description: None,
+            max_length: None,
+            location: None,
         };
 
         assert_eq!(translation.key, "ui.button");
@@ -46,6 +80,9 @@ mod tests {
             value: "Close".to_string(),
             locale: "en".to_string(),
             context: Some("button".to_string()),
+            description: None,
+            max_length: None,
+            location: None,
         };
 
         assert_eq!(translation.context, Some("button".to_string()));
@@ -58,6 +95,9 @@ mod tests {
             value: "Test Value".to_string(),
             locale: "en".to_string(),
             context: None,
+            description: None,
+            max_length: None,
+            location: None,
         };
 
         let cloned = translation.clone();
@@ -71,6 +111,9 @@ mod tests {
             value: "value".to_string(),
             locale: "en".to_string(),
             context: None,
+            description: None,
+            max_length: None,
+            location: None,
         };
 
         let t2 = Translation {
@@ -78,11 +121,25 @@ mod tests {
             value: "value".to_string(),
             locale: "en".to_string(),
             context: None,
+            description: None,
+            max_length: None,
+            location: None,
         };
 
         assert_eq!(t1, t2);
     }
 
+    #[test]
+    fn test_source_location_display() {
+        let location = SourceLocation {
+            file: PathBuf::from("translations/id.json"),
+            line: 42,
+            column: 7,
+        };
+
+        assert_eq!(location.to_string(), "translations/id.json:42:7");
+    }
+
     #[test]
     fn test_translation_map() {
         let mut map: TranslationMap = HashMap::new();
@@ -1,4 +1,6 @@
+use super::locate;
 use super::types::*;
+use crate::config::ScalarPolicy;
 use crate::utils::flatten;
 use crate::utils::validation;
 use anyhow::{bail, Result};
@@ -6,7 +8,17 @@ use serde_yaml::Value;
 use std::path::Path;
 
 /// Parse a YAML translation file
+#[allow(dead_code)] // Public API for library users
 pub fn parse_yaml_file(path: &Path, locale: &str) -> Result<Vec<Translation>> {
+    parse_yaml_file_with_policy(path, locale, ScalarPolicy::default())
+}
+
+/// Parse a YAML translation file, handling numbers and booleans per `policy`
+pub fn parse_yaml_file_with_policy(
+    path: &Path,
+    locale: &str,
+    policy: ScalarPolicy,
+) -> Result<Vec<Translation>> {
     // Read file with better error context
     let content = std::fs::read_to_string(path).map_err(|e| {
         anyhow::anyhow!(
@@ -74,9 +86,23 @@ pub fn parse_yaml_file(path: &Path, locale: &str) -> Result<Vec<Translation>> {
         )
     })?;
 
+    // A document with only `---` or comments has no entries
+    if yaml.is_null() {
+        log::warn!("No translations found in: {}", path.display());
+        return Ok(Vec::new());
+    }
+
     // Convert YAML to JSON for flattening
     let json = yaml_to_json(&yaml)?;
-    let flattened = flatten::flatten_json(&json, String::new());
+    let positions = locate::locate_yaml_values(&content);
+
+    let flattened = flatten::flatten_entries(&json, String::new(), policy).map_err(|e| {
+        anyhow::anyhow!(
+            "Invalid translation entry in: {}\n{}",
+            locate::describe_location(path, &positions, &e.key),
+            e
+        )
+    })?;
 
     // Check if any translations were found - just return empty vector if none
     if flattened.is_empty() {
@@ -86,7 +112,17 @@ pub fn parse_yaml_file(path: &Path, locale: &str) -> Result<Vec<Translation>> {
 
     let translations = flattened
         .into_iter()
-        .map(|(key, value)| {
+        .map(|(key, entry)| {
+            // Point at the text itself for entries in the extended `@value` form
+            let position = positions
+                .get(&format!("{}.@value", key))
+                .or_else(|| positions.get(&key));
+            let location = position.map(|&(line, column)| SourceLocation {
+                file: path.to_path_buf(),
+                line,
+                column,
+            });
+
             // Validate translation key format
             validation::validate_translation_key(&key).map_err(|e| {
                 anyhow::anyhow!(
@@ -94,16 +130,22 @@ pub fn parse_yaml_file(path: &Path, locale: &str) -> Result<Vec<Translation>> {
                      {}\n\
                      \n\
                      Hint: Translation keys should use dot notation (e.g., 'ui.button.buy')",
-                    path.display(),
+                    location
+                        .as_ref()
+                        .map(|l| l.to_string())
+                        .unwrap_or_else(|| path.display().to_string()),
                     e
                 )
             })?;
 
             Ok(Translation {
                 key,
-                value,
+                value: entry.value,
                 locale: locale.to_string(),
-                context: None,
+                context: entry.context,
+                description: entry.description,
+                max_length: entry.max_length,
+                location,
             })
         })
         .collect::<Result<Vec<_>>>()?;
@@ -112,7 +154,7 @@ pub fn parse_yaml_file(path: &Path, locale: &str) -> Result<Vec<Translation>> {
 }
 
 /// Convert YAML Value to JSON Value
-fn yaml_to_json(yaml: &Value) -> Result<serde_json::Value> {
+pub(crate) fn yaml_to_json(yaml: &Value) -> Result<serde_json::Value> {
     match yaml {
         Value::Null => Ok(serde_json::Value::Null),
         Value::Bool(b) => Ok(serde_json::Value::Bool(*b)),
@@ -135,9 +177,14 @@ fn yaml_to_json(yaml: &Value) -> Result<serde_json::Value> {
         Value::Mapping(map) => {
             let mut obj = serde_json::Map::new();
             for (k, v) in map {
-                if let Value::String(key) = k {
-                    obj.insert(key.clone(), yaml_to_json(v)?);
-                }
+                // Unquoted keys like `1:` or `true:` are still names
+                let key = match k {
+                    Value::String(key) => key.clone(),
+                    Value::Number(n) => n.to_string(),
+                    Value::Bool(b) => b.to_string(),
+                    _ => bail!("Unsupported YAML key: {:?}", k),
+                };
+                obj.insert(key, yaml_to_json(v)?);
             }
             Ok(serde_json::Value::Object(obj))
         }
@@ -200,6 +247,99 @@ ui:
             .any(|t| t.key == "ui.labels.welcome" && t.value == "Welcome!"));
     }
 
+    #[test]
+    fn test_parse_yaml_records_locations() {
+        let yaml_content = "ui:\n  buttons:\n    buy: Buy\n  title: \"Shop\"\n";
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+
+        let translations = parse_yaml_file(temp_file.path(), "en").unwrap();
+
+        let buy = translations
+            .iter()
+            .find(|t| t.key == "ui.buttons.buy")
+            .unwrap();
+        let location = buy.location.as_ref().unwrap();
+        assert_eq!(location.file, temp_file.path());
+        assert_eq!((location.line, location.column), (3, 10));
+
+        let title = translations.iter().find(|t| t.key == "ui.title").unwrap();
+        let location = title.location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (4, 10));
+    }
+
+    #[test]
+    fn test_parse_yaml_metadata_form() {
+        let yaml_content = r#"ui:
+  buy:
+    "@value": Buy
+    "@context": button
+    "@description": Shop purchase button
+    "@maxLength": 12
+  sell: Sell
+"#;
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+
+        let translations = parse_yaml_file(temp_file.path(), "en").unwrap();
+        assert_eq!(translations.len(), 2);
+
+        let buy = translations.iter().find(|t| t.key == "ui.buy").unwrap();
+        assert_eq!(buy.value, "Buy");
+        assert_eq!(buy.context.as_deref(), Some("button"));
+        assert_eq!(buy.description.as_deref(), Some("Shop purchase button"));
+        assert_eq!(buy.max_length, Some(12));
+
+        // Location points at the text, not the metadata block
+        let location = buy.location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (3, 15));
+
+        let sell = translations.iter().find(|t| t.key == "ui.sell").unwrap();
+        assert_eq!(sell.context, None);
+    }
+
+    #[test]
+    fn test_parse_yaml_lists_and_scalars() {
+        let yaml_content = "game:\n  maxPlayers: 8\n  tips:\n    - Jump\n    - Run\n";
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+
+        let translations = parse_yaml_file(temp_file.path(), "en").unwrap();
+        let value = |key: &str| {
+            translations
+                .iter()
+                .find(|t| t.key == key)
+                .map(|t| t.value.clone())
+        };
+
+        assert_eq!(value("game.maxPlayers").as_deref(), Some("8"));
+        assert_eq!(value("game.tips.0").as_deref(), Some("Jump"));
+        assert_eq!(value("game.tips.1").as_deref(), Some("Run"));
+
+        let err = parse_yaml_file_with_policy(temp_file.path(), "en", ScalarPolicy::Reject)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(":2:15"));
+        assert!(err.contains("Non-string value 8"));
+    }
+
+    #[test]
+    fn test_parse_yaml_null_reports_location() {
+        let yaml_content = "ui:\n  title: Shop\n  subtitle:\n";
+
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(yaml_content.as_bytes()).unwrap();
+
+        let err = parse_yaml_file(temp_file.path(), "en")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(&format!("{}:3:3", temp_file.path().display())));
+        assert!(err.contains("Null value at key 'ui.subtitle'"));
+    }
+
     #[test]
     fn test_yaml_to_json_conversion() {
         let yaml: Value = serde_yaml::from_str(
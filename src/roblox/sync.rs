@@ -3,6 +3,7 @@ use super::merge::{MergeEngine, MergeStrategy};
 use super::types::{DownloadStats, LocalizationEntry, SyncStats, UploadStats};
 use crate::config::Config;
 use crate::parser::{self, Translation};
+use crate::utils::links;
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use std::path::Path;
@@ -179,8 +180,9 @@ impl SyncOrchestrator {
 
         let entries_count = entries.len();
 
-        // Convert to Translation format
-        let translations = self.entries_to_translations(&entries);
+        // Convert to Translation format, keeping the local links that still match
+        let mut translations = self.entries_to_translations(&entries);
+        self.keep_local_links(&mut translations)?;
 
         // Group by locale
         let mut by_locale: HashMap<String, Vec<Translation>> = HashMap::new();
@@ -194,33 +196,23 @@ impl SyncOrchestrator {
         let mut locales_created = 0;
         let mut locales_updated = 0;
 
-        if !dry_run {
-            // Write translation files for each locale
-            for (locale, locale_translations) in &by_locale {
-                let file_path =
-                    Path::new(&self.config.input_directory).join(format!("{}.json", locale));
-
-                let existed = file_path.exists();
-
-                self.write_translation_file(&file_path, locale_translations)?;
+        let input_dir = Path::new(&self.config.input_directory);
 
-                if existed {
-                    locales_updated += 1;
-                } else {
-                    locales_created += 1;
-                }
+        for (locale, locale_translations) in &by_locale {
+            if parser::locale_has_sources(input_dir, locale, &self.config.base_locale) {
+                locales_updated += 1;
+            } else {
+                locales_created += 1;
             }
-        } else {
-            // In dry-run, count what would be created/updated
-            for locale in by_locale.keys() {
-                let file_path =
-                    Path::new(&self.config.input_directory).join(format!("{}.json", locale));
-
-                if file_path.exists() {
-                    locales_updated += 1;
-                } else {
-                    locales_created += 1;
-                }
+
+            // In dry-run, only count what would be created/updated
+            if !dry_run {
+                parser::write_locale_translations(
+                    input_dir,
+                    locale,
+                    &self.config.base_locale,
+                    locale_translations,
+                )?;
             }
         }
 
@@ -311,6 +303,10 @@ impl SyncOrchestrator {
         // Apply strategy
         let merge_result = MergeEngine::apply_strategy(&diff, strategy, &local_map);
 
+        // Per-key metadata (context, description) travels with the text it describes
+        let local_metadata = self.metadata_by_key(&local_translations);
+        let cloud_metadata = self.metadata_by_key(&cloud_translations);
+
         let mut entries_added = 0;
         let mut entries_updated = 0;
         let entries_deleted = 0;
@@ -321,11 +317,17 @@ impl SyncOrchestrator {
                 let upload_translations: Vec<Translation> = merge_result
                     .to_upload
                     .iter()
-                    .map(|(key, locale, value)| Translation {
-                        key: key.clone(),
-                        locale: locale.clone(),
-                        value: value.clone(),
-                        context: None,
+                    .map(|(key, locale, value)| {
+                        let metadata = local_metadata.get(key.as_str());
+                        Translation {
+                            key: key.clone(),
+                            locale: locale.clone(),
+                            value: value.clone(),
+                            context: metadata.and_then(|t| t.context.clone()),
+                            description: metadata.and_then(|t| t.description.clone()),
+                            max_length: metadata.and_then(|t| t.max_length),
+                            location: None,
+                        }
                     })
                     .collect();
 
@@ -350,11 +352,17 @@ impl SyncOrchestrator {
                 let download_translations: Vec<Translation> = merge_result
                     .to_download
                     .iter()
-                    .map(|(key, locale, value)| Translation {
-                        key: key.clone(),
-                        locale: locale.clone(),
-                        value: value.clone(),
-                        context: None,
+                    .map(|(key, locale, value)| {
+                        let metadata = cloud_metadata.get(key.as_str());
+                        Translation {
+                            key: key.clone(),
+                            locale: locale.clone(),
+                            value: value.clone(),
+                            context: metadata.and_then(|t| t.context.clone()),
+                            description: metadata.and_then(|t| t.description.clone()),
+                            max_length: metadata.and_then(|t| t.max_length),
+                            location: None,
+                        }
                     })
                     .collect();
 
@@ -367,11 +375,14 @@ impl SyncOrchestrator {
                         .push(translation);
                 }
 
+                let input_dir = Path::new(&self.config.input_directory);
                 for (locale, locale_translations) in &by_locale {
-                    let file_path =
-                        Path::new(&self.config.input_directory).join(format!("{}.json", locale));
-
-                    self.write_translation_file(&file_path, locale_translations)?;
+                    parser::write_locale_translations(
+                        input_dir,
+                        locale,
+                        &self.config.base_locale,
+                        locale_translations,
+                    )?;
                 }
 
                 entries_updated = merge_result.to_download.len();
@@ -400,22 +411,71 @@ impl SyncOrchestrator {
 
     // Helper methods
 
-    /// Read all local translation files
+    /// Read all local translation files, with their `@:key` links resolved as
+    /// they are built
     fn read_local_translations(&self) -> Result<Vec<Translation>> {
-        let mut all_translations = Vec::new();
+        links::resolve_links(
+            &self.read_source_translations()?,
+            &self.config.base_locale,
+            &self.config.fallbacks,
+        )
+    }
 
-        for locale in &self.config.supported_locales {
-            let file_path =
-                Path::new(&self.config.input_directory).join(format!("{}.json", locale));
+    /// Keep the local value of downloaded translations whose value is what the
+    /// local value's links resolve to, so downloads don't flatten `@:key` links
+    fn keep_local_links(&self, translations: &mut [Translation]) -> Result<()> {
+        let source = self.read_source_translations()?;
+        if !source.iter().any(|t| links::has_links(&t.value)) {
+            return Ok(());
+        }
+        // Broken links are left to the build to report
+        let Ok(resolved) =
+            links::resolve_links(&source, &self.config.base_locale, &self.config.fallbacks)
+        else {
+            return Ok(());
+        };
+
+        let linked: HashMap<(&str, &str), (&str, &str)> = source
+            .iter()
+            .zip(&resolved)
+            .filter(|(raw, _)| links::has_links(&raw.value))
+            .map(|(raw, resolved)| {
+                (
+                    (raw.locale.as_str(), raw.key.as_str()),
+                    (raw.value.as_str(), resolved.value.as_str()),
+                )
+            })
+            .collect();
 
-            if !file_path.exists() {
-                continue;
+        for translation in translations {
+            if let Some((raw, resolved)) =
+                linked.get(&(translation.locale.as_str(), translation.key.as_str()))
+            {
+                if translation.value == *resolved {
+                    translation.value = raw.to_string();
+                }
             }
+        }
+
+        Ok(())
+    }
 
-            let translations = parser::parse_json_file(&file_path, locale)
-                .context(format!("Failed to parse {}", file_path.display()))?;
+    /// Read all local translation files as written
+    fn read_source_translations(&self) -> Result<Vec<Translation>> {
+        let mut all_translations = Vec::new();
+        let input_dir = Path::new(&self.config.input_directory);
 
-            all_translations.extend(translations);
+        for locale in &self.config.supported_locales {
+            if let Some(translations) = parser::load_locale_translations(
+                input_dir,
+                locale,
+                &self.config.base_locale,
+                self.config.scalar_values,
+            )
+            .context(format!("Failed to read translations for {}", locale))?
+            {
+                all_translations.extend(translations);
+            }
         }
 
         Ok(all_translations)
@@ -444,14 +504,17 @@ impl SyncOrchestrator {
                     .map(|t| t.value.clone())
                     .unwrap_or_else(|| translations[0].value.clone());
 
+                let metadata = self.metadata_by_key(translations.iter().copied());
+                let metadata = metadata.get(key.as_str());
+
                 LocalizationEntry {
                     identifier: Identifier {
                         key: key.clone(),
-                        context: translations[0].context.clone(),
+                        context: metadata.and_then(|t| t.context.clone()),
                         source,
                     },
                     metadata: Some(EntryMetadata {
-                        example: None,
+                        example: metadata.and_then(|t| t.description.clone()),
                         entry_type: Some("manual".to_string()),
                     }),
                     translations: translations
@@ -478,6 +541,9 @@ impl SyncOrchestrator {
                 locale: self.config.base_locale.clone(),
                 value: entry.identifier.source.clone(),
                 context: entry.identifier.context.clone(),
+                description: entry.metadata.as_ref().and_then(|m| m.example.clone()),
+                max_length: None,
+                location: None,
             });
 
             // Add all other translations from API response
@@ -487,6 +553,9 @@ impl SyncOrchestrator {
                     locale: api_translation.locale.clone(),
                     value: api_translation.translation_text.clone(),
                     context: entry.identifier.context.clone(),
+                    description: entry.metadata.as_ref().and_then(|m| m.example.clone()),
+                    max_length: None,
+                    location: None,
                 });
             }
         }
@@ -494,6 +563,36 @@ impl SyncOrchestrator {
         translations
     }
 
+    /// Pick the translation that carries each key's metadata
+    ///
+    /// Metadata is normally written in the base locale file, so base locale
+    /// entries win; otherwise the first entry with any metadata is used.
+    fn metadata_by_key<'a>(
+        &self,
+        translations: impl IntoIterator<Item = &'a Translation>,
+    ) -> HashMap<&'a str, &'a Translation> {
+        let mut by_key: HashMap<&str, &Translation> = HashMap::new();
+
+        for translation in translations {
+            let has_metadata = translation.context.is_some()
+                || translation.description.is_some()
+                || translation.max_length.is_some();
+            if !has_metadata {
+                continue;
+            }
+
+            let is_base = translation.locale == self.config.base_locale;
+            match by_key.get(translation.key.as_str()) {
+                Some(existing) if existing.locale == self.config.base_locale || !is_base => {}
+                _ => {
+                    by_key.insert(&translation.key, translation);
+                }
+            }
+        }
+
+        by_key
+    }
+
     /// Convert translations to HashMap for merge engine
     fn translations_to_map(
         &self,
@@ -505,23 +604,6 @@ impl SyncOrchestrator {
             .collect()
     }
 
-    /// Write translation file in nested JSON format
-    fn write_translation_file(&self, path: &Path, translations: &[Translation]) -> Result<()> {
-        use crate::utils::flatten::unflatten_translations;
-        use std::fs;
-
-        // Convert flat translations to nested structure
-        let nested = unflatten_translations(translations);
-
-        // Write to file
-        let json =
-            serde_json::to_string_pretty(&nested).context("Failed to serialize translations")?;
-
-        fs::write(path, json).context(format!("Failed to write {}", path.display()))?;
-
-        Ok(())
-    }
-
     /// Write conflicts to YAML file
     fn write_conflicts_file(&self, conflicts: &[super::merge::Conflict]) -> Result<()> {
         use std::fs;
@@ -581,12 +663,18 @@ mod tests {
                 locale: "en".to_string(),
                 value: "Buy".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 locale: "id".to_string(),
                 value: "Beli".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -615,12 +703,18 @@ mod tests {
                 locale: "en".to_string(),
                 value: "Buy".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 locale: "es".to_string(),
                 value: "Comprar".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -643,6 +737,9 @@ mod tests {
             locale: "en".to_string(),
             value: "Buy".to_string(),
             context: Some("shop".to_string()),
+            description: None,
+            max_length: None,
+            location: None,
         }];
 
         let entries = orchestrator.translations_to_entries(&translations);
@@ -651,6 +748,43 @@ mod tests {
         assert_eq!(entries[0].identifier.context, Some("shop".to_string()));
     }
 
+    #[test]
+    fn test_translations_to_entries_uses_base_locale_metadata() {
+        let client = RobloxCloudClient::new("test_key".to_string()).unwrap();
+        let config = Config::default();
+        let orchestrator = SyncOrchestrator::new(client, config);
+
+        let translations = vec![
+            Translation {
+                key: "ui.button".to_string(),
+                locale: "id".to_string(),
+                value: "Beli".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: None,
+            },
+            Translation {
+                key: "ui.button".to_string(),
+                locale: "en".to_string(),
+                value: "Buy".to_string(),
+                context: Some("button".to_string()),
+                description: Some("Shop purchase button".to_string()),
+                max_length: Some(12),
+                location: None,
+            },
+        ];
+
+        let entries = orchestrator.translations_to_entries(&translations);
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].identifier.context, Some("button".to_string()));
+        assert_eq!(
+            entries[0].metadata.as_ref().unwrap().example,
+            Some("Shop purchase button".to_string())
+        );
+    }
+
     #[test]
     fn test_entries_to_translations() {
         let client = RobloxCloudClient::new("test_key".to_string()).unwrap();
@@ -786,18 +920,27 @@ mod tests {
                 locale: "en".to_string(),
                 value: "Buy".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.title".to_string(),
                 locale: "en".to_string(),
                 value: "Shop".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 locale: "es".to_string(),
                 value: "Comprar".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -22,7 +22,6 @@ pub enum CloudSyncError {
     NetworkError(String),
 
     #[error("Validation failed in {file}:{line} - {reason}")]
-    #[allow(dead_code)]
     ValidationError {
         file: String,
         line: usize,
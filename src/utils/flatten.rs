@@ -1,63 +1,263 @@
+use crate::config::ScalarPolicy;
+use anyhow::{bail, Result};
 use std::collections::HashMap;
+use thiserror::Error;
+
+/// Keys allowed in the extended leaf form (`{"@value": "Buy", "@context": "button"}`)
+pub const METADATA_KEYS: [&str; 4] = ["@value", "@context", "@description", "@maxLength"];
+
+/// A flattened leaf value together with its optional metadata
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FlatEntry {
+    pub value: String,
+    pub context: Option<String>,
+    pub description: Option<String>,
+    pub max_length: Option<usize>,
+}
+
+/// An invalid entry found while flattening, with the key it was found at
+#[derive(Debug, Error)]
+#[error("{message}")]
+pub struct EntryError {
+    /// Flattened key of the offending value
+    pub key: String,
+
+    /// What is wrong with it
+    pub message: String,
+}
+
+impl EntryError {
+    fn new(key: &str, message: impl Into<String>) -> Self {
+        EntryError {
+            key: key.to_string(),
+            message: message.into(),
+        }
+    }
+}
 
 /// Flatten a nested JSON structure to dot notation
 /// Used for converting nested translations to flat keys
+///
+/// Arrays become indexed keys (`tips.0`) and numbers/booleans are stringified.
+/// Nulls can't be represented and are skipped with a warning.
+#[allow(dead_code)] // Public API for library users
 pub fn flatten_json(value: &serde_json::Value, prefix: String) -> HashMap<String, String> {
     let mut result = HashMap::new();
 
     match value {
+        serde_json::Value::Object(map) if is_metadata_leaf(map) => match map.get("@value") {
+            Some(serde_json::Value::String(s)) => {
+                result.insert(prefix, s.clone());
+            }
+            _ => {
+                log::warn!(
+                    "Skipping metadata entry without string '@value' at key: {}",
+                    prefix
+                );
+            }
+        },
         serde_json::Value::Object(map) => {
             for (key, val) in map {
-                let new_prefix = if prefix.is_empty() {
-                    key.clone()
-                } else {
-                    format!("{}.{}", prefix, key)
-                };
-                result.extend(flatten_json(val, new_prefix));
+                result.extend(flatten_json(val, join_key(&prefix, key)));
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                result.extend(flatten_json(item, join_key(&prefix, &index.to_string())));
             }
         }
         serde_json::Value::String(s) => {
             result.insert(prefix, s.clone());
         }
-        _ => {
-            log::warn!("Skipping non-string value at key: {}", prefix);
+        serde_json::Value::Number(n) => {
+            result.insert(prefix, n.to_string());
+        }
+        serde_json::Value::Bool(b) => {
+            result.insert(prefix, b.to_string());
+        }
+        serde_json::Value::Null => {
+            log::warn!("Skipping null value at key: {}", prefix);
         }
     }
 
     result
 }
 
+/// Flatten a nested JSON structure to dot notation, keeping per-key metadata
+///
+/// Leaves may be plain strings or objects in the extended form
+/// `{"@value": "...", "@context": "...", "@description": "...", "@maxLength": 12}`.
+/// Arrays of leaves become indexed keys (`tips.0`, `tips.1`). Numbers and booleans
+/// are handled according to `policy`, and nulls are always an error.
+pub fn flatten_entries(
+    value: &serde_json::Value,
+    prefix: String,
+    policy: ScalarPolicy,
+) -> Result<HashMap<String, FlatEntry>, EntryError> {
+    let mut result = HashMap::new();
+
+    match value {
+        serde_json::Value::Object(map) if is_metadata_leaf(map) => {
+            let entry = parse_metadata_leaf(map, &prefix)
+                .map_err(|e| EntryError::new(&prefix, e.to_string()))?;
+            result.insert(prefix, entry);
+        }
+        serde_json::Value::Object(map) => {
+            for (key, val) in map {
+                result.extend(flatten_entries(val, join_key(&prefix, key), policy)?);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                let key = join_key(&prefix, &index.to_string());
+
+                let nested = match item {
+                    serde_json::Value::Object(map) => !is_metadata_leaf(map),
+                    serde_json::Value::Array(_) => true,
+                    _ => false,
+                };
+                if nested {
+                    return Err(EntryError::new(
+                        &key,
+                        format!(
+                            "List '{}' can only contain text, found a nested object or list\n\
+                             \n\
+                             Hint: Move structured entries into an object with named keys",
+                            prefix
+                        ),
+                    ));
+                }
+
+                result.extend(flatten_entries(item, key, policy)?);
+            }
+        }
+        serde_json::Value::String(s) => {
+            result.insert(
+                prefix,
+                FlatEntry {
+                    value: s.clone(),
+                    ..Default::default()
+                },
+            );
+        }
+        serde_json::Value::Number(_) | serde_json::Value::Bool(_) => match policy {
+            ScalarPolicy::Stringify => {
+                result.insert(
+                    prefix,
+                    FlatEntry {
+                        value: value.to_string(),
+                        ..Default::default()
+                    },
+                );
+            }
+            ScalarPolicy::Reject => {
+                return Err(EntryError::new(
+                    &prefix,
+                    format!(
+                        "Non-string value {} at key '{}'\n\
+                         \n\
+                         Hint: Quote the value (\"{}\") or set 'scalar_values: stringify' in the config",
+                        value, prefix, value
+                    ),
+                ));
+            }
+        },
+        serde_json::Value::Null => {
+            return Err(EntryError::new(
+                &prefix,
+                format!(
+                    "Null value at key '{}'\n\
+                     \n\
+                     Hint: Use an empty string (\"\") or remove the key",
+                    prefix
+                ),
+            ));
+        }
+    }
+
+    Ok(result)
+}
+
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+/// Whether an object is a leaf in the extended form rather than a namespace
+fn is_metadata_leaf(map: &serde_json::Map<String, serde_json::Value>) -> bool {
+    map.keys().any(|k| k.starts_with('@'))
+}
+
+fn parse_metadata_leaf(
+    map: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<FlatEntry> {
+    for field in map.keys() {
+        if !METADATA_KEYS.contains(&field.as_str()) {
+            bail!(
+                "Unknown field '{}' in translation '{}'\n\
+                 \n\
+                 Hint: Supported fields are {}",
+                field,
+                key,
+                METADATA_KEYS.join(", ")
+            );
+        }
+    }
+
+    let text = |field: &str| -> Result<Option<String>> {
+        match map.get(field) {
+            None => Ok(None),
+            Some(serde_json::Value::String(s)) => Ok(Some(s.clone())),
+            Some(_) => bail!("'{}' of translation '{}' must be a string", field, key),
+        }
+    };
+
+    let value = match text("@value")? {
+        Some(value) => value,
+        None => bail!(
+            "Translation '{}' has metadata but no '@value'\n\
+             \n\
+             Hint: Add the translated text, e.g. {{\"@value\": \"Buy\", \"@context\": \"button\"}}",
+            key
+        ),
+    };
+
+    let max_length = match map.get("@maxLength") {
+        None => None,
+        Some(limit) => match limit.as_u64() {
+            Some(n) if n > 0 => Some(n as usize),
+            _ => bail!(
+                "'@maxLength' of translation '{}' must be a positive integer, got {}",
+                key,
+                limit
+            ),
+        },
+    };
+
+    Ok(FlatEntry {
+        value,
+        context: text("@context")?,
+        description: text("@description")?,
+        max_length,
+    })
+}
+
 /// Unflatten dot notation keys back to nested JSON structure
 /// Used for converting flat CSV keys back to nested format
+///
+/// Keys are inserted in sorted order so the output is the same on every run.
 pub fn unflatten_to_json(flat: &HashMap<String, String>) -> serde_json::Value {
     let mut root = serde_json::Map::new();
 
-    for (key, value) in flat {
-        let parts: Vec<&str> = key.split('.').collect();
-
-        // Navigate to the correct nested position
-        let mut current = &mut root;
-        for (i, part) in parts.iter().enumerate() {
-            if i == parts.len() - 1 {
-                // Last part - insert the value
-                current.insert(part.to_string(), serde_json::Value::String(value.clone()));
-            } else {
-                // Intermediate part - ensure nested object exists
-                let part_string = part.to_string();
-                if !current.contains_key(&part_string) {
-                    current.insert(
-                        part_string.clone(),
-                        serde_json::Value::Object(serde_json::Map::new()),
-                    );
-                }
+    let mut keys: Vec<&String> = flat.keys().collect();
+    keys.sort_by(|a, b| compare_keys(a, b));
 
-                // Move to the nested object
-                current = current
-                    .get_mut(&part_string)
-                    .and_then(|v| v.as_object_mut())
-                    .expect("Expected object");
-            }
-        }
+    for key in keys {
+        insert_nested(&mut root, key, serde_json::Value::String(flat[key].clone()));
     }
 
     serde_json::Value::Object(root)
@@ -65,14 +265,215 @@ pub fn unflatten_to_json(flat: &HashMap<String, String>) -> serde_json::Value {
 
 /// Unflatten translations to nested JSON structure
 /// Used for writing translation files
+///
+/// Entries carrying context, description or max length are written in the
+/// extended `@value` form so the metadata survives a round trip.
+#[allow(dead_code)] // Public API for library users
 pub fn unflatten_translations(translations: &[crate::parser::Translation]) -> serde_json::Value {
-    let mut flat = HashMap::new();
+    let mut root = serde_json::Value::Object(serde_json::Map::new());
+    merge_translations(&mut root, translations);
+    root
+}
+
+/// Merge translations into an existing nested document
+///
+/// Keys already in the document are updated where they are, so the file's key
+/// order survives. New keys are inserted after the last sibling sharing the
+/// longest prefix with them (`buyAll` after `buy`), at their sorted position in
+/// a sorted object, or at the end. Keys ending in a list index (`tips.0`) are
+/// written as array items. Metadata on an existing `@value` entry is only
+/// overwritten by fields the translation actually carries.
+pub fn merge_translations(
+    root: &mut serde_json::Value,
+    translations: &[crate::parser::Translation],
+) {
+    let mut sorted: Vec<&crate::parser::Translation> = translations.iter().collect();
+    sorted.sort_by(|a, b| compare_keys(&a.key, &b.key));
+
+    for translation in sorted {
+        let parts: Vec<&str> = translation.key.split('.').collect();
+
+        let mut current = &mut *root;
+        for (i, part) in parts.iter().enumerate() {
+            let placeholder = match parts.get(i + 1) {
+                Some(&"0") => serde_json::Value::Array(Vec::new()),
+                Some(_) => serde_json::Value::Object(serde_json::Map::new()),
+                None => serde_json::Value::Null,
+            };
+            current = child_mut(current, part, placeholder);
+        }
+
+        update_leaf(current, translation);
+    }
+}
+
+/// Get the child at `segment`, inserting `placeholder` if it doesn't exist
+///
+/// Arrays are indexed by number and can grow by one item at a time; any other
+/// segment turns the node into an object.
+fn child_mut<'a>(
+    node: &'a mut serde_json::Value,
+    segment: &str,
+    placeholder: serde_json::Value,
+) -> &'a mut serde_json::Value {
+    let index = match node {
+        serde_json::Value::Array(items) => segment
+            .parse::<usize>()
+            .ok()
+            .filter(|&index| index <= items.len()),
+        _ => None,
+    };
+
+    if index.is_none() && !node.is_object() {
+        let map = match node.take() {
+            serde_json::Value::Array(items) => items
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| (i.to_string(), item))
+                .collect(),
+            _ => serde_json::Map::new(),
+        };
+        *node = serde_json::Value::Object(map);
+    }
+
+    match (node, index) {
+        (serde_json::Value::Array(items), Some(index)) => {
+            if index == items.len() {
+                items.push(placeholder);
+            }
+            &mut items[index]
+        }
+        (serde_json::Value::Object(map), _) => {
+            if !map.contains_key(segment) {
+                let index = sibling_position(map, segment);
+                map.shift_insert(index, segment.to_string(), placeholder);
+            }
+            map.get_mut(segment).expect("key was just inserted")
+        }
+        _ => unreachable!("node was converted to an object"),
+    }
+}
 
-    for translation in translations {
-        flat.insert(translation.key.clone(), translation.value.clone());
+/// Index a new key is inserted at among the keys of `map`
+///
+/// After the last key sharing the longest prefix with it, or at its sorted
+/// position if the keys are sorted, or at the end.
+fn sibling_position(map: &serde_json::Map<String, serde_json::Value>, key: &str) -> usize {
+    let keys: Vec<&String> = map.keys().collect();
+    let shared = |other: &str| {
+        other
+            .chars()
+            .zip(key.chars())
+            .take_while(|(a, b)| a == b)
+            .count()
+    };
+
+    let longest = keys.iter().map(|k| shared(k)).max().unwrap_or(0);
+    if longest > 0 {
+        if let Some(last) = keys.iter().rposition(|k| shared(k) == longest) {
+            return last + 1;
+        }
+    }
+
+    if keys
+        .windows(2)
+        .all(|pair| compare_keys(pair[0], pair[1]).is_le())
+    {
+        return keys
+            .iter()
+            .position(|k| compare_keys(k, key).is_gt())
+            .unwrap_or(keys.len());
     }
 
-    unflatten_to_json(&flat)
+    keys.len()
+}
+
+/// Write a translation into its leaf, keeping an existing extended form
+fn update_leaf(leaf: &mut serde_json::Value, translation: &crate::parser::Translation) {
+    let map = match leaf {
+        serde_json::Value::Object(map) if is_metadata_leaf(map) => map,
+        _ => {
+            *leaf = translation_to_json(translation);
+            return;
+        }
+    };
+
+    map.insert("@value".to_string(), translation.value.clone().into());
+    if let Some(context) = &translation.context {
+        map.insert("@context".to_string(), context.clone().into());
+    }
+    if let Some(description) = &translation.description {
+        map.insert("@description".to_string(), description.clone().into());
+    }
+    if let Some(max_length) = translation.max_length {
+        map.insert("@maxLength".to_string(), max_length.into());
+    }
+}
+
+/// Order keys segment by segment, with list indices in numeric order
+fn compare_keys(a: &str, b: &str) -> std::cmp::Ordering {
+    fn order(segment: &str) -> (usize, &str) {
+        (segment.parse().unwrap_or(usize::MAX), segment)
+    }
+    a.split('.').map(order).cmp(b.split('.').map(order))
+}
+
+/// Convert a translation to its leaf value (plain string or extended form)
+fn translation_to_json(translation: &crate::parser::Translation) -> serde_json::Value {
+    if translation.context.is_none()
+        && translation.description.is_none()
+        && translation.max_length.is_none()
+    {
+        return serde_json::Value::String(translation.value.clone());
+    }
+
+    let mut leaf = serde_json::Map::new();
+    leaf.insert("@value".to_string(), translation.value.clone().into());
+    if let Some(context) = &translation.context {
+        leaf.insert("@context".to_string(), context.clone().into());
+    }
+    if let Some(description) = &translation.description {
+        leaf.insert("@description".to_string(), description.clone().into());
+    }
+    if let Some(max_length) = translation.max_length {
+        leaf.insert("@maxLength".to_string(), max_length.into());
+    }
+
+    serde_json::Value::Object(leaf)
+}
+
+/// Insert a value at a dot-separated path, creating intermediate objects
+fn insert_nested(
+    root: &mut serde_json::Map<String, serde_json::Value>,
+    key: &str,
+    value: serde_json::Value,
+) {
+    let parts: Vec<&str> = key.split('.').collect();
+
+    // Navigate to the correct nested position
+    let mut current = root;
+    for (i, part) in parts.iter().enumerate() {
+        if i == parts.len() - 1 {
+            // Last part - insert the value
+            current.insert(part.to_string(), value);
+            return;
+        }
+
+        // Intermediate part - ensure nested object exists
+        let part_string = part.to_string();
+        if !current.contains_key(&part_string) {
+            current.insert(
+                part_string.clone(),
+                serde_json::Value::Object(serde_json::Map::new()),
+            );
+        }
+
+        // Move to the nested object
+        current = current
+            .get_mut(&part_string)
+            .and_then(|v| v.as_object_mut())
+            .expect("Expected object");
+    }
 }
 
 #[cfg(test)]
@@ -173,8 +574,51 @@ mod tests {
             "array": [1, 2, 3]
         });
         let result = flatten_json(&json, String::new());
-        // Non-string values should be skipped
-        assert_eq!(result.len(), 0);
+        // Scalars are stringified, arrays indexed and nulls skipped
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.get("number"), Some(&"42".to_string()));
+        assert_eq!(result.get("boolean"), Some(&"true".to_string()));
+        assert_eq!(result.get("array.2"), Some(&"3".to_string()));
+        assert!(!result.contains_key("null"));
+    }
+
+    #[test]
+    fn test_flatten_entries_arrays() {
+        let json = json!({
+            "tips": ["Jump", {"@value": "Run", "@context": "verb"}]
+        });
+        let result = flatten_entries(&json, String::new(), ScalarPolicy::Reject).unwrap();
+
+        assert_eq!(result["tips.0"].value, "Jump");
+        assert_eq!(result["tips.1"].value, "Run");
+        assert_eq!(result["tips.1"].context.as_deref(), Some("verb"));
+
+        let nested = json!({"levels": [{"name": "One"}]});
+        let err = flatten_entries(&nested, String::new(), ScalarPolicy::Stringify).unwrap_err();
+        assert_eq!(err.key, "levels.0");
+        assert!(err.message.contains("can only contain text"));
+    }
+
+    #[test]
+    fn test_flatten_entries_scalar_policy() {
+        let json = json!({"game": {"maxPlayers": 8, "pvp": false}});
+
+        let result = flatten_entries(&json, String::new(), ScalarPolicy::Stringify).unwrap();
+        assert_eq!(result["game.maxPlayers"].value, "8");
+        assert_eq!(result["game.pvp"].value, "false");
+
+        let err = flatten_entries(&json, String::new(), ScalarPolicy::Reject).unwrap_err();
+        assert!(err.message.contains("Non-string value"));
+        assert!(err.key.starts_with("game."));
+    }
+
+    #[test]
+    fn test_flatten_entries_rejects_null() {
+        let json = json!({"ui": {"title": null}});
+
+        let err = flatten_entries(&json, String::new(), ScalarPolicy::Stringify).unwrap_err();
+        assert_eq!(err.key, "ui.title");
+        assert!(err.message.contains("Null value at key 'ui.title'"));
     }
 
     #[test]
@@ -214,12 +658,18 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.label".to_string(),
                 value: "Label".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -227,4 +677,187 @@ mod tests {
         assert_eq!(result["ui"]["button"], "Buy");
         assert_eq!(result["ui"]["label"], "Label");
     }
+
+    #[test]
+    fn test_flatten_entries_with_metadata() {
+        let json = json!({
+            "ui": {
+                "buy": {
+                    "@value": "Buy",
+                    "@context": "button",
+                    "@description": "Shop purchase button",
+                    "@maxLength": 12
+                },
+                "sell": "Sell"
+            }
+        });
+
+        let result = flatten_entries(&json, String::new(), ScalarPolicy::default()).unwrap();
+
+        assert_eq!(
+            result["ui.buy"],
+            FlatEntry {
+                value: "Buy".to_string(),
+                context: Some("button".to_string()),
+                description: Some("Shop purchase button".to_string()),
+                max_length: Some(12),
+            }
+        );
+        assert_eq!(result["ui.sell"].value, "Sell");
+        assert_eq!(result["ui.sell"].context, None);
+
+        // Plain flattening only sees the value
+        let plain = flatten_json(&json, String::new());
+        assert_eq!(plain.get("ui.buy"), Some(&"Buy".to_string()));
+    }
+
+    #[test]
+    fn test_flatten_entries_rejects_bad_metadata() {
+        let missing_value = json!({"buy": {"@context": "button"}});
+        let err =
+            flatten_entries(&missing_value, String::new(), ScalarPolicy::default()).unwrap_err();
+        assert!(err.to_string().contains("no '@value'"));
+
+        let unknown = json!({"buy": {"@value": "Buy", "@note": "x"}});
+        let err = flatten_entries(&unknown, String::new(), ScalarPolicy::default()).unwrap_err();
+        assert!(err.to_string().contains("Unknown field '@note'"));
+
+        let bad_length = json!({"buy": {"@value": "Buy", "@maxLength": "12"}});
+        let err = flatten_entries(&bad_length, String::new(), ScalarPolicy::default()).unwrap_err();
+        assert!(err.to_string().contains("positive integer"));
+    }
+
+    #[test]
+    fn test_unflatten_translations_keeps_metadata() {
+        use crate::parser::Translation;
+
+        let translations = vec![Translation {
+            key: "ui.buy".to_string(),
+            value: "Buy".to_string(),
+            locale: "en".to_string(),
+            context: Some("button".to_string()),
+            description: None,
+            max_length: Some(12),
+            location: None,
+        }];
+
+        let result = unflatten_translations(&translations);
+        assert_eq!(
+            result,
+            json!({"ui": {"buy": {"@value": "Buy", "@context": "button", "@maxLength": 12}}})
+        );
+
+        let entries = flatten_entries(&result, String::new(), ScalarPolicy::default()).unwrap();
+        assert_eq!(entries["ui.buy"].context.as_deref(), Some("button"));
+        assert_eq!(entries["ui.buy"].max_length, Some(12));
+    }
+
+    #[test]
+    fn test_merge_translations_keeps_order() {
+        use crate::parser::Translation;
+
+        let translation = |key: &str, value: &str| Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: "en".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+
+        let mut root: serde_json::Value = serde_json::from_str(
+            r#"{"zeta": "Z", "shop": {"sell": "Sell", "buy": "Buy"}, "alpha": "A"}"#,
+        )
+        .unwrap();
+
+        merge_translations(
+            &mut root,
+            &[
+                translation("shop.buy", "Purchase"),
+                translation("shop.cancel", "Cancel"),
+                translation("alpha", "First"),
+            ],
+        );
+
+        assert_eq!(
+            serde_json::to_string(&root).unwrap(),
+            r#"{"zeta":"Z","shop":{"sell":"Sell","buy":"Purchase","cancel":"Cancel"},"alpha":"First"}"#
+        );
+    }
+
+    #[test]
+    fn test_merge_translations_inserts_next_to_siblings() {
+        use crate::parser::Translation;
+
+        let translations: Vec<Translation> =
+            ["shop.buyAll", "shop.items(few)", "shop.cancel", "title"]
+                .iter()
+                .map(|key| Translation {
+                    key: key.to_string(),
+                    value: "New".to_string(),
+                    locale: "en".to_string(),
+                    context: None,
+                    description: None,
+                    max_length: None,
+                    location: None,
+                })
+                .collect();
+
+        let mut root = json!({
+            "shop": {
+                "buy": "Buy",
+                "items(one)": "Item",
+                "items(other)": "Items",
+                "sell": "Sell"
+            },
+            "zeta": "Z",
+            "alpha": "A"
+        });
+
+        merge_translations(&mut root, &translations);
+
+        assert_eq!(
+            serde_json::to_string(&root).unwrap(),
+            r#"{"shop":{"buy":"Buy","buyAll":"New","cancel":"New","items(one)":"Item","items(other)":"Items","items(few)":"New","sell":"Sell"},"zeta":"Z","alpha":"A","title":"New"}"#
+        );
+    }
+
+    #[test]
+    fn test_merge_translations_lists_and_metadata() {
+        use crate::parser::Translation;
+
+        let mut root = json!({
+            "tips": ["Jump"],
+            "buy": {"@value": "Buy", "@maxLength": 12}
+        });
+
+        let translations: Vec<Translation> =
+            [("tips.2", "Hide"), ("tips.1", "Run"), ("buy", "Get")]
+                .iter()
+                .map(|(key, value)| Translation {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    locale: "en".to_string(),
+                    context: Some("button".to_string()).filter(|_| *key == "buy"),
+                    description: None,
+                    max_length: None,
+                    location: None,
+                })
+                .collect();
+
+        merge_translations(&mut root, &translations);
+
+        assert_eq!(
+            root,
+            json!({
+                "tips": ["Jump", "Run", "Hide"],
+                "buy": {"@value": "Get", "@maxLength": 12, "@context": "button"}
+            })
+        );
+
+        // Without a first item there is no list to append to, so indices stay keys
+        let fresh = unflatten_translations(&translations[..2]);
+        assert_eq!(fresh, json!({"tips": {"1": "Run", "2": "Hide"}}));
+    }
 }
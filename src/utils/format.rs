@@ -1,25 +1,339 @@
 /// Format specifier types
 /// Based on Flutter Slang format specifiers
-use std::collections::HashMap;
+use super::icu::{self, Part};
+use crate::config::FormatterConfig;
+use anyhow::{bail, Result};
+use std::collections::{BTreeMap, HashMap};
+
+/// Custom format specifiers from the config's `formatters` section, by name
+pub type Formatters = BTreeMap<String, FormatterConfig>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum FormatSpecifier {
     /// Integer formatting: {count:int}
     Int,
-    /// Fixed decimal: {price:fixed(2)}
+    /// Fixed decimal, with the locale's separators: {price:fixed(2)}
     Fixed(usize),
-    /// Number formatting: {value:num}
+    /// Number with the locale's separators: {value:num}
     Num,
-    /// DateTime formatting: {date:datetime}
-    DateTime,
+    /// Short compact number: {views:compact} (1.2K, 1,2 Mio.)
+    Compact,
+    /// Percentage of a ratio: {progress:percent} (0.25 -> 25%)
+    Percent,
+    /// Currency amount: {price:currency(Robux)}
+    Currency(String),
+    /// Seconds from now, in the largest whole unit: {ends:relative} (in 3 minutes)
+    Relative,
+    /// Seconds as a timer, in two units: {left:duration} (2h 05m)
+    Duration,
+    /// DateTime in the locale, with a `FormatLocalTime` pattern: {date:datetime(long)}
+    DateTime(String),
     /// Nested translation: {label:translate}
     Translate,
+    /// Custom specifier from the config, formatted by its module: {hp:health}
+    Custom(FormatterConfig),
     /// No specifier (default string)
     None,
 }
 
+impl FormatSpecifier {
+    /// Luau type of a parameter with this specifier, for the type definitions
+    pub fn luau_type(&self) -> &str {
+        match self {
+            FormatSpecifier::Int
+            | FormatSpecifier::Fixed(_)
+            | FormatSpecifier::Num
+            | FormatSpecifier::Compact
+            | FormatSpecifier::Percent
+            | FormatSpecifier::Currency(_)
+            | FormatSpecifier::Relative
+            | FormatSpecifier::Duration => "number",
+            FormatSpecifier::DateTime(_) => "DateTime",
+            FormatSpecifier::Translate => "string",
+            FormatSpecifier::Custom(formatter) => &formatter.input,
+            FormatSpecifier::None => "string | number",
+        }
+    }
+
+    /// Whether the generated module formats the value with the locale's number symbols
+    pub fn is_locale_aware(&self) -> bool {
+        matches!(
+            self,
+            FormatSpecifier::Fixed(_)
+                | FormatSpecifier::Num
+                | FormatSpecifier::Compact
+                | FormatSpecifier::Percent
+                | FormatSpecifier::Currency(_)
+                | FormatSpecifier::Relative
+        )
+    }
+}
+
+/// Currency unit drawn as the Robux icon
+pub const ROBUX: &str = "Robux";
+
+/// Luau string literal of the Robux icon, a private-use glyph of Roblox's fonts
+const ROBUX_ICON: &str = "\"\\u{E002}\"";
+
+/// Quote text as a Luau string literal
+///
+/// Quotes, backslashes and ASCII control characters are escaped (`\"`, `\\`,
+/// `\n`, `\x1b`), and invisible Unicode characters are written as `\u{..}`.
+pub fn luau_string(text: &str) -> String {
+    let mut literal = String::with_capacity(text.len() + 2);
+    literal.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_ascii_control() => literal.push_str(&format!("\\x{:02x}", c as u32)),
+            c if c.is_ascii() => literal.push(c),
+            c => literal.extend(c.escape_debug()),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// `FormatLocalTime` pattern of a `date`, `time` or `datetime` specifier
+///
+/// The `short`, `medium`, `long` and `full` styles map to Roblox's localized
+/// tokens (`L`, `ll`, `LL`, ...); any other style is used as the pattern itself.
+pub fn date_time_pattern(kind: &str, style: Option<&str>) -> String {
+    let style = style.map(str::trim).filter(|s| !s.is_empty());
+    let pattern = match (kind, style.unwrap_or("short")) {
+        ("date", "short") => "L",
+        ("date", "medium") => "ll",
+        ("date", "long") => "LL",
+        ("date", "full") => "dddd, LL",
+        ("time", "short") => "LT",
+        ("time", "medium" | "long" | "full") => "LTS",
+        (_, "short") => "L LT",
+        (_, "medium") => "lll",
+        (_, "long") => "LLL",
+        (_, "full") => "LLLL",
+        (_, custom) => custom,
+    };
+    pattern.to_string()
+}
+
+/// Names of the format specifiers, as written after the colon
+const SPECIFIER_NAMES: &[&str] = &[
+    "int",
+    "num",
+    "fixed",
+    "compact",
+    "percent",
+    "currency",
+    "relative",
+    "duration",
+    "date",
+    "time",
+    "datetime",
+    "translate",
+];
+
+/// Specifiers from other formatting libraries, and the one to use instead
+const SPECIFIER_ALIASES: &[(&str, &str)] = &[
+    ("integer", "int"),
+    ("number", "num"),
+    ("decimal", "fixed(2)"),
+    ("float", "fixed(2)"),
+    ("robux", "currency(Robux)"),
+    ("money", "currency(Robux)"),
+    ("ago", "relative"),
+    ("timer", "duration"),
+    ("timestamp", "datetime"),
+    ("t", "translate"),
+];
+
+/// Parse format specifier from parameter, rejecting unknown or malformed specifiers
+/// Example: "count:int" -> ("count", FormatSpecifier::Int)
+///
+/// Specifiers declared in `formatters` become [`FormatSpecifier::Custom`].
+/// Errors suggest the specifier that was probably meant (`integer` -> `int`).
+pub fn try_parse_format_specifier(
+    param: &str,
+    formatters: &Formatters,
+) -> Result<(String, FormatSpecifier)> {
+    let (name, format) = parse_format_specifier(param);
+    let Some((_, spec)) = param.split_once(':') else {
+        return Ok((name, format));
+    };
+    let spec = spec.trim();
+
+    if format == FormatSpecifier::None {
+        if let Some(formatter) = formatters.get(spec) {
+            return Ok((name, FormatSpecifier::Custom(formatter.clone())));
+        }
+        let kind = spec.split('(').next().unwrap_or(spec).trim();
+        if SPECIFIER_NAMES.contains(&kind) || formatters.contains_key(kind) {
+            bail!(
+                "Malformed format specifier '{}' for '{}' (expected {})",
+                spec,
+                name,
+                specifier_usage(kind)
+            );
+        }
+        match suggest_specifier(kind, formatters) {
+            Some(suggestion) => bail!(
+                "Unknown format specifier '{}' for '{}' (did you mean '{}'?)",
+                spec,
+                name,
+                suggestion
+            ),
+            None => bail!(
+                "Unknown format specifier '{}' for '{}' (expected one of: {})",
+                spec,
+                name,
+                SPECIFIER_NAMES
+                    .iter()
+                    .copied()
+                    .chain(formatters.keys().map(String::as_str))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    if let Some(digits) = spec
+        .strip_prefix("fixed(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        if digits.trim().parse::<usize>().is_err() {
+            bail!(
+                "Invalid digits '{}' in format specifier '{}' for '{}' (expected {})",
+                digits,
+                spec,
+                name,
+                specifier_usage("fixed")
+            );
+        }
+    }
+
+    Ok((name, format))
+}
+
+/// How a specifier is written, for error messages
+fn specifier_usage(kind: &str) -> &'static str {
+    match kind {
+        "fixed" => "fixed or fixed(n), like fixed(2)",
+        "currency" => "currency(unit), like currency(Robux)",
+        "date" | "time" | "datetime" => "a style like date(long), or a FormatLocalTime pattern",
+        _ => "no arguments",
+    }
+}
+
+/// The specifier an unknown one was probably meant to be
+fn suggest_specifier(spec: &str, formatters: &Formatters) -> Option<String> {
+    let spec = spec.to_lowercase();
+    if let Some((_, suggestion)) = SPECIFIER_ALIASES.iter().find(|(alias, _)| *alias == spec) {
+        return Some(suggestion.to_string());
+    }
+    SPECIFIER_NAMES
+        .iter()
+        .copied()
+        .chain(formatters.keys().map(String::as_str))
+        .map(|name| (edit_distance(&spec, &name.to_lowercase()), name))
+        .filter(|(distance, _)| *distance <= 2)
+        .min()
+        .map(|(_, name)| name.to_string())
+}
+
+/// Validate the config's custom format specifiers
+///
+/// Names must be single words that don't replace a built-in specifier, and
+/// functions Luau identifiers.
+pub fn validate_formatters(formatters: &Formatters) -> Result<()> {
+    let example = "formatters:\n\
+                     health:\n\
+                       module: game.ReplicatedStorage.Formatters\n\
+                       function: heartBar\n\
+                       input: number";
+
+    for (name, formatter) in formatters {
+        if !icu::is_argument_name(name) {
+            bail!(
+                "Configuration error: formatter name '{}' is not a valid specifier\n\
+                 \n\
+                 Formatters are written after the colon of a placeholder ({{hp:health}}),\n\
+                 so their names can only use letters, digits and underscores.\n\
+                 \n\
+                 Hint: Rename it, e.g. 'heart_bar' instead of 'heart-bar'.",
+                name
+            );
+        }
+        if SPECIFIER_NAMES.contains(&name.as_str()) {
+            bail!(
+                "Configuration error: formatter '{}' has the name of a built-in format specifier\n\
+                 \n\
+                 Hint: Built-in specifiers can't be replaced. Pick another name, e.g. 'game_{}'.",
+                name,
+                name
+            );
+        }
+        if formatter.module.trim().is_empty() {
+            bail!(
+                "Configuration error: formatter '{}' needs a module\n\
+                 \n\
+                 Expected format:\n\
+                 {}",
+                name,
+                example
+            );
+        }
+        if !icu::is_argument_name(&formatter.function) {
+            bail!(
+                "Configuration error: formatter '{}' has an invalid function name '{}'\n\
+                 \n\
+                 Expected format:\n\
+                 {}\n\
+                 \n\
+                 Hint: The function is called as module.function(value, locale),\n\
+                 so it must be a Luau identifier.",
+                name,
+                formatter.function,
+                example
+            );
+        }
+        if formatter.input.trim().is_empty() {
+            bail!(
+                "Configuration error: formatter '{}' has an empty input type\n\
+                 \n\
+                 Hint: Use a Luau type like number, string or DateTime,\n\
+                 or remove input to accept any value (unknown).",
+                name
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
 /// Parse format specifier from parameter
 /// Example: "count:int" -> ("count", FormatSpecifier::Int)
+///
+/// Unknown specifiers become [`FormatSpecifier::None`]; see
+/// [`try_parse_format_specifier`] to reject them.
 pub fn parse_format_specifier(param: &str) -> (String, FormatSpecifier) {
     if let Some(colon_pos) = param.find(':') {
         let name = param[..colon_pos].trim().to_string();
@@ -28,7 +342,14 @@ pub fn parse_format_specifier(param: &str) -> (String, FormatSpecifier) {
         let format = match spec {
             "int" => FormatSpecifier::Int,
             "num" => FormatSpecifier::Num,
-            "datetime" => FormatSpecifier::DateTime,
+            "fixed" => FormatSpecifier::Fixed(2),
+            "compact" => FormatSpecifier::Compact,
+            "percent" => FormatSpecifier::Percent,
+            "relative" => FormatSpecifier::Relative,
+            "duration" => FormatSpecifier::Duration,
+            "date" | "time" | "datetime" => {
+                FormatSpecifier::DateTime(date_time_pattern(spec, None))
+            }
             "translate" => FormatSpecifier::Translate,
             s if s.starts_with("fixed(") && s.ends_with(')') => {
                 // Parse fixed(n)
@@ -36,6 +357,18 @@ pub fn parse_format_specifier(param: &str) -> (String, FormatSpecifier) {
                 let digits = digits_str.parse::<usize>().unwrap_or(2);
                 FormatSpecifier::Fixed(digits)
             }
+            s if s.starts_with("currency(") && s.ends_with(')') => {
+                // Parse currency(unit)
+                let unit = s[9..s.len() - 1].trim();
+                FormatSpecifier::Currency(if unit.is_empty() { ROBUX } else { unit }.to_string())
+            }
+            s if s.ends_with(')') => match s.split_once('(') {
+                // Parse date(style), time(style) and datetime(style)
+                Some((kind @ ("date" | "time" | "datetime"), style)) => FormatSpecifier::DateTime(
+                    date_time_pattern(kind, Some(&style[..style.len() - 1])),
+                ),
+                _ => FormatSpecifier::None,
+            },
             _ => FormatSpecifier::None,
         };
 
@@ -46,7 +379,39 @@ pub fn parse_format_specifier(param: &str) -> (String, FormatSpecifier) {
 }
 
 /// Extract all parameters with their format specifiers from a translation string
+///
+/// For ICU messages (`{count, plural, ...}`) these are the message's arguments,
+/// whose formatting is compiled into the message itself. Escaped braces (`{{`,
+/// `}}`) are literal text. Custom specifiers are [`FormatSpecifier::None`]; see
+/// [`extract_parameters_with_formatters`] to resolve them.
 pub fn extract_parameters_with_format(text: &str) -> HashMap<String, FormatSpecifier> {
+    extract_parameters_with_formatters(text, &Formatters::new())
+}
+
+/// Extract all parameters with their format specifiers, resolving the custom
+/// specifiers declared in `formatters`
+pub fn extract_parameters_with_formatters(
+    text: &str,
+    formatters: &Formatters,
+) -> HashMap<String, FormatSpecifier> {
+    if icu::is_icu_message(text) {
+        if let Ok(parts) = icu::parse_message_with_formatters(text, formatters) {
+            return icu::parameters(&parts)
+                .into_keys()
+                .map(|name| (name, FormatSpecifier::None))
+                .collect();
+        }
+    }
+    if let Ok(parts) = icu::parse_plain(text, formatters) {
+        return parts
+            .into_iter()
+            .filter_map(|part| match part {
+                Part::Argument { name, format } => Some((name, format)),
+                _ => None,
+            })
+            .collect();
+    }
+
     let mut params = HashMap::new();
     let mut in_param = false;
     let mut current_param = String::new();
@@ -75,32 +440,88 @@ pub fn extract_parameters_with_format(text: &str) -> HashMap<String, FormatSpeci
     params
 }
 
-/// Generate Luau code for format specifier
-pub fn generate_format_code(param_name: &str, specifier: &FormatSpecifier) -> String {
-    match specifier {
-        FormatSpecifier::Int => {
+/// Luau expression formatting `value` in `locale` for locale-aware specifiers
+///
+/// The expression calls the `formatNumber`, `formatCompact`, `formatPercent`,
+/// `formatCurrency`, `formatRelative` or `formatDuration` helpers of the
+/// generated module. Robux amounts are whole numbers after the Robux icon;
+/// other units are written before the amount as given, with two decimals.
+pub fn number_format_expression(
+    specifier: &FormatSpecifier,
+    value: &str,
+    locale: &str,
+) -> Option<String> {
+    let expression = match specifier {
+        FormatSpecifier::Fixed(digits) => {
             format!(
-                "params.{} = math.floor(tonumber(params.{}) or 0)",
-                param_name, param_name
+                "formatNumber({}, {}, {}, {})",
+                locale, value, digits, digits
             )
         }
-        FormatSpecifier::Fixed(digits) => {
+        FormatSpecifier::Num => format!("formatNumber({}, {}, 0, 3)", locale, value),
+        FormatSpecifier::Compact => format!("formatCompact({}, {})", locale, value),
+        FormatSpecifier::Percent => format!("formatPercent({}, {})", locale, value),
+        FormatSpecifier::Currency(unit) if unit.eq_ignore_ascii_case(ROBUX) => {
+            format!("formatCurrency({}, {}, {}, 0)", locale, value, ROBUX_ICON)
+        }
+        FormatSpecifier::Currency(unit) => {
             format!(
-                "params.{} = string.format(\"%.{}f\", tonumber(params.{}) or 0)",
-                param_name, digits, param_name
+                "formatCurrency({}, {}, {}, 2)",
+                locale,
+                value,
+                luau_string(unit)
             )
         }
-        FormatSpecifier::Num => {
+        FormatSpecifier::Relative => format!("formatRelative({}, {})", locale, value),
+        FormatSpecifier::Duration => format!("formatDuration({}, {})", locale, value),
+        _ => return None,
+    };
+    Some(expression)
+}
+
+/// Luau call of a custom specifier's function with `value` and `locale`
+///
+/// The module is required when the value is formatted, so it may itself
+/// require the generated module.
+pub fn custom_format_expression(formatter: &FormatterConfig, value: &str, locale: &str) -> String {
+    format!(
+        "require({}).{}({}, {})",
+        formatter.module.trim(),
+        formatter.function,
+        value,
+        locale
+    )
+}
+
+/// Generate Luau code for format specifier
+pub fn generate_format_code(param_name: &str, specifier: &FormatSpecifier) -> String {
+    let value = format!("params.{}", param_name);
+    if let Some(expression) = number_format_expression(specifier, &value, "self._locale") {
+        return format!("{} = {}", value, expression);
+    }
+    if let FormatSpecifier::Custom(formatter) = specifier {
+        return format!(
+            "{} = {}",
+            value,
+            custom_format_expression(formatter, &value, "self._locale")
+        );
+    }
+
+    match specifier {
+        FormatSpecifier::Int => {
             format!(
-                "params.{} = tostring(params.{} or 0)",
+                "params.{} = math.floor(tonumber(params.{}) or 0)",
                 param_name, param_name
             )
         }
-        FormatSpecifier::DateTime => {
-            // Roblox DateTime formatting
+        FormatSpecifier::DateTime(pattern) => {
+            // Roblox DateTime formatting, in the current locale
             format!(
-                "if typeof(params.{}) == \"DateTime\" then\n        params.{} = params.{}:FormatLocalTime(\"L LT\", \"en-us\")\n    end",
-                param_name, param_name, param_name
+                "if typeof(params.{}) == \"DateTime\" then\n        params.{} = params.{}:FormatLocalTime({}, self._locale)\n    end",
+                param_name,
+                param_name,
+                param_name,
+                luau_string(pattern)
             )
         }
         FormatSpecifier::Translate => {
@@ -110,7 +531,7 @@ pub fn generate_format_code(param_name: &str, specifier: &FormatSpecifier) -> St
                 param_name, param_name, param_name
             )
         }
-        FormatSpecifier::None => String::new(),
+        _ => String::new(),
     }
 }
 
@@ -150,7 +571,122 @@ mod tests {
     fn test_parse_datetime_specifier() {
         let (name, spec) = parse_format_specifier("date:datetime");
         assert_eq!(name, "date");
-        assert_eq!(spec, FormatSpecifier::DateTime);
+        assert_eq!(spec, FormatSpecifier::DateTime("L LT".to_string()));
+    }
+
+    #[test]
+    fn test_parse_date_time_specifiers() {
+        let pattern = |param: &str| match parse_format_specifier(param).1 {
+            FormatSpecifier::DateTime(pattern) => pattern,
+            other => panic!("{} parsed as {:?}", param, other),
+        };
+        assert_eq!(pattern("d:date"), "L");
+        assert_eq!(pattern("d:date(long)"), "LL");
+        assert_eq!(pattern("d:time"), "LT");
+        assert_eq!(pattern("d:time(long)"), "LTS");
+        assert_eq!(pattern("d:datetime(medium)"), "lll");
+        assert_eq!(pattern("d:datetime( LLL )"), "LLL");
+        assert_eq!(pattern("d:datetime()"), "L LT");
+
+        assert_eq!(
+            parse_format_specifier("ends:relative").1,
+            FormatSpecifier::Relative
+        );
+        assert_eq!(
+            parse_format_specifier("left:duration").1,
+            FormatSpecifier::Duration
+        );
+        assert_eq!(
+            parse_format_specifier("d:weekday(long)").1,
+            FormatSpecifier::None
+        );
+    }
+
+    #[test]
+    fn test_try_parse_format_specifier() {
+        assert_eq!(
+            try_parse_format_specifier("count:int", &Formatters::new()).unwrap(),
+            ("count".to_string(), FormatSpecifier::Int)
+        );
+        assert_eq!(
+            try_parse_format_specifier("name", &Formatters::new()).unwrap(),
+            ("name".to_string(), FormatSpecifier::None)
+        );
+
+        let error = |param: &str| {
+            try_parse_format_specifier(param, &Formatters::new())
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("count:integer"),
+            "Unknown format specifier 'integer' for 'count' (did you mean 'int'?)"
+        );
+        assert!(error("price:fxed").contains("(did you mean 'fixed'?)"));
+        assert!(error("x:banana").contains("(expected one of: int, num, fixed,"));
+        assert!(error("price:fixed(x)").contains("Invalid digits 'x'"));
+        assert!(error("price:fixed()").contains("Invalid digits ''"));
+        assert!(error("cost:currency(Robux").contains("Malformed format specifier"));
+        assert!(error("count:int(3)").contains("(expected no arguments)"));
+    }
+
+    fn formatters() -> Formatters {
+        Formatters::from([(
+            "health".to_string(),
+            FormatterConfig {
+                module: "game.ReplicatedStorage.Formatters".to_string(),
+                function: "heartBar".to_string(),
+                input: "number".to_string(),
+            },
+        )])
+    }
+
+    #[test]
+    fn test_try_parse_custom_specifier() {
+        let formatters = formatters();
+        assert_eq!(
+            try_parse_format_specifier("hp:health", &formatters).unwrap(),
+            (
+                "hp".to_string(),
+                FormatSpecifier::Custom(formatters["health"].clone())
+            )
+        );
+        // Built-in specifiers are unchanged
+        assert_eq!(
+            try_parse_format_specifier("count:int", &formatters)
+                .unwrap()
+                .1,
+            FormatSpecifier::Int
+        );
+
+        let error = |param: &str| {
+            try_parse_format_specifier(param, &formatters)
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("hp:helth").contains("(did you mean 'health'?)"));
+        assert!(error("hp:health(3)").contains("(expected no arguments)"));
+        assert!(error("x:banana").ends_with("datetime, translate, health)"));
+        assert!(try_parse_format_specifier("hp:health", &Formatters::new()).is_err());
+    }
+
+    #[test]
+    fn test_validate_formatters() {
+        assert!(validate_formatters(&formatters()).is_ok());
+
+        let error = |name: &str, edit: fn(&mut FormatterConfig)| {
+            let mut formatter = formatters()["health"].clone();
+            edit(&mut formatter);
+            validate_formatters(&Formatters::from([(name.to_string(), formatter)]))
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("heart-bar", |_| {}).contains("formatter name 'heart-bar' is not a valid"));
+        assert!(error("int", |_| {}).contains("has the name of a built-in format specifier"));
+        assert!(error("health", |f| f.module.clear()).contains("needs a module"));
+        assert!(error("health", |f| f.function = "heart bar".to_string())
+            .contains("invalid function name 'heart bar'"));
+        assert!(error("health", |f| f.input = " ".to_string()).contains("empty input type"));
     }
 
     #[test]
@@ -239,6 +775,15 @@ mod tests {
         assert_eq!(params.get("inner"), Some(&FormatSpecifier::None));
     }
 
+    #[test]
+    fn test_extract_escaped_braces() {
+        let params = extract_parameters_with_format("{{not_a_param}} {name} {{{count:int}}}");
+
+        assert_eq!(params.len(), 2);
+        assert_eq!(params.get("name"), Some(&FormatSpecifier::None));
+        assert_eq!(params.get("count"), Some(&FormatSpecifier::Int));
+    }
+
     #[test]
     fn test_extract_all_specifier_types() {
         let text = "Int: {a:int}, Fixed: {b:fixed(3)}, Num: {c:num}, DateTime: {d:datetime}, Translate: {e:translate}, None: {f}";
@@ -248,7 +793,10 @@ mod tests {
         assert_eq!(params.get("a"), Some(&FormatSpecifier::Int));
         assert_eq!(params.get("b"), Some(&FormatSpecifier::Fixed(3)));
         assert_eq!(params.get("c"), Some(&FormatSpecifier::Num));
-        assert_eq!(params.get("d"), Some(&FormatSpecifier::DateTime));
+        assert_eq!(
+            params.get("d"),
+            Some(&FormatSpecifier::DateTime("L LT".to_string()))
+        );
         assert_eq!(params.get("e"), Some(&FormatSpecifier::Translate));
         assert_eq!(params.get("f"), Some(&FormatSpecifier::None));
     }
@@ -264,24 +812,114 @@ mod tests {
     #[test]
     fn test_generate_format_code_fixed() {
         let code = generate_format_code("price", &FormatSpecifier::Fixed(2));
-        assert!(code.contains("string.format"));
-        assert!(code.contains("%.2f"));
-        assert!(code.contains("params.price"));
+        assert_eq!(
+            code,
+            "params.price = formatNumber(self._locale, params.price, 2, 2)"
+        );
     }
 
     #[test]
     fn test_generate_format_code_num() {
         let code = generate_format_code("value", &FormatSpecifier::Num);
-        assert!(code.contains("tostring"));
-        assert!(code.contains("params.value"));
+        assert_eq!(
+            code,
+            "params.value = formatNumber(self._locale, params.value, 0, 3)"
+        );
+    }
+
+    #[test]
+    fn test_parse_number_format_specifiers() {
+        assert_eq!(
+            parse_format_specifier("views:compact").1,
+            FormatSpecifier::Compact
+        );
+        assert_eq!(
+            parse_format_specifier("ratio:percent").1,
+            FormatSpecifier::Percent
+        );
+        assert_eq!(
+            parse_format_specifier("price:fixed").1,
+            FormatSpecifier::Fixed(2)
+        );
+        assert_eq!(
+            parse_format_specifier("price:currency(Robux)").1,
+            FormatSpecifier::Currency("Robux".to_string())
+        );
+        assert_eq!(
+            parse_format_specifier("price:currency()").1,
+            FormatSpecifier::Currency(ROBUX.to_string())
+        );
+    }
+
+    #[test]
+    fn test_luau_string() {
+        assert_eq!(luau_string("Say \"hi\""), "\"Say \\\"hi\\\"\"");
+        assert_eq!(luau_string("a\\b\n\t"), "\"a\\\\b\\n\\t\"");
+        // A digit after a control character stays out of its escape
+        assert_eq!(luau_string("\u{0}1\u{1b}"), "\"\\x001\\x1b\"");
+        assert_eq!(luau_string("it's €5"), "\"it's €5\"");
+        assert_eq!(luau_string("1\u{a0}000"), "\"1\\u{a0}000\"");
+    }
+
+    #[test]
+    fn test_generate_format_code_locale_aware() {
+        assert_eq!(
+            generate_format_code("views", &FormatSpecifier::Compact),
+            "params.views = formatCompact(self._locale, params.views)"
+        );
+        assert_eq!(
+            generate_format_code("ratio", &FormatSpecifier::Percent),
+            "params.ratio = formatPercent(self._locale, params.ratio)"
+        );
+        assert_eq!(
+            generate_format_code("price", &FormatSpecifier::Currency("Robux".to_string())),
+            "params.price = formatCurrency(self._locale, params.price, \"\\u{E002}\", 0)"
+        );
+        assert_eq!(
+            generate_format_code("price", &FormatSpecifier::Currency("$".to_string())),
+            "params.price = formatCurrency(self._locale, params.price, \"$\", 2)"
+        );
+        assert_eq!(
+            generate_format_code("price", &FormatSpecifier::Currency("R\"$".to_string())),
+            "params.price = formatCurrency(self._locale, params.price, \"R\\\"$\", 2)"
+        );
+        assert!(!FormatSpecifier::Int.is_locale_aware());
+        assert!(FormatSpecifier::Num.is_locale_aware());
     }
 
     #[test]
     fn test_generate_format_code_datetime() {
-        let code = generate_format_code("date", &FormatSpecifier::DateTime);
+        let code = generate_format_code("date", &FormatSpecifier::DateTime("LL".to_string()));
         assert!(code.contains("DateTime"));
-        assert!(code.contains("FormatLocalTime"));
-        assert!(code.contains("params.date"));
+        assert!(code.contains("params.date:FormatLocalTime(\"LL\", self._locale)"));
+
+        assert_eq!(
+            generate_format_code("ends", &FormatSpecifier::Relative),
+            "params.ends = formatRelative(self._locale, params.ends)"
+        );
+        assert_eq!(
+            generate_format_code("left", &FormatSpecifier::Duration),
+            "params.left = formatDuration(self._locale, params.left)"
+        );
+    }
+
+    #[test]
+    fn test_generate_format_code_custom() {
+        let specifier = FormatSpecifier::Custom(formatters()["health"].clone());
+        assert_eq!(
+            generate_format_code("hp", &specifier),
+            "params.hp = require(game.ReplicatedStorage.Formatters).heartBar(params.hp, self._locale)"
+        );
+        assert_eq!(specifier.luau_type(), "number");
+        assert!(!specifier.is_locale_aware());
+
+        let text = "HP: {hp:health} ({hp_max:int})";
+        let params = extract_parameters_with_formatters(text, &formatters());
+        assert_eq!(params.get("hp"), Some(&specifier));
+        // Without the config, custom specifiers are left unformatted
+        let params = extract_parameters_with_format(text);
+        assert_eq!(params.get("hp"), Some(&FormatSpecifier::None));
+        assert_eq!(params.get("hp_max"), Some(&FormatSpecifier::Int));
     }
 
     #[test]
@@ -0,0 +1,730 @@
+//! ICU MessageFormat
+//!
+//! Translation values may use ICU MessageFormat arguments alongside the plain
+//! `{name}` / `{name:spec}` parameters:
+//!
+//! ```text
+//! {count, plural, =0 {No items} one {# item} other {# items}}
+//! {place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}
+//! {gender, select, male {He} female {She} other {They}} joined
+//! ```
+//!
+//! Messages are parsed here and compiled to Luau expressions; plural and
+//! selectordinal cases are picked with the CLDR rules from [`super::plurals`].
+
+use super::format::{self, FormatSpecifier, Formatters};
+use super::plurals::PLURAL_CATEGORIES;
+use anyhow::{bail, Result};
+use std::collections::BTreeMap;
+
+/// A piece of a parsed message
+#[derive(Debug, Clone, PartialEq)]
+pub enum Part {
+    /// Literal text
+    Text(String),
+    /// `{name}` or `{name:spec}`
+    Argument {
+        name: String,
+        format: FormatSpecifier,
+    },
+    /// `{name, plural, ...}` or `{name, selectordinal, ...}`
+    Plural {
+        name: String,
+        ordinal: bool,
+        offset: u32,
+        cases: Vec<(String, Vec<Part>)>,
+    },
+    /// `{name, select, ...}`
+    Select {
+        name: String,
+        cases: Vec<(String, Vec<Part>)>,
+    },
+    /// `#` inside a plural case: the number, minus the offset
+    Pound,
+}
+
+/// How a message uses a parameter, for the type definitions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ParameterKind<'a> {
+    /// Inserted as text (`{name}`), with the Luau type of its format specifier
+    Value(&'a str),
+    /// Chooses a `select` case
+    Choice,
+    /// Chooses a `plural`/`selectordinal` case
+    Number,
+}
+
+impl<'a> ParameterKind<'a> {
+    /// Luau type of the parameter
+    pub fn luau_type(self) -> &'a str {
+        match self {
+            ParameterKind::Value(luau_type) => luau_type,
+            ParameterKind::Choice => "string",
+            ParameterKind::Number => "number",
+        }
+    }
+}
+
+/// Detect if a translation value uses ICU `plural`, `selectordinal` or `select` arguments
+/// Example: "{count, plural, one {# item} other {# items}}" -> true
+pub fn is_icu_message(text: &str) -> bool {
+    text.match_indices('{').any(|(start, _)| {
+        let rest = &text[start + 1..];
+        let Some((name, rest)) = rest.split_once(',') else {
+            return false;
+        };
+        let Some((kind, _)) = rest.split_once(',') else {
+            return false;
+        };
+        is_argument_name(name.trim())
+            && ["plural", "selectordinal", "select"].contains(&kind.trim())
+    })
+}
+
+/// Parse a message into its parts
+pub fn parse_message(text: &str) -> Result<Vec<Part>> {
+    parse_message_with_formatters(text, &Formatters::new())
+}
+
+/// Parse a message into its parts, with the custom specifiers of `formatters`
+pub fn parse_message_with_formatters(text: &str, formatters: &Formatters) -> Result<Vec<Part>> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+        formatters,
+    };
+    let parts = parser.parse_parts(false, false)?;
+    if parser.pos < parser.chars.len() {
+        bail!("Unmatched '}}' at column {}", parser.pos + 1);
+    }
+    Ok(parts)
+}
+
+/// Parse a value without ICU arguments into text and `{name}` / `{name:spec}`
+/// placeholders, with `{{` and `}}` for literal braces
+///
+/// Unknown format specifiers, invalid names and unbalanced braces are errors;
+/// the specifiers declared in `formatters` are known.
+pub fn parse_plain(text: &str, formatters: &Formatters) -> Result<Vec<Part>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut pos = 0;
+
+    while let Some(&c) = chars.get(pos) {
+        let escaped = chars.get(pos + 1) == Some(&c);
+        match c {
+            '{' | '}' if escaped => {
+                literal.push(c);
+                pos += 2;
+            }
+            '{' => {
+                let column = pos + 1;
+                let Some(length) = chars[pos + 1..].iter().position(|&c| c == '{' || c == '}')
+                else {
+                    bail!(
+                        "Unclosed '{{' at column {} (write '{{{{' for a literal brace)",
+                        column
+                    );
+                };
+                let end = pos + 1 + length;
+                if chars[end] == '{' {
+                    bail!(
+                        "Unclosed '{{' at column {} (write '{{{{' for a literal brace)",
+                        column
+                    );
+                }
+
+                let placeholder: String = chars[pos + 1..end].iter().collect();
+                if placeholder.trim().is_empty() {
+                    bail!(
+                        "Empty placeholder '{{}}' at column {} (write '{{{{}}}}' for literal braces)",
+                        column
+                    );
+                }
+                let (name, format) = format::try_parse_format_specifier(&placeholder, formatters)?;
+                if !is_argument_name(&name) {
+                    bail!(
+                        "Invalid placeholder name '{}' at column {} (use letters, digits and underscores, not starting with a digit)",
+                        name,
+                        column
+                    );
+                }
+
+                if !literal.is_empty() {
+                    parts.push(Part::Text(std::mem::take(&mut literal)));
+                }
+                parts.push(Part::Argument { name, format });
+                pos = end + 1;
+            }
+            '}' => bail!(
+                "Unmatched '}}' at column {} (write '}}}}' for a literal brace)",
+                pos + 1
+            ),
+            _ => {
+                literal.push(c);
+                pos += 1;
+            }
+        }
+    }
+
+    if !literal.is_empty() {
+        parts.push(Part::Text(literal));
+    }
+    Ok(parts)
+}
+
+/// Parse any translation value: an ICU message, or text with plain placeholders
+pub fn parse_value(text: &str, formatters: &Formatters) -> Result<Vec<Part>> {
+    if is_icu_message(text) {
+        parse_message_with_formatters(text, formatters)
+    } else {
+        parse_plain(text, formatters)
+    }
+}
+
+/// Whether a value without ICU arguments writes literal braces as `{{` or `}}`
+pub fn has_escaped_braces(text: &str) -> bool {
+    !is_icu_message(text) && (text.contains("{{") || text.contains("}}"))
+}
+
+/// Parameters used by a message, by name
+///
+/// A parameter used both as text and to pick a case gets the stricter kind.
+pub fn parameters(parts: &[Part]) -> BTreeMap<String, ParameterKind<'_>> {
+    let mut parameters = BTreeMap::new();
+    collect_parameters(parts, &mut parameters);
+    parameters
+}
+
+fn collect_parameters<'a>(parts: &'a [Part], parameters: &mut BTreeMap<String, ParameterKind<'a>>) {
+    for part in parts {
+        let (name, kind, cases) = match part {
+            Part::Argument { name, format } => {
+                (name, ParameterKind::Value(format.luau_type()), None)
+            }
+            Part::Plural { name, cases, .. } => (name, ParameterKind::Number, Some(cases)),
+            Part::Select { name, cases } => (name, ParameterKind::Choice, Some(cases)),
+            Part::Text(_) | Part::Pound => continue,
+        };
+
+        let entry = parameters.entry(name.clone()).or_insert(kind);
+        *entry = (*entry).max(kind);
+
+        for (_, case) in cases.into_iter().flatten() {
+            collect_parameters(case, parameters);
+        }
+    }
+}
+
+/// Whether a message has a `plural` (or, with `ordinal`, a `selectordinal`) argument
+pub fn uses_plural(parts: &[Part], ordinal: bool) -> bool {
+    parts.iter().any(|part| match part {
+        Part::Plural {
+            ordinal: o, cases, ..
+        } => *o == ordinal || cases.iter().any(|(_, case)| uses_plural(case, ordinal)),
+        Part::Select { cases, .. } => cases.iter().any(|(_, case)| uses_plural(case, ordinal)),
+        _ => false,
+    })
+}
+
+/// Whether a message has an argument whose format `matches`
+pub fn uses_format(parts: &[Part], matches: fn(&FormatSpecifier) -> bool) -> bool {
+    parts.iter().any(|part| match part {
+        Part::Argument { format, .. } => matches(format),
+        Part::Plural { cases, .. } | Part::Select { cases, .. } => {
+            cases.iter().any(|(_, case)| uses_format(case, matches))
+        }
+        _ => false,
+    })
+}
+
+/// Compile a message to a Luau expression
+///
+/// The expression reads `params` and `locale`, and expects the `icuPlural` and
+/// `icuSelect` helpers of the generated module to be in scope, as well as its
+/// number and time formatting helpers if it [`uses_format`] them.
+pub fn message_to_luau(parts: &[Part]) -> String {
+    if parts.is_empty() {
+        return "\"\"".to_string();
+    }
+
+    parts
+        .iter()
+        .map(part_to_luau)
+        .collect::<Vec<_>>()
+        .join(" .. ")
+}
+
+fn part_to_luau(part: &Part) -> String {
+    match part {
+        Part::Text(text) => format::luau_string(text),
+        Part::Pound => "n".to_string(),
+        Part::Argument { name, format } => {
+            let value = format!("params.{}", name);
+            if let Some(expression) = format::number_format_expression(format, &value, "locale") {
+                return expression;
+            }
+            match format {
+                FormatSpecifier::Int => format!("tostring(math.floor(tonumber({}) or 0))", value),
+                FormatSpecifier::DateTime(pattern) => format!(
+                    "(if typeof({0}) == \"DateTime\" then {0}:FormatLocalTime({1}, locale) else tostring({0}))",
+                    value,
+                    format::luau_string(pattern)
+                ),
+                FormatSpecifier::Custom(formatter) => format!(
+                    "tostring({})",
+                    format::custom_format_expression(formatter, &value, "locale")
+                ),
+                _ => format!("tostring({})", value),
+            }
+        }
+        Part::Plural {
+            name,
+            ordinal,
+            offset,
+            cases,
+        } => format!(
+            "icuPlural(locale, params.{}, {}, {}, {{ {} }})",
+            name,
+            offset,
+            ordinal,
+            cases_to_luau(cases, "n")
+        ),
+        Part::Select { name, cases } => format!(
+            "icuSelect(params.{}, {{ {} }})",
+            name,
+            cases_to_luau(cases, "")
+        ),
+    }
+}
+
+fn cases_to_luau(cases: &[(String, Vec<Part>)], argument: &str) -> String {
+    cases
+        .iter()
+        .map(|(selector, parts)| {
+            format!(
+                "[{}] = function({}) return {} end",
+                format::luau_string(selector),
+                argument,
+                message_to_luau(parts)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(super) fn is_argument_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+struct Parser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    formatters: &'a Formatters,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(c) => bail!(
+                "Expected '{}' but found '{}' at column {}",
+                expected,
+                c,
+                self.pos + 1
+            ),
+            None => bail!("Expected '{}' but the message ended", expected),
+        }
+    }
+
+    /// Read up to (not including) any of `stops`, trimmed
+    fn read_until(&mut self, stops: &[char]) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| !stops.contains(&c)) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .trim()
+            .to_string()
+    }
+
+    /// Parse text and arguments up to a closing `}` (inside a case) or the end
+    fn parse_parts(&mut self, in_case: bool, in_plural: bool) -> Result<Vec<Part>> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(self.parse_argument(in_plural)?);
+                }
+                '}' if in_case => break,
+                '}' => bail!("Unmatched '}}' at column {}", self.pos + 1),
+                '#' if in_plural => {
+                    if !text.is_empty() {
+                        parts.push(Part::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(Part::Pound);
+                    self.pos += 1;
+                }
+                '\'' => self.parse_quoted(&mut text),
+                _ => {
+                    text.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(Part::Text(text));
+        }
+        Ok(parts)
+    }
+
+    /// Apostrophe quoting: `''` is a literal `'`, and `'{...}'` quotes syntax characters
+    fn parse_quoted(&mut self, text: &mut String) {
+        self.pos += 1;
+        match self.peek() {
+            Some('\'') => {
+                text.push('\'');
+                self.pos += 1;
+            }
+            Some('{') | Some('}') | Some('#') | Some('|') => {
+                while let Some(c) = self.peek() {
+                    self.pos += 1;
+                    if c != '\'' {
+                        text.push(c);
+                    } else if self.peek() == Some('\'') {
+                        text.push('\'');
+                        self.pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+            _ => text.push('\''),
+        }
+    }
+
+    fn parse_argument(&mut self, in_plural: bool) -> Result<Part> {
+        let start = self.pos + 1;
+        self.pos += 1;
+
+        let argument = self.read_until(&[',', '}']);
+        let (name, format) = format::try_parse_format_specifier(&argument, self.formatters)?;
+        if !is_argument_name(&name) {
+            bail!("Invalid argument name '{}' at column {}", name, start);
+        }
+
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Part::Argument { name, format });
+        }
+        self.expect(',')?;
+
+        let kind = self.read_until(&[',', '}']);
+        let part = match kind.as_str() {
+            "plural" | "selectordinal" => {
+                self.expect(',')?;
+                let ordinal = kind == "selectordinal";
+                let offset = self.parse_offset()?;
+                let cases = self.parse_cases(&name, true, in_plural)?;
+                Part::Plural {
+                    name,
+                    ordinal,
+                    offset,
+                    cases,
+                }
+            }
+            "select" => {
+                self.expect(',')?;
+                let cases = self.parse_cases(&name, false, in_plural)?;
+                Part::Select { name, cases }
+            }
+            "number" => {
+                // `{n, number}`, or with the `integer` or `percent` style
+                let format = if self.peek() == Some(',') {
+                    self.pos += 1;
+                    let style = self.read_until(&['}']);
+                    match style.trim() {
+                        "integer" => FormatSpecifier::Int,
+                        "percent" => FormatSpecifier::Percent,
+                        other => bail!(
+                            "Unsupported number style '{}' for '{}' (expected integer or percent)",
+                            other,
+                            name
+                        ),
+                    }
+                } else {
+                    FormatSpecifier::Num
+                };
+                self.expect('}')?;
+                return Ok(Part::Argument { name, format });
+            }
+            "date" | "time" => {
+                // `{d, date}`, or with a style or pattern (`{d, time, short}`)
+                let style = if self.peek() == Some(',') {
+                    self.pos += 1;
+                    Some(self.read_until(&['}']))
+                } else {
+                    None
+                };
+                self.expect('}')?;
+                let format = FormatSpecifier::DateTime(format::date_time_pattern(
+                    &kind,
+                    style.as_deref(),
+                ));
+                return Ok(Part::Argument { name, format });
+            }
+            other => bail!(
+                "Unsupported argument type '{}' for '{}' (expected plural, selectordinal, select, number, date or time)",
+                other,
+                name
+            ),
+        };
+
+        self.expect('}')?;
+        Ok(part)
+    }
+
+    /// `offset:1` at the start of a plural's cases
+    fn parse_offset(&mut self) -> Result<u32> {
+        self.skip_whitespace();
+        let rest: String = self.chars[self.pos..].iter().take(7).collect();
+        if rest != "offset:" {
+            return Ok(0);
+        }
+        self.pos += 7;
+        let digits = self.read_until(&[' ', '\t', '\n', '{', '}']);
+        digits
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid plural offset '{}'", digits))
+    }
+
+    fn parse_cases(
+        &mut self,
+        name: &str,
+        plural: bool,
+        in_plural: bool,
+    ) -> Result<Vec<(String, Vec<Part>)>> {
+        let mut cases: Vec<(String, Vec<Part>)> = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('}') => break,
+                None => bail!("Missing '}}' after the cases of '{}'", name),
+                _ => {}
+            }
+
+            let selector = self.read_until(&['{', '}', ' ', '\t', '\n']);
+            let valid = if plural {
+                PLURAL_CATEGORIES.contains(&selector.as_str())
+                    || selector
+                        .strip_prefix('=')
+                        .is_some_and(|n| n.parse::<f64>().is_ok())
+            } else {
+                is_argument_name(&selector) || selector.parse::<f64>().is_ok()
+            };
+            if !valid {
+                bail!("Invalid case '{}' for '{}'", selector, name);
+            }
+            if cases.iter().any(|(s, _)| *s == selector) {
+                bail!("Duplicate case '{}' for '{}'", selector, name);
+            }
+
+            self.expect('{')?;
+            let parts = self.parse_parts(true, plural || in_plural)?;
+            self.expect('}')?;
+            cases.push((selector, parts));
+        }
+
+        if !cases.iter().any(|(selector, _)| selector == "other") {
+            bail!("Argument '{}' is missing the required 'other' case", name);
+        }
+        Ok(cases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_icu_message() {
+        assert!(is_icu_message(
+            "{count, plural, one {# item} other {# items}}"
+        ));
+        assert!(is_icu_message("Hi {gender,select,male {him} other {them}}"));
+        assert!(!is_icu_message("Hello {name}, you have {count:int} items"));
+        assert!(!is_icu_message("{a, b, c}"));
+    }
+
+    #[test]
+    fn test_parse_plural() {
+        let parts =
+            parse_message("You have {count, plural, =0 {no items} one {# item} other {# items}}.")
+                .unwrap();
+
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], Part::Text("You have ".to_string()));
+        let Part::Plural {
+            name,
+            ordinal,
+            offset,
+            cases,
+        } = &parts[1]
+        else {
+            panic!("expected a plural");
+        };
+        assert_eq!(name, "count");
+        assert!(!ordinal);
+        assert_eq!(*offset, 0);
+        assert_eq!(
+            cases.iter().map(|(s, _)| s.as_str()).collect::<Vec<_>>(),
+            vec!["=0", "one", "other"]
+        );
+        assert_eq!(
+            cases[1].1,
+            vec![Part::Pound, Part::Text(" item".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_parse_nested_and_quoted() {
+        let parts = parse_message(
+            "{host, select, female {{guests, plural, offset:1 one {She invited {guest}} other {She invited # people}}} other {They'' party '{'x'}'}}",
+        )
+        .unwrap();
+
+        let params = parameters(&parts);
+        assert_eq!(params["host"], ParameterKind::Choice);
+        assert_eq!(params["guests"], ParameterKind::Number);
+        assert_eq!(params["guest"], ParameterKind::Value("string | number"));
+
+        let Part::Select { cases, .. } = &parts[0] else {
+            panic!("expected a select");
+        };
+        let Part::Plural { offset, .. } = &cases[0].1[0] else {
+            panic!("expected a nested plural");
+        };
+        assert_eq!(*offset, 1);
+        assert_eq!(cases[1].1, vec![Part::Text("They' party {x}".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |text: &str| parse_message(text).unwrap_err().to_string();
+
+        assert!(error("{count, plural, one {# item}}").contains("required 'other' case"));
+        assert!(error("{count, plural, single {x} other {y}}").contains("Invalid case 'single'"));
+        assert!(error("{count, plural, one {x} other {y}").contains("Missing '}'"));
+        assert!(error("{count, list, other {y}}").contains("Unsupported argument type 'list'"));
+        assert!(error("Done}").contains("Unmatched '}'"));
+    }
+
+    #[test]
+    fn test_message_to_luau() {
+        let parts =
+            parse_message("{n, selectordinal, one {#st} other {#th}} by {name:int}").unwrap();
+
+        assert_eq!(
+            message_to_luau(&parts),
+            "icuPlural(locale, params.n, 0, true, { [\"one\"] = function(n) return n .. \"st\" end, \
+             [\"other\"] = function(n) return n .. \"th\" end }) .. \" by \" .. \
+             tostring(math.floor(tonumber(params.name) or 0))"
+        );
+    }
+
+    #[test]
+    fn test_number_arguments() {
+        let parts = parse_message(
+            "{n, plural, one {# win, {rate, number, percent}} other {{gems:compact}}}",
+        )
+        .unwrap();
+        assert!(uses_format(&parts, FormatSpecifier::is_locale_aware));
+        assert!(message_to_luau(&parts).contains("formatPercent(locale, params.rate)"));
+        assert!(message_to_luau(&parts).contains("formatCompact(locale, params.gems)"));
+
+        let parts = parse_message("{n, select, other {{n, number, integer}}}").unwrap();
+        assert!(!uses_format(&parts, FormatSpecifier::is_locale_aware));
+
+        let error = parse_message("{n, select, other {{n, number, currency}}}")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Unsupported number style 'currency'"));
+    }
+
+    #[test]
+    fn test_parse_plain() {
+        assert_eq!(
+            parse_plain("Use {{x}} or {{{count:int}}}, don't", &Formatters::new()).unwrap(),
+            vec![
+                Part::Text("Use {x} or {".to_string()),
+                Part::Argument {
+                    name: "count".to_string(),
+                    format: FormatSpecifier::Int
+                },
+                Part::Text("}, don't".to_string()),
+            ]
+        );
+
+        let error = |text: &str| {
+            parse_plain(text, &Formatters::new())
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("Open {menu"),
+            "Unclosed '{' at column 6 (write '{{' for a literal brace)"
+        );
+        assert!(error("Open {a {b}").starts_with("Unclosed '{' at column 6"));
+        assert!(error("Done }").starts_with("Unmatched '}' at column 6"));
+        assert!(error("Empty {}").starts_with("Empty placeholder '{}'"));
+        assert!(error("{1st}").starts_with("Invalid placeholder name '1st'"));
+        assert!(error("{n:integer}").contains("(did you mean 'int'?)"));
+
+        assert!(has_escaped_braces("{{x}}"));
+        assert!(!has_escaped_braces("{n, select, other {{name}}}"));
+        assert!(parse_message("{n, select, other {{n:integer}}}").is_err());
+    }
+
+    #[test]
+    fn test_date_time_arguments() {
+        let parts = parse_message(
+            "{n, plural, one {Ends {d, date, long}} other {At {d, time}, {left:duration}}}",
+        )
+        .unwrap();
+        let code = message_to_luau(&parts);
+        assert!(code.contains(
+            "(if typeof(params.d) == \"DateTime\" then params.d:FormatLocalTime(\"LL\", locale) else tostring(params.d))"
+        ));
+        assert!(code.contains("params.d:FormatLocalTime(\"LT\", locale)"));
+        assert!(code.contains("formatDuration(locale, params.left)"));
+        assert!(uses_format(&parts, |f| *f == FormatSpecifier::Duration));
+        assert!(!uses_format(&parts, FormatSpecifier::is_locale_aware));
+        assert_eq!(parameters(&parts)["d"], ParameterKind::Value("DateTime"));
+    }
+}
@@ -0,0 +1,414 @@
+//! Linked messages
+//!
+//! A value can reuse another key's text with `@:key`, or `@:(key)` when the key
+//! runs into other text:
+//!
+//! ```text
+//! "shop.buy": "Buy 100 @:common.currencyName"
+//! "shop.title": "@:(common.gameName)'s shop"
+//! ```
+//!
+//! Links are replaced at build time, in each locale, with the linked key's
+//! value in that locale (or its fallbacks, then the base locale), so a shared
+//! string is translated once and every value linking to it follows.
+
+use super::locales;
+use crate::parser::Translation;
+use anyhow::{bail, Context, Result};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// A `@:key` link inside a value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// Linked key
+    pub key: String,
+    /// Byte range of the whole link (`@:key`) in the value
+    pub range: std::ops::Range<usize>,
+}
+
+/// Find the links of a value
+/// Example: "Buy @:common.gems." -> [common.gems]
+///
+/// A bare key is made of letters, digits and underscores separated by dots, so a
+/// trailing period ends the sentence, not the key. `@:(key)` takes any key,
+/// including plural forms like `@:(items(one))`.
+pub fn find_links(value: &str) -> Vec<Link> {
+    let mut links = Vec::new();
+    let mut search = 0;
+
+    while let Some(offset) = value[search..].find("@:") {
+        let start = search + offset;
+        let rest = &value[start + 2..];
+        let (key, length) = if rest.starts_with('(') {
+            parenthesized_key(rest)
+        } else {
+            bare_key(rest)
+        };
+
+        search = start + 2 + length;
+        if !key.is_empty() {
+            links.push(Link {
+                key: key.to_string(),
+                range: start..search,
+            });
+        }
+    }
+
+    links
+}
+
+/// Whether a value links to other keys
+pub fn has_links(value: &str) -> bool {
+    !find_links(value).is_empty()
+}
+
+/// `key.path` at the start of `text`, and its length
+fn bare_key(text: &str) -> (&str, usize) {
+    let is_key_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut end = 0;
+    for segment in text.split('.') {
+        let length = segment.find(|c| !is_key_char(c)).unwrap_or(segment.len());
+        if length == 0 {
+            break;
+        }
+        end += if end == 0 { length } else { length + 1 };
+        if length < segment.len() {
+            break;
+        }
+    }
+    (&text[..end], end)
+}
+
+/// `(key)` at the start of `text`, with balanced parentheses, and its length
+fn parenthesized_key(text: &str) -> (&str, usize) {
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return (text[1..index].trim(), index + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    // Unclosed: not a link
+    ("", 0)
+}
+
+/// Replace the links of every value with the linked values
+///
+/// A link is resolved in the locale of the value linking to it: the linked key's
+/// value in that locale, its `fallbacks`, then `base_locale`. Links inside
+/// linked values are followed too. Links to keys no locale in the chain has, and
+/// links that lead back to themselves, are errors.
+pub fn resolve_links(
+    translations: &[Translation],
+    base_locale: &str,
+    fallbacks: &BTreeMap<String, Vec<String>>,
+) -> Result<Vec<Translation>> {
+    if !translations.iter().any(|t| has_links(&t.value)) {
+        return Ok(translations.to_vec());
+    }
+
+    let mut resolver = Resolver::new(translations, base_locale, fallbacks);
+    resolver.resolve_all(translations)
+}
+
+/// Replace the links of the `overlay` values, as if they were merged into
+/// `translations`
+///
+/// Overlay values replace the values of `translations` with the same key and
+/// locale, so links to those keys get the overlay's text. Only the overlay is
+/// returned, which saves resolving every value again for each runtime variant.
+pub fn resolve_overlay_links(
+    translations: &[Translation],
+    overlay: &[Translation],
+    base_locale: &str,
+    fallbacks: &BTreeMap<String, Vec<String>>,
+) -> Result<Vec<Translation>> {
+    if !overlay.iter().any(|t| has_links(&t.value)) {
+        return Ok(overlay.to_vec());
+    }
+
+    let mut resolver = Resolver::new(translations, base_locale, fallbacks);
+    resolver.values.extend(
+        overlay
+            .iter()
+            .map(|t| ((t.locale.as_str(), t.key.as_str()), t.value.as_str())),
+    );
+    resolver.resolve_all(overlay)
+}
+
+/// Check the links of every value, returning `(translation, error)` for each
+/// value whose links can't be resolved
+pub fn link_errors<'a>(
+    translations: &'a [Translation],
+    base_locale: &str,
+    fallbacks: &BTreeMap<String, Vec<String>>,
+) -> Vec<(&'a Translation, String)> {
+    let mut resolver = Resolver::new(translations, base_locale, fallbacks);
+    translations
+        .iter()
+        .filter(|t| has_links(&t.value))
+        .filter_map(|t| {
+            resolver
+                .resolve(&t.key, &t.locale)
+                .err()
+                .map(|e| (t, e.to_string()))
+        })
+        .collect()
+}
+
+/// Keys other values link to
+pub fn linked_keys(translations: &[Translation]) -> HashSet<String> {
+    translations
+        .iter()
+        .flat_map(|t| find_links(&t.value))
+        .map(|link| link.key)
+        .collect()
+}
+
+/// Resolves links, remembering the values already resolved
+struct Resolver<'a> {
+    values: HashMap<(&'a str, &'a str), &'a str>,
+    base_locale: &'a str,
+    fallbacks: &'a BTreeMap<String, Vec<String>>,
+    resolved: HashMap<(String, String), String>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(
+        translations: &'a [Translation],
+        base_locale: &'a str,
+        fallbacks: &'a BTreeMap<String, Vec<String>>,
+    ) -> Self {
+        Self {
+            values: translations
+                .iter()
+                .map(|t| ((t.locale.as_str(), t.key.as_str()), t.value.as_str()))
+                .collect(),
+            base_locale,
+            fallbacks,
+            resolved: HashMap::new(),
+        }
+    }
+
+    /// `translations` with their links replaced
+    fn resolve_all(&mut self, translations: &[Translation]) -> Result<Vec<Translation>> {
+        translations
+            .iter()
+            .map(|translation| {
+                let value = self
+                    .resolve(&translation.key, &translation.locale)
+                    .with_context(|| {
+                        format!(
+                            "Broken link in key '{}' ({})",
+                            translation.key, translation.locale
+                        )
+                    })?;
+                Ok(Translation {
+                    value,
+                    ..translation.clone()
+                })
+            })
+            .collect()
+    }
+
+    /// Resolved value of `key` in `locale`
+    fn resolve(&mut self, key: &str, locale: &str) -> Result<String> {
+        self.resolve_key(key, locale, &mut Vec::new())
+    }
+
+    fn resolve_key(&mut self, key: &str, locale: &str, path: &mut Vec<String>) -> Result<String> {
+        if let Some(value) = self.resolved.get(&(locale.to_string(), key.to_string())) {
+            return Ok(value.clone());
+        }
+
+        let chain = locales::fallback_chain(locale, self.fallbacks, self.base_locale);
+        let Some(value) = chain
+            .iter()
+            .find_map(|l| self.values.get(&(*l, key)).copied())
+        else {
+            bail!(
+                "Link '@:{}' points to a key that doesn't exist in '{}' or its fallbacks",
+                key,
+                locale
+            );
+        };
+
+        path.push(key.to_string());
+        let mut text = String::with_capacity(value.len());
+        let mut last = 0;
+        for link in find_links(value) {
+            if path.contains(&link.key) {
+                path.push(link.key.clone());
+                bail!(
+                    "Circular link in '{}': {}",
+                    locale,
+                    path.iter()
+                        .map(|k| format!("@:{}", k))
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                );
+            }
+            text.push_str(&value[last..link.range.start]);
+            text.push_str(&self.resolve_key(&link.key, locale, path)?);
+            last = link.range.end;
+        }
+        text.push_str(&value[last..]);
+        path.pop();
+
+        self.resolved
+            .insert((locale.to_string(), key.to_string()), text.clone());
+        Ok(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(key: &str, value: &str, locale: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    fn keys(value: &str) -> Vec<String> {
+        find_links(value).into_iter().map(|l| l.key).collect()
+    }
+
+    #[test]
+    fn test_find_links() {
+        assert_eq!(keys("Buy @:common.gems."), vec!["common.gems"]);
+        assert_eq!(keys("@:(common.game)'s shop"), vec!["common.game"]);
+        assert_eq!(
+            keys("@:tips.0 and @:(items(one))"),
+            vec!["tips.0", "items(one)"]
+        );
+        assert_eq!(keys("user@: or @:( or @:"), Vec::<String>::new());
+
+        let links = find_links("Buy @:common.gems!");
+        assert_eq!(links[0].range, 4..17);
+        assert!(has_links("@:x"));
+        assert!(!has_links("Email me @ 5:00"));
+    }
+
+    #[test]
+    fn test_resolve_links() {
+        let translations = vec![
+            translation("common.gems", "Gems", "en"),
+            translation("common.game", "Obby", "en"),
+            translation("shop.buy", "Buy {count} @:common.gems", "en"),
+            translation("shop.title", "@:(common.game)'s @:shop.name", "en"),
+            translation("shop.name", "shop", "en"),
+            translation("common.gems", "Permata", "id"),
+            translation("shop.buy", "Beli {count} @:common.gems", "id"),
+            translation("shop.title", "Toko @:common.game", "id"),
+        ];
+
+        let resolved = resolve_links(&translations, "en", &BTreeMap::new()).unwrap();
+        let value = |key: &str, locale: &str| {
+            resolved
+                .iter()
+                .find(|t| t.key == key && t.locale == locale)
+                .map(|t| t.value.as_str())
+                .unwrap()
+        };
+
+        assert_eq!(value("shop.buy", "en"), "Buy {count} Gems");
+        assert_eq!(value("shop.title", "en"), "Obby's shop");
+        assert_eq!(value("shop.buy", "id"), "Beli {count} Permata");
+        // Keys a locale lacks come from the base locale
+        assert_eq!(value("shop.title", "id"), "Toko Obby");
+    }
+
+    #[test]
+    fn test_resolve_links_fallbacks() {
+        let translations = vec![
+            translation("common.gems", "Gems", "en"),
+            translation("common.gems", "Gemas", "es"),
+            translation("shop.buy", "Comprar @:common.gems", "pt"),
+        ];
+        let fallbacks = BTreeMap::from([("pt".to_string(), vec!["es".to_string()])]);
+
+        let resolved = resolve_links(&translations, "en", &fallbacks).unwrap();
+        assert_eq!(resolved[2].value, "Comprar Gemas");
+    }
+
+    #[test]
+    fn test_resolve_overlay_links() {
+        let translations = vec![
+            translation("common.event", "Sale", "en"),
+            translation("shop.title", "@:common.event shop", "en"),
+            translation("shop.buy", "Buy", "en"),
+        ];
+        let overlay = vec![
+            translation("common.event", "Halloween", "en"),
+            translation("shop.buy", "Buy for @:common.event", "en"),
+            translation("shop.buy", "Beli untuk @:common.event", "id"),
+        ];
+
+        let resolved =
+            resolve_overlay_links(&translations, &overlay, "en", &BTreeMap::new()).unwrap();
+        let values: Vec<&str> = resolved.iter().map(|t| t.value.as_str()).collect();
+        // Links see the overlay's values, and only the overlay is returned
+        assert_eq!(
+            values,
+            vec!["Halloween", "Buy for Halloween", "Beli untuk Halloween"]
+        );
+    }
+
+    #[test]
+    fn test_link_errors() {
+        let translations = vec![
+            translation("a", "@:b", "en"),
+            translation("b", "x @:c", "en"),
+            translation("c", "@:a", "en"),
+            translation("d", "@:d", "en"),
+            translation("e", "Buy @:common.gem", "en"),
+            translation("f", "@:e", "en"),
+        ];
+
+        let errors: Vec<String> = link_errors(&translations, "en", &BTreeMap::new())
+            .into_iter()
+            .map(|(t, e)| format!("{}: {}", t.key, e))
+            .collect();
+
+        assert_eq!(
+            errors,
+            vec![
+                "a: Circular link in 'en': @:a -> @:b -> @:c -> @:a",
+                "b: Circular link in 'en': @:b -> @:c -> @:a -> @:b",
+                "c: Circular link in 'en': @:c -> @:a -> @:b -> @:c",
+                "d: Circular link in 'en': @:d -> @:d",
+                "e: Link '@:common.gem' points to a key that doesn't exist in 'en' or its fallbacks",
+                "f: Link '@:common.gem' points to a key that doesn't exist in 'en' or its fallbacks",
+            ]
+        );
+        let error = resolve_links(&translations, "en", &BTreeMap::new()).unwrap_err();
+        assert_eq!(error.to_string(), "Broken link in key 'a' (en)");
+    }
+
+    #[test]
+    fn test_linked_keys() {
+        let translations = vec![
+            translation("shop.buy", "Buy @:common.gems", "en"),
+            translation("shop.sell", "Sell @:common.gems for @:common.coins", "id"),
+        ];
+
+        let keys = linked_keys(&translations);
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains("common.gems"));
+        assert!(keys.contains("common.coins"));
+    }
+}
@@ -0,0 +1,57 @@
+//! List translations
+//!
+//! Arrays in translation files are flattened to indexed keys (`tips.0`, `tips.1`);
+//! the generators group the items back into a single list accessor.
+
+/// Detect if a translation key is a list item
+/// Example: "loading.tips.0" -> true
+pub fn is_list_item_key(key: &str) -> bool {
+    list_index(key).is_some()
+}
+
+/// Extract the list key from a list item key
+/// Example: "loading.tips.0" -> "loading.tips"
+pub fn extract_list_key(key: &str) -> String {
+    match key.rfind('.') {
+        Some(pos) if is_list_item_key(key) => key[..pos].to_string(),
+        _ => key.to_string(),
+    }
+}
+
+/// Index of a list item key
+/// Example: "loading.tips.2" -> Some(2)
+pub fn list_index(key: &str) -> Option<usize> {
+    let (_, last) = key.rsplit_once('.')?;
+    if last.is_empty() || !last.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    last.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_list_item_key() {
+        assert!(is_list_item_key("tips.0"));
+        assert!(is_list_item_key("loading.tips.12"));
+        assert!(!is_list_item_key("tips"));
+        assert!(!is_list_item_key("0"));
+        assert!(!is_list_item_key("tips.first"));
+        assert!(!is_list_item_key("items(one)"));
+    }
+
+    #[test]
+    fn test_extract_list_key() {
+        assert_eq!(extract_list_key("tips.0"), "tips");
+        assert_eq!(extract_list_key("loading.tips.12"), "loading.tips");
+        assert_eq!(extract_list_key("ui.title"), "ui.title");
+    }
+
+    #[test]
+    fn test_list_index() {
+        assert_eq!(list_index("tips.10"), Some(10));
+        assert_eq!(list_index("tips.x"), None);
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 /// Roblox supported locales
 /// Based on: <https://create.roblox.com/docs/production/localization/language-codes>
 /// Roblox supported locale information
@@ -106,6 +108,24 @@ pub fn is_roblox_locale(code: &str) -> bool {
         .any(|locale| locale.code == code)
 }
 
+/// Locales tried for `locale`, in order: the locale itself, its configured
+/// fallbacks, then the base locale
+/// Example: "pt" with `fallbacks: { pt: [es] }` and base "en" -> ["pt", "es", "en"]
+pub fn fallback_chain<'a>(
+    locale: &'a str,
+    fallbacks: &'a BTreeMap<String, Vec<String>>,
+    base_locale: &'a str,
+) -> Vec<&'a str> {
+    let mut chain = vec![locale];
+    let configured = fallbacks.get(locale).into_iter().flatten();
+    for fallback in configured.map(String::as_str).chain([base_locale]) {
+        if !chain.contains(&fallback) {
+            chain.push(fallback);
+        }
+    }
+    chain
+}
+
 /// Get all supported locale codes
 pub fn get_supported_locale_codes() -> Vec<&'static str> {
     get_roblox_locales()
@@ -132,6 +152,25 @@ mod tests {
         assert!(!is_roblox_locale("xx"));
     }
 
+    #[test]
+    fn test_fallback_chain() {
+        let fallbacks = BTreeMap::from([
+            ("pt".to_string(), vec!["es".to_string(), "en".to_string()]),
+            ("zh-tw".to_string(), vec!["zh-cn".to_string()]),
+        ]);
+
+        assert_eq!(
+            fallback_chain("pt", &fallbacks, "en"),
+            vec!["pt", "es", "en"]
+        );
+        assert_eq!(
+            fallback_chain("zh-tw", &fallbacks, "en"),
+            vec!["zh-tw", "zh-cn", "en"]
+        );
+        assert_eq!(fallback_chain("id", &fallbacks, "en"), vec!["id", "en"]);
+        assert_eq!(fallback_chain("en", &fallbacks, "en"), vec!["en"]);
+    }
+
     #[test]
     fn test_get_supported_locale_codes() {
         let codes = get_supported_locale_codes();
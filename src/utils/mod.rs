@@ -1,10 +1,17 @@
 //! Utility functions
 //!
 //! This module provides utility functions for flattening/unflattening JSON,
-//! pluralization, locale handling, and validation.
+//! pluralization, lists, select cases, ICU messages, linked messages, locale
+//! handling, number and time formatting, and validation.
 
 pub mod flatten;
 pub mod format;
+pub mod icu;
+pub mod links;
+pub mod lists;
 pub mod locales;
+pub mod numbers;
 pub mod plurals;
+pub mod selects;
+pub mod time_units;
 pub mod validation;
@@ -0,0 +1,252 @@
+//! CLDR number formatting data
+//! Based on Unicode CLDR: <https://cldr.unicode.org/translation/number-currency-formats>
+//!
+//! Separators, percent patterns and short compact suffixes for every Roblox
+//! locale. The generated module formats `num`, `fixed`, `compact`, `percent`
+//! and `currency` parameters with the table of the player's locale.
+
+/// Number formatting symbols of a locale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberSymbols {
+    /// Language (`de`), or full locale code when it differs from its language (`zh-tw`)
+    pub locale: &'static str,
+    pub decimal: &'static str,
+    pub group: &'static str,
+    /// Integer digits needed before grouping starts, minus three (Spanish and
+    /// Polish write `1234` but `12 345`)
+    pub min_grouping: usize,
+    /// Percent pattern, `#` being the number (`#%`, `# %`, `%#`)
+    pub percent: &'static str,
+    /// Short compact suffixes by power of ten, ascending (`(3, "K")` is 1.2K)
+    pub compact: &'static [(u32, &'static str)],
+}
+
+/// CLDR number symbols for every Roblox locale
+pub const NUMBER_SYMBOLS: &[NumberSymbols] = &[
+    NumberSymbols {
+        locale: "de",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        percent: "#\u{a0}%",
+        compact: &[(6, "\u{a0}Mio."), (9, "\u{a0}Mrd."), (12, "\u{a0}Bio.")],
+    },
+    NumberSymbols {
+        locale: "en",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[(3, "K"), (6, "M"), (9, "B"), (12, "T")],
+    },
+    NumberSymbols {
+        locale: "es",
+        decimal: ",",
+        group: ".",
+        min_grouping: 2,
+        percent: "#\u{a0}%",
+        // Thousands of millions are written as such ("1000 M")
+        compact: &[(3, "\u{a0}mil"), (6, "\u{a0}M"), (12, "\u{a0}B")],
+    },
+    NumberSymbols {
+        locale: "fr",
+        decimal: ",",
+        group: "\u{202f}",
+        min_grouping: 1,
+        percent: "#\u{202f}%",
+        compact: &[
+            (3, "\u{a0}k"),
+            (6, "\u{a0}M"),
+            (9, "\u{a0}Md"),
+            (12, "\u{a0}Bn"),
+        ],
+    },
+    NumberSymbols {
+        locale: "id",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[
+            (3, "\u{a0}rb"),
+            (6, "\u{a0}jt"),
+            (9, "\u{a0}M"),
+            (12, "\u{a0}T"),
+        ],
+    },
+    NumberSymbols {
+        locale: "it",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[(6, "\u{a0}Mln"), (9, "\u{a0}Mrd"), (12, "\u{a0}Bln")],
+    },
+    NumberSymbols {
+        locale: "ja",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[(4, "万"), (8, "億"), (12, "兆")],
+    },
+    NumberSymbols {
+        locale: "ko",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[(3, "천"), (4, "만"), (8, "억"), (12, "조")],
+    },
+    NumberSymbols {
+        locale: "pl",
+        decimal: ",",
+        group: "\u{a0}",
+        min_grouping: 2,
+        percent: "#%",
+        compact: &[
+            (3, "\u{a0}tys."),
+            (6, "\u{a0}mln"),
+            (9, "\u{a0}mld"),
+            (12, "\u{a0}bln"),
+        ],
+    },
+    NumberSymbols {
+        locale: "pt",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[
+            (3, "\u{a0}mil"),
+            (6, "\u{a0}mi"),
+            (9, "\u{a0}bi"),
+            (12, "\u{a0}tri"),
+        ],
+    },
+    NumberSymbols {
+        locale: "ru",
+        decimal: ",",
+        group: "\u{a0}",
+        min_grouping: 1,
+        percent: "#\u{a0}%",
+        compact: &[
+            (3, "\u{a0}тыс."),
+            (6, "\u{a0}млн"),
+            (9, "\u{a0}млрд"),
+            (12, "\u{a0}трлн"),
+        ],
+    },
+    NumberSymbols {
+        locale: "th",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[(3, "K"), (6, "M"), (9, "B"), (12, "T")],
+    },
+    NumberSymbols {
+        locale: "tr",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        percent: "%#",
+        compact: &[
+            (3, "\u{a0}B"),
+            (6, "\u{a0}Mn"),
+            (9, "\u{a0}Mr"),
+            (12, "\u{a0}Tn"),
+        ],
+    },
+    NumberSymbols {
+        locale: "uk",
+        decimal: ",",
+        group: "\u{a0}",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[
+            (3, "\u{a0}тис."),
+            (6, "\u{a0}млн"),
+            (9, "\u{a0}млрд"),
+            (12, "\u{a0}трлн"),
+        ],
+    },
+    NumberSymbols {
+        locale: "vi",
+        decimal: ",",
+        group: ".",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[
+            (3, "\u{a0}N"),
+            (6, "\u{a0}Tr"),
+            (9, "\u{a0}T"),
+            (12, "\u{a0}NT"),
+        ],
+    },
+    NumberSymbols {
+        locale: "zh",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[(4, "万"), (8, "亿"), (12, "万亿")],
+    },
+    NumberSymbols {
+        locale: "zh-tw",
+        decimal: ".",
+        group: ",",
+        min_grouping: 1,
+        percent: "#%",
+        compact: &[(4, "萬"), (8, "億"), (12, "兆")],
+    },
+];
+
+/// Symbols for a locale code, matching the full code first, then the language
+/// (`zh-tw` → `zh-tw`, `zh-cn` → `zh`)
+pub fn symbols_for_locale(locale: &str) -> Option<&'static NumberSymbols> {
+    let locale = locale.to_lowercase().replace('_', "-");
+    let language = locale.split('-').next().unwrap_or(&locale);
+
+    NUMBER_SYMBOLS
+        .iter()
+        .find(|s| s.locale == locale)
+        .or_else(|| NUMBER_SYMBOLS.iter().find(|s| s.locale == language))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::locales;
+
+    #[test]
+    fn test_symbols_for_locale() {
+        assert_eq!(symbols_for_locale("de").unwrap().decimal, ",");
+        assert_eq!(symbols_for_locale("pt-BR").unwrap().locale, "pt");
+        assert_eq!(symbols_for_locale("zh-cn").unwrap().compact[0], (4, "万"));
+        assert_eq!(symbols_for_locale("zh_TW").unwrap().compact[0], (4, "萬"));
+        assert!(symbols_for_locale("xx").is_none());
+    }
+
+    #[test]
+    fn test_every_roblox_locale_has_symbols() {
+        for locale in locales::get_supported_locale_codes() {
+            assert!(
+                symbols_for_locale(locale).is_some(),
+                "no number symbols for {}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn test_compact_suffixes_ascending() {
+        for symbols in NUMBER_SYMBOLS {
+            assert!(
+                symbols.compact.windows(2).all(|w| w[0].0 < w[1].0),
+                "compact suffixes of {} are not ascending",
+                symbols.locale
+            );
+        }
+    }
+}
@@ -1,13 +1,168 @@
-/// CLDR Plural Rules Implementation
-/// Based on Unicode CLDR: <https://cldr.unicode.org/index/cldr-spec/plural-rules>
+//! CLDR Plural Rules Implementation
+//! Based on Unicode CLDR: <https://cldr.unicode.org/index/cldr-spec/plural-rules>
+//!
+//! Rules are kept in CLDR syntax (`i % 10 = 2..4 and v = 0`) and compiled to Luau
+//! for the generated module. Conditions on the compact decimal exponent (`e`)
+//! are left out, since counts are never written in compact form.
+
+/// Plural categories, in CLDR order
+pub const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Prefix marking ordinal forms: `rank(ordinal:one)` is used for 1st, 21st, ...
+pub const ORDINAL_PREFIX: &str = "ordinal:";
+
+/// Cardinal and ordinal rules for a language
+///
+/// Each rule is a `(category, condition)` pair checked in order; a number that
+/// matches none is `other`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PluralRules {
+    pub language: &'static str,
+    pub cardinal: &'static [(&'static str, &'static str)],
+    pub ordinal: &'static [(&'static str, &'static str)],
+}
+
+/// `many` for exact millions (French, Spanish, Italian, Portuguese)
+const MILLIONS: &str = "i != 0 and i % 1000000 = 0 and v = 0";
+
+/// CLDR rules for every Roblox locale (plus Arabic)
+pub const PLURAL_RULES: &[PluralRules] = &[
+    PluralRules {
+        language: "ar",
+        cardinal: &[
+            ("zero", "n = 0"),
+            ("one", "n = 1"),
+            ("two", "n = 2"),
+            ("few", "n % 100 = 3..10"),
+            ("many", "n % 100 = 11..99"),
+        ],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "de",
+        cardinal: &[("one", "i = 1 and v = 0")],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "en",
+        cardinal: &[("one", "i = 1 and v = 0")],
+        ordinal: &[
+            ("one", "n % 10 = 1 and n % 100 != 11"),
+            ("two", "n % 10 = 2 and n % 100 != 12"),
+            ("few", "n % 10 = 3 and n % 100 != 13"),
+        ],
+    },
+    PluralRules {
+        language: "es",
+        cardinal: &[("one", "n = 1"), ("many", MILLIONS)],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "fr",
+        cardinal: &[("one", "i = 0,1"), ("many", MILLIONS)],
+        ordinal: &[("one", "n = 1")],
+    },
+    PluralRules {
+        language: "id",
+        cardinal: &[],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "it",
+        cardinal: &[("one", "i = 1 and v = 0"), ("many", MILLIONS)],
+        ordinal: &[("many", "n = 11,8,80,800")],
+    },
+    PluralRules {
+        language: "ja",
+        cardinal: &[],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "ko",
+        cardinal: &[],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "pl",
+        cardinal: &[
+            ("one", "i = 1 and v = 0"),
+            ("few", "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+            (
+                "many",
+                "v = 0 and i != 1 and i % 10 = 0..1 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 12..14",
+            ),
+        ],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "pt",
+        cardinal: &[("one", "i = 0..1"), ("many", MILLIONS)],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "ru",
+        cardinal: &[
+            ("one", "v = 0 and i % 10 = 1 and i % 100 != 11"),
+            ("few", "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+            (
+                "many",
+                "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14",
+            ),
+        ],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "th",
+        cardinal: &[],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "tr",
+        cardinal: &[("one", "n = 1")],
+        ordinal: &[],
+    },
+    PluralRules {
+        language: "uk",
+        cardinal: &[
+            ("one", "v = 0 and i % 10 = 1 and i % 100 != 11"),
+            ("few", "v = 0 and i % 10 = 2..4 and i % 100 != 12..14"),
+            (
+                "many",
+                "v = 0 and i % 10 = 0 or v = 0 and i % 10 = 5..9 or v = 0 and i % 100 = 11..14",
+            ),
+        ],
+        ordinal: &[("few", "n % 10 = 3 and n % 100 != 13")],
+    },
+    PluralRules {
+        language: "vi",
+        cardinal: &[],
+        ordinal: &[("one", "n = 1")],
+    },
+    PluralRules {
+        language: "zh",
+        cardinal: &[],
+        ordinal: &[],
+    },
+];
+
 /// Detect if a translation key is a plural form
+/// Example: "items(one)" or "rank(ordinal:one)"
 pub fn is_plural_key(key: &str) -> bool {
-    key.ends_with("(zero)")
-        || key.ends_with("(one)")
-        || key.ends_with("(two)")
-        || key.ends_with("(few)")
-        || key.ends_with("(many)")
-        || key.ends_with("(other)")
+    plural_category_of(key).is_some()
+}
+
+/// Detect if a translation key is an ordinal plural form
+/// Example: "rank(ordinal:two)"
+pub fn is_ordinal_key(key: &str) -> bool {
+    key.contains(&format!("({}", ORDINAL_PREFIX)) && is_plural_key(key)
+}
+
+/// The plural category of a plural key, without the ordinal prefix
+/// Example: "rank(ordinal:two)" -> "two"
+pub fn plural_category_of(key: &str) -> Option<&str> {
+    let form = key.strip_suffix(')')?.rsplit_once('(')?.1;
+    let category = form.strip_prefix(ORDINAL_PREFIX).unwrap_or(form);
+    PLURAL_CATEGORIES.contains(&category).then_some(category)
 }
 
 /// Extract base key from plural key
@@ -20,6 +175,251 @@ pub fn extract_base_key(key: &str) -> String {
     }
 }
 
+/// Rules for a locale code, matching on the language (`zh-cn` → `zh`)
+pub fn rules_for_locale(locale: &str) -> Option<&'static PluralRules> {
+    let language = locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(locale)
+        .to_lowercase();
+    PLURAL_RULES.iter().find(|r| r.language == language)
+}
+
+/// Categories a locale's translations must provide for a plural key
+///
+/// Always includes `other`. The `many` form for exact millions (French, Spanish,
+/// Italian, Portuguese) is optional, since it falls back to `other`.
+pub fn required_categories(rules: &PluralRules, ordinal: bool) -> Vec<&'static str> {
+    let rules = if ordinal {
+        rules.ordinal
+    } else {
+        rules.cardinal
+    };
+
+    let mut categories: Vec<&'static str> = rules
+        .iter()
+        .filter(|(_, condition)| *condition != MILLIONS)
+        .map(|(category, _)| *category)
+        .chain(std::iter::once("other"))
+        .collect();
+    categories.sort_by_key(|c| PLURAL_CATEGORIES.iter().position(|p| p == c));
+    categories.dedup();
+    categories
+}
+
+/// Plural category of a number written in decimal (`"1"`, `"1.50"`, `"-3"`)
+///
+/// Visible decimals matter: in English `"1"` is `one` but `"1.0"` is `other`.
+/// Unknown locales and unparsable numbers are `other`.
+#[allow(dead_code)] // Public API for library users
+pub fn plural_category(locale: &str, number: &str, ordinal: bool) -> &'static str {
+    let (Some(rules), Some(operands)) = (rules_for_locale(locale), Operands::parse(number)) else {
+        return "other";
+    };
+
+    let rules = if ordinal {
+        rules.ordinal
+    } else {
+        rules.cardinal
+    };
+    rules
+        .iter()
+        .find(|(_, condition)| {
+            parse_condition(condition)
+                .iter()
+                .any(|and| and.iter().all(|r| r.matches(&operands)))
+        })
+        .map(|(category, _)| *category)
+        .unwrap_or("other")
+}
+
+/// Compile rules to the body of a Luau function taking `(n, i, v, w, f, t)`
+pub fn rules_to_luau(rules: &[(&str, &str)], indent: &str) -> String {
+    let mut code = String::new();
+    for (category, condition) in rules {
+        let expression = parse_condition(condition)
+            .iter()
+            .map(|and| {
+                and.iter()
+                    .map(Relation::to_luau)
+                    .collect::<Vec<_>>()
+                    .join(" and ")
+            })
+            .collect::<Vec<_>>()
+            .join(" or ");
+        code.push_str(&format!("{}if {} then\n", indent, expression));
+        code.push_str(&format!("{}    return \"{}\"\n", indent, category));
+        code.push_str(&format!("{}end\n", indent));
+    }
+    code.push_str(&format!("{}return \"other\"\n", indent));
+    code
+}
+
+/// CLDR plural operands of a decimal number
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Operands {
+    /// Absolute value
+    n: f64,
+    /// Integer digits
+    i: f64,
+    /// Number of visible fraction digits, with and without trailing zeros
+    v: f64,
+    w: f64,
+    /// Visible fraction digits, with and without trailing zeros
+    f: f64,
+    t: f64,
+}
+
+impl Operands {
+    fn parse(number: &str) -> Option<Self> {
+        let number = number.trim().trim_start_matches('-');
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if integer.is_empty()
+            || !(integer.chars().chain(fraction.chars())).all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let trimmed = fraction.trim_end_matches('0');
+        let digits = |text: &str| {
+            if text.is_empty() {
+                Some(0.0)
+            } else {
+                text.parse().ok()
+            }
+        };
+
+        Some(Self {
+            n: number.parse().ok()?,
+            i: integer.parse().ok()?,
+            v: fraction.len() as f64,
+            w: trimmed.len() as f64,
+            f: digits(fraction)?,
+            t: digits(trimmed)?,
+        })
+    }
+
+    fn get(&self, operand: char) -> f64 {
+        match operand {
+            'n' => self.n,
+            'i' => self.i,
+            'v' => self.v,
+            'w' => self.w,
+            'f' => self.f,
+            't' => self.t,
+            _ => 0.0,
+        }
+    }
+}
+
+/// `operand [% modulus] (= | !=) ranges`
+#[derive(Debug, Clone, PartialEq)]
+struct Relation {
+    operand: char,
+    modulus: Option<u64>,
+    negated: bool,
+    ranges: Vec<(u64, u64)>,
+}
+
+impl Relation {
+    fn matches(&self, operands: &Operands) -> bool {
+        let mut value = operands.get(self.operand);
+        if let Some(modulus) = self.modulus {
+            value %= modulus as f64;
+        }
+
+        // Ranges only contain integers, so 1.5 is not in 1..2
+        let found = value.fract() == 0.0
+            && self
+                .ranges
+                .iter()
+                .any(|&(low, high)| value >= low as f64 && value <= high as f64);
+        found != self.negated
+    }
+
+    fn to_luau(&self) -> String {
+        let value = match self.modulus {
+            Some(modulus) => format!("{} % {}", self.operand, modulus),
+            None => self.operand.to_string(),
+        };
+
+        if let [(low, high)] = self.ranges[..] {
+            if low == high {
+                let op = if self.negated { "~=" } else { "==" };
+                return format!("{} {} {}", value, op, low);
+            }
+        }
+
+        let tests: Vec<String> = self
+            .ranges
+            .iter()
+            .map(|&(low, high)| {
+                if low == high {
+                    format!("{} == {}", value, low)
+                } else if self.operand == 'n' {
+                    // `n` may have decimals, which never match a range
+                    format!(
+                        "{v} == math.floor({v}) and {v} >= {} and {v} <= {}",
+                        low,
+                        high,
+                        v = value
+                    )
+                } else {
+                    format!("{v} >= {} and {v} <= {}", low, high, v = value)
+                }
+            })
+            .collect();
+
+        let test = format!("({})", tests.join(" or "));
+        if self.negated {
+            format!("not {}", test)
+        } else {
+            test
+        }
+    }
+}
+
+/// Parse a CLDR condition into `or` groups of `and`-ed relations
+///
+/// The tables above are checked by tests, so malformed rules are a bug.
+fn parse_condition(condition: &str) -> Vec<Vec<Relation>> {
+    condition
+        .split(" or ")
+        .map(|and| and.split(" and ").map(parse_relation).collect())
+        .collect()
+}
+
+fn parse_relation(relation: &str) -> Relation {
+    let tokens: Vec<&str> = relation.split_whitespace().collect();
+    let (operand, modulus, rest) = match tokens.as_slice() {
+        [operand, "%", modulus, rest @ ..] => (*operand, Some(*modulus), rest),
+        [operand, rest @ ..] => (*operand, None, rest),
+        [] => panic!("empty plural rule relation"),
+    };
+
+    let [op, ranges] = rest else {
+        panic!("invalid plural rule relation: {}", relation);
+    };
+
+    let bound = |text: &str| {
+        text.parse::<u64>()
+            .unwrap_or_else(|_| panic!("invalid number in plural rule: {}", relation))
+    };
+
+    Relation {
+        operand: operand.chars().next().unwrap_or('n'),
+        modulus: modulus.map(bound),
+        negated: *op == "!=",
+        ranges: ranges
+            .split(',')
+            .map(|range| match range.split_once("..") {
+                Some((low, high)) => (bound(low), bound(high)),
+                None => (bound(range), bound(range)),
+            })
+            .collect(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,4 +490,59 @@ mod tests {
     fn test_extract_base_key_empty() {
         assert_eq!(extract_base_key(""), "");
     }
+
+    #[test]
+    fn test_plural_category_cardinal() {
+        assert_eq!(plural_category("en", "1", false), "one");
+        assert_eq!(plural_category("en", "1.0", false), "other");
+        assert_eq!(plural_category("pl", "1", false), "one");
+        assert_eq!(plural_category("pl", "3", false), "few");
+        assert_eq!(plural_category("pl", "12", false), "many");
+        assert_eq!(plural_category("pl", "22", false), "few");
+        assert_eq!(plural_category("pl", "1.5", false), "other");
+        assert_eq!(plural_category("ru", "21", false), "one");
+        assert_eq!(plural_category("ru", "11", false), "many");
+        assert_eq!(plural_category("fr", "1.5", false), "one");
+        assert_eq!(plural_category("fr", "2", false), "other");
+        assert_eq!(plural_category("pt-br", "0", false), "one");
+        assert_eq!(plural_category("ar", "103", false), "few");
+        assert_eq!(plural_category("ja", "1", false), "other");
+        assert_eq!(plural_category("zh-cn", "1", false), "other");
+    }
+
+    #[test]
+    fn test_plural_category_ordinal() {
+        assert_eq!(plural_category("en", "1", true), "one");
+        assert_eq!(plural_category("en", "2", true), "two");
+        assert_eq!(plural_category("en", "23", true), "few");
+        assert_eq!(plural_category("en", "11", true), "other");
+        assert_eq!(plural_category("fr", "1", true), "one");
+        assert_eq!(plural_category("fr", "2", true), "other");
+    }
+
+    #[test]
+    fn test_ordinal_keys() {
+        assert!(is_plural_key("rank(ordinal:one)"));
+        assert!(is_ordinal_key("rank(ordinal:few)"));
+        assert!(!is_ordinal_key("items(few)"));
+        assert_eq!(extract_base_key("rank(ordinal:two)"), "rank");
+    }
+
+    #[test]
+    fn test_rules_cover_roblox_locales() {
+        for locale in crate::utils::locales::get_roblox_locales() {
+            assert!(rules_for_locale(locale.code).is_some(), "{}", locale.code);
+        }
+    }
+
+    #[test]
+    fn test_rules_to_luau() {
+        let rules = rules_for_locale("en").unwrap();
+        let luau = rules_to_luau(rules.cardinal, "    ");
+
+        assert_eq!(
+            luau,
+            "    if i == 1 and v == 0 then\n        return \"one\"\n    end\n    return \"other\"\n"
+        );
+    }
 }
@@ -0,0 +1,72 @@
+//! Select translations
+//!
+//! Keys with a `(select:case)` suffix vary by a non-numeric argument such as a
+//! pronoun, team or rarity (`greeting(select:male)`, `greeting(select:other)`).
+//! The generators group the cases into a single method taking the case name,
+//! with `other` as the fallback.
+
+/// Prefix marking select cases: `greeting(select:female)`
+pub const SELECT_PREFIX: &str = "select:";
+
+/// Case used when the argument matches no other case
+pub const FALLBACK_CASE: &str = "other";
+
+/// Detect if a translation key is a select case
+/// Example: "greeting(select:male)" -> true
+pub fn is_select_key(key: &str) -> bool {
+    select_case(key).is_some()
+}
+
+/// The case name of a select key
+/// Example: "greeting(select:male)" -> Some("male")
+pub fn select_case(key: &str) -> Option<&str> {
+    let form = key.strip_suffix(')')?.rsplit_once('(')?.1;
+    let case = form.strip_prefix(SELECT_PREFIX)?;
+
+    let mut chars = case.chars();
+    let valid = matches!(chars.next(), Some(c) if c.is_ascii_alphanumeric() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    valid.then_some(case)
+}
+
+/// Extract the base key from a select key
+/// Example: "greeting(select:male)" -> "greeting"
+pub fn extract_base_key(key: &str) -> String {
+    match key.rfind('(') {
+        Some(pos) if is_select_key(key) => key[..pos].to_string(),
+        _ => key.to_string(),
+    }
+}
+
+/// Sort case names for output, with the fallback case last
+pub fn sort_cases(cases: &mut [&str]) {
+    cases.sort_by_key(|case| (*case == FALLBACK_CASE, *case));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_select_key() {
+        assert!(is_select_key("greeting(select:male)"));
+        assert!(is_select_key("team.banner(select:red-team)"));
+        assert!(!is_select_key("greeting(select:)"));
+        assert!(!is_select_key("greeting(select:two words)"));
+        assert!(!is_select_key("items(one)"));
+        assert!(!is_select_key("greeting"));
+    }
+
+    #[test]
+    fn test_extract_base_key() {
+        assert_eq!(extract_base_key("ui.greeting(select:other)"), "ui.greeting");
+        assert_eq!(extract_base_key("items(one)"), "items(one)");
+    }
+
+    #[test]
+    fn test_sort_cases() {
+        let mut cases = vec!["other", "male", "female"];
+        sort_cases(&mut cases);
+        assert_eq!(cases, vec!["female", "male", "other"]);
+    }
+}
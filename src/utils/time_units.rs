@@ -0,0 +1,385 @@
+//! CLDR relative time and duration units
+//! Based on Unicode CLDR: <https://cldr.unicode.org/translation/date-time/date-time-names>
+//!
+//! Relative-time phrases ("in 3 minutes", "2 hours ago") by plural category
+//! and narrow duration units ("2h 05m") for every Roblox locale. The generated
+//! module formats `relative` and `duration` parameters with the units of the
+//! player's locale.
+
+/// Relative-time forms of a unit: (plural category, future, past), `{0}` being the count
+pub type RelativeForms = &'static [(&'static str, &'static str, &'static str)];
+
+/// Seconds in a day, an hour, a minute and a second, the order of [`TimeUnits`]
+pub const UNIT_SECONDS: [u32; 4] = [86_400, 3_600, 60, 1];
+
+/// Time units of a locale, for days, hours, minutes and seconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeUnits {
+    /// Language (`de`), or full locale code when it differs from its language (`zh-tw`)
+    pub locale: &'static str,
+    /// Relative-time forms; every unit has an `other` form
+    pub relative: [RelativeForms; 4],
+    /// Narrow duration units, `{0}` being the count (`{0}h`)
+    pub narrow: [&'static str; 4],
+}
+
+/// CLDR time units for every Roblox locale
+pub const TIME_UNITS: &[TimeUnits] = &[
+    TimeUnits {
+        locale: "de",
+        relative: [
+            &[
+                ("one", "in {0} Tag", "vor {0} Tag"),
+                ("other", "in {0} Tagen", "vor {0} Tagen"),
+            ],
+            &[
+                ("one", "in {0} Stunde", "vor {0} Stunde"),
+                ("other", "in {0} Stunden", "vor {0} Stunden"),
+            ],
+            &[
+                ("one", "in {0} Minute", "vor {0} Minute"),
+                ("other", "in {0} Minuten", "vor {0} Minuten"),
+            ],
+            &[
+                ("one", "in {0} Sekunde", "vor {0} Sekunde"),
+                ("other", "in {0} Sekunden", "vor {0} Sekunden"),
+            ],
+        ],
+        narrow: ["{0} T.", "{0} Std.", "{0} Min.", "{0} Sek."],
+    },
+    TimeUnits {
+        locale: "en",
+        relative: [
+            &[
+                ("one", "in {0} day", "{0} day ago"),
+                ("other", "in {0} days", "{0} days ago"),
+            ],
+            &[
+                ("one", "in {0} hour", "{0} hour ago"),
+                ("other", "in {0} hours", "{0} hours ago"),
+            ],
+            &[
+                ("one", "in {0} minute", "{0} minute ago"),
+                ("other", "in {0} minutes", "{0} minutes ago"),
+            ],
+            &[
+                ("one", "in {0} second", "{0} second ago"),
+                ("other", "in {0} seconds", "{0} seconds ago"),
+            ],
+        ],
+        narrow: ["{0}d", "{0}h", "{0}m", "{0}s"],
+    },
+    TimeUnits {
+        locale: "es",
+        relative: [
+            &[
+                ("one", "dentro de {0} día", "hace {0} día"),
+                ("other", "dentro de {0} días", "hace {0} días"),
+            ],
+            &[
+                ("one", "dentro de {0} hora", "hace {0} hora"),
+                ("other", "dentro de {0} horas", "hace {0} horas"),
+            ],
+            &[
+                ("one", "dentro de {0} minuto", "hace {0} minuto"),
+                ("other", "dentro de {0} minutos", "hace {0} minutos"),
+            ],
+            &[
+                ("one", "dentro de {0} segundo", "hace {0} segundo"),
+                ("other", "dentro de {0} segundos", "hace {0} segundos"),
+            ],
+        ],
+        narrow: ["{0}d", "{0}h", "{0}min", "{0}s"],
+    },
+    TimeUnits {
+        locale: "fr",
+        relative: [
+            &[
+                ("one", "dans {0} jour", "il y a {0} jour"),
+                ("other", "dans {0} jours", "il y a {0} jours"),
+            ],
+            &[
+                ("one", "dans {0} heure", "il y a {0} heure"),
+                ("other", "dans {0} heures", "il y a {0} heures"),
+            ],
+            &[
+                ("one", "dans {0} minute", "il y a {0} minute"),
+                ("other", "dans {0} minutes", "il y a {0} minutes"),
+            ],
+            &[
+                ("one", "dans {0} seconde", "il y a {0} seconde"),
+                ("other", "dans {0} secondes", "il y a {0} secondes"),
+            ],
+        ],
+        narrow: ["{0}j", "{0}h", "{0}min", "{0}s"],
+    },
+    TimeUnits {
+        locale: "id",
+        relative: [
+            &[("other", "dalam {0} hari", "{0} hari yang lalu")],
+            &[("other", "dalam {0} jam", "{0} jam yang lalu")],
+            &[("other", "dalam {0} menit", "{0} menit yang lalu")],
+            &[("other", "dalam {0} detik", "{0} detik yang lalu")],
+        ],
+        narrow: ["{0} h", "{0} j", "{0} mnt", "{0} dtk"],
+    },
+    TimeUnits {
+        locale: "it",
+        relative: [
+            &[
+                ("one", "tra {0} giorno", "{0} giorno fa"),
+                ("other", "tra {0} giorni", "{0} giorni fa"),
+            ],
+            &[
+                ("one", "tra {0} ora", "{0} ora fa"),
+                ("other", "tra {0} ore", "{0} ore fa"),
+            ],
+            &[
+                ("one", "tra {0} minuto", "{0} minuto fa"),
+                ("other", "tra {0} minuti", "{0} minuti fa"),
+            ],
+            &[
+                ("one", "tra {0} secondo", "{0} secondo fa"),
+                ("other", "tra {0} secondi", "{0} secondi fa"),
+            ],
+        ],
+        narrow: ["{0}g", "{0}h", "{0}min", "{0}s"],
+    },
+    TimeUnits {
+        locale: "ja",
+        relative: [
+            &[("other", "{0} 日後", "{0} 日前")],
+            &[("other", "{0} 時間後", "{0} 時間前")],
+            &[("other", "{0} 分後", "{0} 分前")],
+            &[("other", "{0} 秒後", "{0} 秒前")],
+        ],
+        narrow: ["{0}日", "{0}時間", "{0}分", "{0}秒"],
+    },
+    TimeUnits {
+        locale: "ko",
+        relative: [
+            &[("other", "{0}일 후", "{0}일 전")],
+            &[("other", "{0}시간 후", "{0}시간 전")],
+            &[("other", "{0}분 후", "{0}분 전")],
+            &[("other", "{0}초 후", "{0}초 전")],
+        ],
+        narrow: ["{0}일", "{0}시간", "{0}분", "{0}초"],
+    },
+    TimeUnits {
+        locale: "pl",
+        relative: [
+            &[
+                ("one", "za {0} dzień", "{0} dzień temu"),
+                ("few", "za {0} dni", "{0} dni temu"),
+                ("many", "za {0} dni", "{0} dni temu"),
+                ("other", "za {0} dnia", "{0} dnia temu"),
+            ],
+            &[
+                ("one", "za {0} godzinę", "{0} godzinę temu"),
+                ("few", "za {0} godziny", "{0} godziny temu"),
+                ("many", "za {0} godzin", "{0} godzin temu"),
+                ("other", "za {0} godziny", "{0} godziny temu"),
+            ],
+            &[
+                ("one", "za {0} minutę", "{0} minutę temu"),
+                ("few", "za {0} minuty", "{0} minuty temu"),
+                ("many", "za {0} minut", "{0} minut temu"),
+                ("other", "za {0} minuty", "{0} minuty temu"),
+            ],
+            &[
+                ("one", "za {0} sekundę", "{0} sekundę temu"),
+                ("few", "za {0} sekundy", "{0} sekundy temu"),
+                ("many", "za {0} sekund", "{0} sekund temu"),
+                ("other", "za {0} sekundy", "{0} sekundy temu"),
+            ],
+        ],
+        narrow: ["{0} d.", "{0} g.", "{0} min", "{0} s"],
+    },
+    TimeUnits {
+        locale: "pt",
+        relative: [
+            &[
+                ("one", "em {0} dia", "há {0} dia"),
+                ("other", "em {0} dias", "há {0} dias"),
+            ],
+            &[
+                ("one", "em {0} hora", "há {0} hora"),
+                ("other", "em {0} horas", "há {0} horas"),
+            ],
+            &[
+                ("one", "em {0} minuto", "há {0} minuto"),
+                ("other", "em {0} minutos", "há {0} minutos"),
+            ],
+            &[
+                ("one", "em {0} segundo", "há {0} segundo"),
+                ("other", "em {0} segundos", "há {0} segundos"),
+            ],
+        ],
+        narrow: ["{0}d", "{0}h", "{0}min", "{0}s"],
+    },
+    TimeUnits {
+        locale: "ru",
+        relative: [
+            &[
+                ("one", "через {0} день", "{0} день назад"),
+                ("few", "через {0} дня", "{0} дня назад"),
+                ("many", "через {0} дней", "{0} дней назад"),
+                ("other", "через {0} дня", "{0} дня назад"),
+            ],
+            &[
+                ("one", "через {0} час", "{0} час назад"),
+                ("few", "через {0} часа", "{0} часа назад"),
+                ("many", "через {0} часов", "{0} часов назад"),
+                ("other", "через {0} часа", "{0} часа назад"),
+            ],
+            &[
+                ("one", "через {0} минуту", "{0} минуту назад"),
+                ("few", "через {0} минуты", "{0} минуты назад"),
+                ("many", "через {0} минут", "{0} минут назад"),
+                ("other", "через {0} минуты", "{0} минуты назад"),
+            ],
+            &[
+                ("one", "через {0} секунду", "{0} секунду назад"),
+                ("few", "через {0} секунды", "{0} секунды назад"),
+                ("many", "через {0} секунд", "{0} секунд назад"),
+                ("other", "через {0} секунды", "{0} секунды назад"),
+            ],
+        ],
+        narrow: ["{0} д", "{0} ч", "{0} мин", "{0} с"],
+    },
+    TimeUnits {
+        locale: "th",
+        relative: [
+            &[("other", "ในอีก {0} วัน", "{0} วันที่ผ่านมา")],
+            &[("other", "ในอีก {0} ชั่วโมง", "{0} ชั่วโมงที่ผ่านมา")],
+            &[("other", "ในอีก {0} นาที", "{0} นาทีที่ผ่านมา")],
+            &[("other", "ในอีก {0} วินาที", "{0} วินาทีที่ผ่านมา")],
+        ],
+        narrow: ["{0}ว.", "{0}ชม.", "{0}น.", "{0}วิ"],
+    },
+    TimeUnits {
+        locale: "tr",
+        relative: [
+            &[("other", "{0} gün sonra", "{0} gün önce")],
+            &[("other", "{0} saat sonra", "{0} saat önce")],
+            &[("other", "{0} dakika sonra", "{0} dakika önce")],
+            &[("other", "{0} saniye sonra", "{0} saniye önce")],
+        ],
+        narrow: ["{0}g", "{0}s", "{0}d", "{0}sn"],
+    },
+    TimeUnits {
+        locale: "uk",
+        relative: [
+            &[
+                ("one", "через {0} день", "{0} день тому"),
+                ("few", "через {0} дні", "{0} дні тому"),
+                ("many", "через {0} днів", "{0} днів тому"),
+                ("other", "через {0} дня", "{0} дня тому"),
+            ],
+            &[
+                ("one", "через {0} годину", "{0} годину тому"),
+                ("few", "через {0} години", "{0} години тому"),
+                ("many", "через {0} годин", "{0} годин тому"),
+                ("other", "через {0} години", "{0} години тому"),
+            ],
+            &[
+                ("one", "через {0} хвилину", "{0} хвилину тому"),
+                ("few", "через {0} хвилини", "{0} хвилини тому"),
+                ("many", "через {0} хвилин", "{0} хвилин тому"),
+                ("other", "через {0} хвилини", "{0} хвилини тому"),
+            ],
+            &[
+                ("one", "через {0} секунду", "{0} секунду тому"),
+                ("few", "через {0} секунди", "{0} секунди тому"),
+                ("many", "через {0} секунд", "{0} секунд тому"),
+                ("other", "через {0} секунди", "{0} секунди тому"),
+            ],
+        ],
+        narrow: ["{0} д", "{0} год", "{0} хв", "{0} с"],
+    },
+    TimeUnits {
+        locale: "vi",
+        relative: [
+            &[("other", "sau {0} ngày nữa", "{0} ngày trước")],
+            &[("other", "sau {0} giờ nữa", "{0} giờ trước")],
+            &[("other", "sau {0} phút nữa", "{0} phút trước")],
+            &[("other", "sau {0} giây nữa", "{0} giây trước")],
+        ],
+        narrow: ["{0}ng", "{0}h", "{0}p", "{0}s"],
+    },
+    TimeUnits {
+        locale: "zh",
+        relative: [
+            &[("other", "{0}天后", "{0}天前")],
+            &[("other", "{0}小时后", "{0}小时前")],
+            &[("other", "{0}分钟后", "{0}分钟前")],
+            &[("other", "{0}秒钟后", "{0}秒钟前")],
+        ],
+        narrow: ["{0}天", "{0}小时", "{0}分钟", "{0}秒"],
+    },
+    TimeUnits {
+        locale: "zh-tw",
+        relative: [
+            &[("other", "{0} 天後", "{0} 天前")],
+            &[("other", "{0} 小時後", "{0} 小時前")],
+            &[("other", "{0} 分鐘後", "{0} 分鐘前")],
+            &[("other", "{0} 秒後", "{0} 秒前")],
+        ],
+        narrow: ["{0}天", "{0}小時", "{0}分", "{0}秒"],
+    },
+];
+
+/// Time units for a locale code, matching the full code first, then the language
+/// (`zh-tw` → `zh-tw`, `zh-cn` → `zh`)
+pub fn units_for_locale(locale: &str) -> Option<&'static TimeUnits> {
+    let locale = locale.to_lowercase().replace('_', "-");
+    let language = locale.split('-').next().unwrap_or(&locale);
+
+    TIME_UNITS
+        .iter()
+        .find(|u| u.locale == locale)
+        .or_else(|| TIME_UNITS.iter().find(|u| u.locale == language))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{locales, plurals};
+
+    #[test]
+    fn test_units_for_locale() {
+        assert_eq!(units_for_locale("en").unwrap().narrow[1], "{0}h");
+        assert_eq!(units_for_locale("pt-BR").unwrap().locale, "pt");
+        assert_eq!(units_for_locale("zh_TW").unwrap().narrow[2], "{0}分");
+        assert!(units_for_locale("xx").is_none());
+    }
+
+    #[test]
+    fn test_every_roblox_locale_has_units() {
+        for locale in locales::get_supported_locale_codes() {
+            assert!(
+                units_for_locale(locale).is_some(),
+                "no time units for {}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn test_relative_forms_are_valid() {
+        for units in TIME_UNITS {
+            for forms in units.relative {
+                assert!(
+                    forms.iter().any(|(category, _, _)| *category == "other"),
+                    "relative forms of {} lack 'other'",
+                    units.locale
+                );
+                for (category, future, past) in forms {
+                    assert!(plurals::PLURAL_CATEGORIES.contains(category));
+                    assert!(future.contains("{0}") && past.contains("{0}"));
+                }
+            }
+            assert!(units.narrow.iter().all(|unit| unit.contains("{0}")));
+        }
+    }
+}
@@ -8,6 +8,7 @@ use anyhow::{bail, Result};
 use std::path::Path;
 
 use crate::config::Config;
+use crate::utils::{plurals, selects};
 
 /// Validates a locale code format
 ///
@@ -200,9 +201,16 @@ pub fn validate_translation_key(key: &str) -> Result<()> {
         );
     }
 
-    // Check for reserved characters
+    // Check for reserved characters (`(ordinal:one)` and `(select:male)` suffixes may use a colon)
+    let name = if plurals::is_ordinal_key(key) {
+        plurals::extract_base_key(key)
+    } else if selects::is_select_key(key) {
+        selects::extract_base_key(key)
+    } else {
+        key.to_string()
+    };
     let reserved_chars = ['/', '\\', ':', '*', '?', '"', '<', '>', '|', '\0'];
-    if let Some(invalid_char) = key.chars().find(|c| reserved_chars.contains(c)) {
+    if let Some(invalid_char) = name.chars().find(|c| reserved_chars.contains(c)) {
         bail!(
             "Invalid translation key '{}': Contains reserved character '{}'\n\
              \n\
@@ -431,6 +439,7 @@ pub fn validate_safe_path(path: &Path) -> Result<()> {
 ///     overrides: None,
 ///     analytics: None,
 ///     cloud: None,
+///     ..Config::default()
 /// };
 ///
 /// validate_config(&config).unwrap();
@@ -456,46 +465,6 @@ pub fn validate_config(config: &Config) -> Result<()> {
     validate_safe_path(Path::new(&config.output_directory))
         .map_err(|e| anyhow::anyhow!("Configuration error in output_directory:\n{}", e))?;
 
-    // Validate namespace if present
-    if let Some(ref namespace) = config.namespace {
-        if namespace.is_empty() {
-            bail!(
-                "Configuration error: namespace cannot be empty\n\
-                 \n\
-                 Either remove the namespace field or provide a valid value.\n\
-                 Example: namespace: MyGame"
-            );
-        }
-
-        // Check for invalid characters in namespace
-        if !namespace.chars().all(|c| c.is_alphanumeric() || c == '_') {
-            bail!(
-                "Configuration error: namespace '{}' contains invalid characters\n\
-                 \n\
-                 Namespace can only contain:\n\
-                 - Letters (a-z, A-Z)\n\
-                 - Digits (0-9)\n\
-                 - Underscores (_)\n\
-                 \n\
-                 Example: namespace: MyGame_Translations",
-                namespace
-            );
-        }
-
-        // Check if namespace starts with a digit
-        if namespace.chars().next().unwrap().is_ascii_digit() {
-            bail!(
-                "Configuration error: namespace '{}' cannot start with a digit\n\
-                 \n\
-                 Luau identifiers cannot start with digits.\n\
-                 Example: '{}' → 'Game{}'",
-                namespace,
-                namespace,
-                namespace
-            );
-        }
-    }
-
     // Validate override config if present
     if let Some(ref override_config) = config.overrides {
         if override_config.enabled {
@@ -599,6 +568,14 @@ mod tests {
         assert!(validate_translation_key("ui?button").is_err());
     }
 
+    #[test]
+    fn test_validate_translation_key_form_suffixes() {
+        assert!(validate_translation_key("race.place(ordinal:one)").is_ok());
+        assert!(validate_translation_key("race:place(ordinal:one)").is_err());
+        assert!(validate_translation_key("race.place(ordinal:first)").is_err());
+        assert!(validate_translation_key("ui.greeting(select:female)").is_ok());
+    }
+
     #[test]
     fn test_validate_translation_key_whitespace() {
         let result = validate_translation_key("ui button");
@@ -638,6 +615,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         assert!(validate_config(&config).is_ok());
@@ -654,6 +632,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         assert!(validate_config(&config).is_ok());
@@ -670,6 +649,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = validate_config(&config);
@@ -691,6 +671,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = validate_config(&config);
@@ -712,6 +693,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = validate_config(&config);
@@ -731,6 +713,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = validate_config(&config);
@@ -750,6 +733,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = validate_config(&config);
@@ -769,6 +753,7 @@ mod tests {
             overrides: None,
             analytics: None,
             cloud: None,
+            ..Config::default()
         };
 
         let result = validate_config(&config);
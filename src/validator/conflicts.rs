@@ -1,25 +1,32 @@
-use crate::parser::Translation;
-use std::collections::{HashMap, HashSet};
+use super::format_diagnostic;
+use crate::parser::{SourceLocation, Translation};
+use std::collections::HashMap;
 
 /// Detect conflicting keys (duplicates, etc.)
+///
+/// Each duplicated key is reported once, at its second definition.
 pub fn detect_conflicts(translations: &[Translation]) -> Vec<String> {
     let mut conflicts = Vec::new();
-    let mut seen_keys: HashMap<(String, String), usize> = HashMap::new();
+    let mut seen_keys: HashMap<(&str, &str), (usize, Option<&SourceLocation>)> = HashMap::new();
 
     // Check for duplicate keys in same locale
     for translation in translations {
-        let key = (translation.locale.clone(), translation.key.clone());
-        let count = seen_keys.entry(key.clone()).or_insert(0);
+        let key = (translation.locale.as_str(), translation.key.as_str());
+        let (count, first_location) = seen_keys
+            .entry(key)
+            .or_insert((0, translation.location.as_ref()));
         *count += 1;
 
-        if *count > 1 {
-            conflicts.push(format!("Duplicate key '{}' in locale '{}'", key.1, key.0));
+        if *count == 2 {
+            let mut message = format!("Duplicate key '{}' in locale '{}'", key.1, key.0);
+            if let Some(first) = first_location {
+                message.push_str(&format!(" (first defined at {})", first));
+            }
+            conflicts.push(format_diagnostic(translation.location.as_ref(), &message));
         }
     }
 
-    // Remove duplicates from conflicts list
-    let unique_conflicts: HashSet<String> = conflicts.into_iter().collect();
-    unique_conflicts.into_iter().collect()
+    conflicts
 }
 
 #[cfg(test)]
@@ -34,12 +41,18 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Purchase".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -58,12 +71,18 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.label".to_string(),
                 value: "Welcome".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -80,24 +99,36 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Purchase".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.label".to_string(),
                 value: "Welcome".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.label".to_string(),
                 value: "Hello".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -114,12 +145,18 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Beli".to_string(),
                 locale: "id".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -137,18 +174,27 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Purchase".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Get".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -165,4 +211,45 @@ mod tests {
 
         assert_eq!(conflicts.len(), 0);
     }
+
+    #[test]
+    fn test_conflict_reports_locations() {
+        let at = |line| {
+            Some(SourceLocation {
+                file: "translations/en.json".into(),
+                line,
+                column: 7,
+            })
+        };
+        let translations = vec![
+            Translation {
+                key: "ui.button".to_string(),
+                value: "Buy".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: at(2),
+            },
+            Translation {
+                key: "ui.button".to_string(),
+                value: "Purchase".to_string(),
+                locale: "en".to_string(),
+                context: None,
+                description: None,
+                max_length: None,
+                location: at(9),
+            },
+        ];
+
+        let conflicts = detect_conflicts(&translations);
+
+        assert_eq!(
+            conflicts,
+            vec![
+                "translations/en.json:9:7: Duplicate key 'ui.button' in locale 'en' \
+                 (first defined at translations/en.json:2:7)"
+            ]
+        );
+    }
 }
@@ -0,0 +1,85 @@
+use super::format_diagnostic;
+use crate::parser::Translation;
+use crate::utils::links;
+use std::collections::BTreeMap;
+
+/// Check the `@:key` links of every translation value
+///
+/// Reports links to keys that neither the value's locale, its `fallbacks` nor
+/// the base locale has, and links that lead back to the value itself, with the
+/// chain of links.
+pub fn check_links(
+    translations: &[Translation],
+    base_locale: &str,
+    fallbacks: &BTreeMap<String, Vec<String>>,
+) -> Vec<String> {
+    link_issues(translations, base_locale, fallbacks)
+        .into_iter()
+        .map(|(translation, message)| format_diagnostic(translation.location.as_ref(), &message))
+        .collect()
+}
+
+/// Like [`check_links`], but returns each translation with a broken link and
+/// its message, without the location prefix
+pub fn link_issues<'a>(
+    translations: &'a [Translation],
+    base_locale: &str,
+    fallbacks: &BTreeMap<String, Vec<String>>,
+) -> Vec<(&'a Translation, String)> {
+    links::link_errors(translations, base_locale, fallbacks)
+        .into_iter()
+        .map(|(translation, error)| {
+            let message = format!(
+                "Broken link in key '{}' ({}): {}",
+                translation.key, translation.locale, error
+            );
+            (translation, message)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::SourceLocation;
+
+    fn translation(key: &str, value: &str, locale: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_check_links() {
+        let mut dangling = translation("shop.buy", "Beli @:common.gem", "id");
+        dangling.location = Some(SourceLocation {
+            file: "translations/id.json".into(),
+            line: 4,
+            column: 12,
+        });
+        let translations = vec![
+            translation("common.gems", "Gems", "en"),
+            translation("shop.buy", "Buy @:common.gems", "en"),
+            dangling,
+            translation("shop.title", "@:shop.title", "id"),
+        ];
+
+        let issues = check_links(&translations, "en", &BTreeMap::new());
+
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            issues[0],
+            "translations/id.json:4:12: Broken link in key 'shop.buy' (id): Link '@:common.gem' points to a key that doesn't exist in 'id' or its fallbacks"
+        );
+        assert_eq!(
+            issues[1],
+            "Broken link in key 'shop.title' (id): Circular link in 'id': @:shop.title -> @:shop.title"
+        );
+    }
+}
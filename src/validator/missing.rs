@@ -1,11 +1,31 @@
-use crate::parser::Translation;
-use std::collections::{HashMap, HashSet};
+use crate::parser::{SourceLocation, Translation};
+use crate::utils::locales;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Detect missing keys in non-base locales
+#[allow(dead_code)] // Public API for library users
 pub fn detect_missing_keys(
     translations: &[Translation],
     base_locale: &str,
     supported_locales: &[String],
+) -> HashMap<String, Vec<String>> {
+    detect_missing_keys_with_fallbacks(
+        translations,
+        base_locale,
+        supported_locales,
+        &BTreeMap::new(),
+    )
+}
+
+/// Detect missing keys in non-base locales, honoring configured fallbacks
+///
+/// A key only counts as missing when the locale and every fallback before the
+/// base locale lack it. Falling back to the base locale still counts as missing.
+pub fn detect_missing_keys_with_fallbacks(
+    translations: &[Translation],
+    base_locale: &str,
+    supported_locales: &[String],
+    fallbacks: &BTreeMap<String, Vec<String>>,
 ) -> HashMap<String, Vec<String>> {
     // Get all keys from base locale
     let base_keys: HashSet<String> = translations
@@ -22,10 +42,14 @@ pub fn detect_missing_keys(
             continue;
         }
 
-        // Get keys for this locale
+        // Get keys for this locale and its fallbacks
+        let chain: Vec<&str> = locales::fallback_chain(locale, fallbacks, base_locale)
+            .into_iter()
+            .filter(|l| *l != base_locale)
+            .collect();
         let locale_keys: HashSet<String> = translations
             .iter()
-            .filter(|t| t.locale == locale.as_str())
+            .filter(|t| chain.contains(&t.locale.as_str()))
             .map(|t| t.key.clone())
             .collect();
 
@@ -40,6 +64,20 @@ pub fn detect_missing_keys(
     missing_by_locale
 }
 
+/// Map each base-locale key to where it is defined
+///
+/// Used to point missing-key reports at the source entry that needs translating.
+pub fn base_key_locations<'a>(
+    translations: &'a [Translation],
+    base_locale: &str,
+) -> HashMap<&'a str, &'a SourceLocation> {
+    translations
+        .iter()
+        .filter(|t| t.locale == base_locale)
+        .filter_map(|t| t.location.as_ref().map(|l| (t.key.as_str(), l)))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,18 +90,27 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.label".to_string(),
                 value: "Welcome".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Beli".to_string(),
                 locale: "id".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             // ui.label missing in id
         ];
@@ -84,12 +131,18 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Beli".to_string(),
                 locale: "id".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -107,18 +160,27 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.label".to_string(),
                 value: "Welcome".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.message".to_string(),
                 value: "Hello".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             // id missing ui.label and ui.message
             Translation {
@@ -126,6 +188,9 @@ mod tests {
                 value: "Beli".to_string(),
                 locale: "id".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             // es missing ui.message
             Translation {
@@ -133,12 +198,18 @@ mod tests {
                 value: "Comprar".to_string(),
                 locale: "es".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.label".to_string(),
                 value: "Bienvenido".to_string(),
                 locale: "es".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -168,6 +239,9 @@ mod tests {
             value: "Buy".to_string(),
             locale: "en".to_string(),
             context: None,
+            description: None,
+            max_length: None,
+            location: None,
         }];
 
         let supported_locales = vec!["en".to_string()];
@@ -184,18 +258,27 @@ mod tests {
                 value: "Buy".to_string(),
                 locale: "en".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.button".to_string(),
                 value: "Beli".to_string(),
                 locale: "id".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
             Translation {
                 key: "ui.extra".to_string(),
                 value: "Extra".to_string(),
                 locale: "id".to_string(),
                 context: None,
+                description: None,
+                max_length: None,
+                location: None,
             },
         ];
 
@@ -205,4 +288,36 @@ mod tests {
         // Extra keys in non-base locale are not considered "missing"
         assert_eq!(missing.len(), 0);
     }
+
+    #[test]
+    fn test_detect_missing_keys_with_fallbacks() {
+        let translation = |key: &str, locale: &str| Translation {
+            key: key.to_string(),
+            value: "Text".to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        };
+        let translations = vec![
+            translation("ui.button", "en"),
+            translation("ui.label", "en"),
+            translation("ui.title", "en"),
+            translation("ui.button", "pt"),
+            translation("ui.label", "es"),
+        ];
+
+        let supported_locales = vec!["en".to_string(), "es".to_string(), "pt".to_string()];
+        let fallbacks = BTreeMap::from([("pt".to_string(), vec!["es".to_string()])]);
+        let mut missing =
+            detect_missing_keys_with_fallbacks(&translations, "en", &supported_locales, &fallbacks);
+        for keys in missing.values_mut() {
+            keys.sort();
+        }
+
+        // ui.label is covered by es; ui.title only falls back to the base locale
+        assert_eq!(missing["pt"], vec!["ui.title"]);
+        assert_eq!(missing["es"], vec!["ui.button", "ui.title"]);
+    }
 }
@@ -1,13 +1,21 @@
 //! Translation validation
 //!
 //! This module provides validation functions for detecting missing translations,
-//! conflicts, unused keys, and coverage analysis.
+//! conflicts, unused keys, override problems, incomplete plural forms, inconsistent
+//! select cases, malformed placeholders, broken links, and coverage analysis.
 
 pub mod conflicts;
 pub mod coverage;
+pub mod links;
 pub mod missing;
+pub mod overrides;
+pub mod placeholders;
+pub mod plurals;
+pub mod selects;
 pub mod unused;
 
+use crate::parser::SourceLocation;
+
 /// Coverage information for a locale
 #[derive(Debug, Clone)]
 pub struct CoverageInfo {
@@ -17,10 +25,39 @@ pub struct CoverageInfo {
     pub coverage_percent: f64,
 }
 
+/// Prefix a message with its source location, compiler style
+///
+/// Produces `translations/id.json:42:7: message`, or just the message when the
+/// location is unknown.
+pub fn format_diagnostic(location: Option<&SourceLocation>, message: &str) -> String {
+    match location {
+        Some(location) => format!("{}: {}", location, message),
+        None => message.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_diagnostic() {
+        let location = SourceLocation {
+            file: "translations/id.json".into(),
+            line: 42,
+            column: 7,
+        };
+
+        assert_eq!(
+            format_diagnostic(Some(&location), "Missing key 'ui.title'"),
+            "translations/id.json:42:7: Missing key 'ui.title'"
+        );
+        assert_eq!(
+            format_diagnostic(None, "Missing key 'ui.title'"),
+            "Missing key 'ui.title'"
+        );
+    }
+
     #[test]
     fn test_coverage_info_creation() {
         let info = CoverageInfo {
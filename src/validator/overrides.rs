@@ -0,0 +1,274 @@
+use super::format_diagnostic;
+use crate::parser::{Overrides, Translation};
+use crate::utils::{format, icu, plurals};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Check overrides and variants against the main translations
+///
+/// Reports overrides for keys the base locale doesn't define, overrides for
+/// locales outside `supported_locales`, and overrides whose `{params}` differ
+/// from the base entry. Variants only replace plain values at runtime, so
+/// variant values for keys compiled as ICU messages are reported too.
+/// `translations` must not have the overrides merged in yet.
+pub fn check_overrides(
+    overrides: &Overrides,
+    translations: &[Translation],
+    base_locale: &str,
+    supported_locales: &[String],
+) -> Vec<String> {
+    let base: HashMap<&str, &Translation> = translations
+        .iter()
+        .filter(|t| t.locale == base_locale)
+        .map(|t| (t.key.as_str(), t))
+        .collect();
+
+    let message_keys: HashSet<&str> = translations
+        .iter()
+        .filter(|t| is_message(&t.value))
+        .map(|t| t.key.as_str())
+        .collect();
+
+    let mut issues = Vec::new();
+
+    let sources = std::iter::once((None, &overrides.translations)).chain(
+        overrides
+            .variants
+            .iter()
+            .map(|v| (Some(v.name.as_str()), &v.translations)),
+    );
+
+    for (variant, entries) in sources {
+        let label = match variant {
+            Some(name) => format!("Variant '{}' override", name),
+            None => "Override".to_string(),
+        };
+
+        for entry in entries {
+            let location = entry.location.as_ref();
+
+            if !supported_locales.contains(&entry.locale) {
+                issues.push(format_diagnostic(
+                    location,
+                    &format!(
+                        "{} for '{}' uses unsupported locale '{}'",
+                        label, entry.key, entry.locale
+                    ),
+                ));
+                continue;
+            }
+
+            let Some(original) = base_entry(&base, &entry.key) else {
+                issues.push(format_diagnostic(
+                    location,
+                    &format!(
+                        "{} for unknown key '{}' in locale '{}'",
+                        label, entry.key, entry.locale
+                    ),
+                ));
+                continue;
+            };
+
+            if variant.is_some()
+                && (message_keys.contains(entry.key.as_str()) || is_message(&entry.value))
+            {
+                issues.push(format_diagnostic(
+                    location,
+                    &format!(
+                        "{} for '{}' in locale '{}' is an ICU message, which variants can't replace",
+                        label, entry.key, entry.locale
+                    ),
+                ));
+                continue;
+            }
+
+            let expected = parameter_names(original);
+            let actual = parameter_names(entry);
+            if expected != actual {
+                let mut message = format!(
+                    "{} for '{}' in locale '{}' has parameters {} but the base entry has {}",
+                    label,
+                    entry.key,
+                    entry.locale,
+                    format_names(&actual),
+                    format_names(&expected)
+                );
+                if let Some(base_location) = &original.location {
+                    message.push_str(&format!(" (defined at {})", base_location));
+                }
+                issues.push(format_diagnostic(location, &message));
+            }
+        }
+    }
+
+    issues
+}
+
+/// The base-locale entry an override replaces
+///
+/// Plural forms only need the plural key to exist in the base locale (locales have
+/// different forms), and are compared with its `other` form.
+fn base_entry<'a>(base: &HashMap<&str, &'a Translation>, key: &str) -> Option<&'a Translation> {
+    if let Some(entry) = base.get(key) {
+        return Some(entry);
+    }
+
+    if !plurals::is_plural_key(key) {
+        return None;
+    }
+
+    let base_key = plurals::extract_base_key(key);
+    base.get(format!("{}(other)", base_key).as_str())
+        .or_else(|| {
+            base.values()
+                .filter(|t| plurals::is_plural_key(&t.key))
+                .find(|t| plurals::extract_base_key(&t.key) == base_key)
+        })
+        .copied()
+}
+
+/// Whether a value is compiled as an ICU message rather than looked up as text
+fn is_message(value: &str) -> bool {
+    icu::is_icu_message(value) || icu::has_escaped_braces(value)
+}
+
+/// Parameter names used by a translation (`count` is implied for plural forms)
+fn parameter_names(translation: &Translation) -> BTreeSet<String> {
+    let mut names: BTreeSet<String> = format::extract_parameters_with_format(&translation.value)
+        .into_keys()
+        .collect();
+    if plurals::is_plural_key(&translation.key) {
+        names.remove("count");
+    }
+    names
+}
+
+fn format_names(names: &BTreeSet<String>) -> String {
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    format!("{{{}}}", names.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{SourceLocation, Variant};
+
+    fn translation(key: &str, value: &str, locale: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    fn main_translations() -> Vec<Translation> {
+        let mut greeting = translation("ui.greeting", "Hi, {name}!", "en");
+        greeting.location = Some(SourceLocation {
+            file: "translations/en.json".into(),
+            line: 3,
+            column: 17,
+        });
+
+        vec![
+            translation("ui.buy", "Buy", "en"),
+            greeting,
+            translation("items(one)", "One item", "en"),
+            translation("items(other)", "{count} items", "en"),
+        ]
+    }
+
+    #[test]
+    fn test_check_overrides_valid() {
+        let overrides = Overrides {
+            translations: vec![
+                translation("ui.greeting", "Hey, {name}!", "id"),
+                translation("items(few)", "{count} przedmioty", "pl"),
+            ],
+            variants: Vec::new(),
+        };
+        let locales = vec!["en".to_string(), "id".to_string(), "pl".to_string()];
+
+        assert!(check_overrides(&overrides, &main_translations(), "en", &locales).is_empty());
+    }
+
+    #[test]
+    fn test_check_overrides_reports_issues() {
+        let mut orphan = translation("ui.gift", "Gift", "en");
+        orphan.location = Some(SourceLocation {
+            file: "overrides.yaml".into(),
+            line: 2,
+            column: 12,
+        });
+
+        let overrides = Overrides {
+            translations: vec![orphan, translation("ui.buy", "Acheter", "fr")],
+            variants: vec![Variant {
+                name: "halloween".to_string(),
+                start: None,
+                end: None,
+                segments: Vec::new(),
+                translations: vec![translation("ui.greeting", "Boo, {player}!", "en")],
+            }],
+        };
+        let locales = vec!["en".to_string()];
+
+        let issues = check_overrides(&overrides, &main_translations(), "en", &locales);
+
+        assert_eq!(issues.len(), 3);
+        assert_eq!(
+            issues[0],
+            "overrides.yaml:2:12: Override for unknown key 'ui.gift' in locale 'en'"
+        );
+        assert_eq!(
+            issues[1],
+            "Override for 'ui.buy' uses unsupported locale 'fr'"
+        );
+        assert_eq!(
+            issues[2],
+            "Variant 'halloween' override for 'ui.greeting' in locale 'en' has parameters \
+             {player} but the base entry has {name} (defined at translations/en.json:3:17)"
+        );
+    }
+
+    #[test]
+    fn test_check_overrides_rejects_variant_messages() {
+        let mut translations = main_translations();
+        translations.push(translation(
+            "shop.cart",
+            "{count, plural, one {# item} other {# items}}",
+            "en",
+        ));
+        translations.push(translation("shop.cart", "{count} barang", "id"));
+
+        let overrides = Overrides {
+            // Plain overrides are merged before the messages are compiled
+            translations: vec![translation("shop.cart", "{count} item(s)", "en")],
+            variants: vec![Variant {
+                name: "sale".to_string(),
+                start: None,
+                end: None,
+                segments: Vec::new(),
+                translations: vec![
+                    translation("shop.cart", "{count} barang diskon", "id"),
+                    translation("ui.buy", "{{Buy}}", "en"),
+                ],
+            }],
+        };
+        let locales = vec!["en".to_string(), "id".to_string()];
+
+        let issues = check_overrides(&overrides, &translations, "en", &locales);
+
+        assert_eq!(
+            issues,
+            vec![
+                "Variant 'sale' override for 'shop.cart' in locale 'id' is an ICU message, \
+                 which variants can't replace",
+                "Variant 'sale' override for 'ui.buy' in locale 'en' is an ICU message, \
+                 which variants can't replace",
+            ]
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use super::format_diagnostic;
+use crate::parser::Translation;
+use crate::utils::format::Formatters;
+use crate::utils::{icu, lists, plurals, selects};
+
+/// Check the placeholders of every translation value
+///
+/// Reports unknown or malformed format specifiers (`{count:integer}`,
+/// `{price:fixed(x)}`), invalid placeholder names, unbalanced braces and ICU
+/// syntax errors, with the key, locale and a suggested fix. Literal braces are
+/// written `{{` and `}}`, except in plural, select and list keys, whose forms
+/// are formatted by the translator. The custom specifiers of `formatters` are
+/// known.
+pub fn check_placeholders(translations: &[Translation], formatters: &Formatters) -> Vec<String> {
+    placeholder_issues(translations, formatters)
+        .into_iter()
+        .map(|(translation, message)| format_diagnostic(translation.location.as_ref(), &message))
+        .collect()
+}
+
+/// Like [`check_placeholders`], but returns each invalid translation with its
+/// message, without the location prefix
+pub fn placeholder_issues<'a>(
+    translations: &'a [Translation],
+    formatters: &Formatters,
+) -> Vec<(&'a Translation, String)> {
+    let mut issues = Vec::new();
+
+    for translation in translations {
+        let message = match icu::parse_value(&translation.value, formatters) {
+            Err(e) => format!(
+                "Invalid placeholder in key '{}' ({}): {}",
+                translation.key, translation.locale, e
+            ),
+            Ok(_)
+                if icu::has_escaped_braces(&translation.value)
+                    && (plurals::is_plural_key(&translation.key)
+                        || selects::is_select_key(&translation.key)
+                        || lists::is_list_item_key(&translation.key)) =>
+            {
+                format!(
+                    "Key '{}' ({}) escapes braces, which plural, select and list keys don't support (write it as an ICU message and quote the braces: '{{')",
+                    translation.key, translation.locale
+                )
+            }
+            Ok(_) => continue,
+        };
+        issues.push((translation, message));
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::SourceLocation;
+
+    fn translation(key: &str, value: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: value.to_string(),
+            locale: "es".to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_check_placeholders() {
+        let mut unknown = translation("ui.coins", "Monedas: {count:integer}");
+        unknown.location = Some(SourceLocation {
+            file: "translations/es.json".into(),
+            line: 3,
+            column: 14,
+        });
+        let translations = vec![
+            translation("ui.ok", "Hola {name}, usa {{x}} y {price:fixed(2)}"),
+            unknown,
+            translation("ui.price", "{price:fixed(x)}"),
+            translation("ui.open", "Abre {menu"),
+            translation("ui.items(one)", "{{# item}}"),
+        ];
+
+        let issues = check_placeholders(&translations, &Formatters::new());
+
+        assert_eq!(issues.len(), 4);
+        assert_eq!(
+            issues[0],
+            "translations/es.json:3:14: Invalid placeholder in key 'ui.coins' (es): Unknown format specifier 'integer' for 'count' (did you mean 'int'?)"
+        );
+        assert!(issues[1].contains("Invalid digits 'x' in format specifier 'fixed(x)'"));
+        assert!(issues[2].contains("Unclosed '{' at column 6 (write '{{' for a literal brace)"));
+        assert!(issues[3].contains("Key 'ui.items(one)' (es) escapes braces"));
+    }
+}
@@ -0,0 +1,154 @@
+use super::format_diagnostic;
+use crate::parser::Translation;
+use crate::utils::plurals;
+use std::collections::BTreeMap;
+
+/// Check that every plural key has the forms each locale's CLDR rules need
+///
+/// Cardinal keys (`items(one)`) are checked against the cardinal rules and ordinal
+/// keys (`place(ordinal:one)`) against the ordinal rules, so English ordinals need
+/// `one`, `two`, `few` and `other` while French ones only need `one` and `other`.
+/// A key that mixes cardinal and ordinal forms is reported too.
+pub fn check_plural_forms(
+    translations: &[Translation],
+    supported_locales: &[String],
+) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    for locale in supported_locales {
+        // Plural forms of this locale, grouped by base key
+        let mut groups: BTreeMap<String, Vec<&Translation>> = BTreeMap::new();
+        for translation in translations
+            .iter()
+            .filter(|t| &t.locale == locale && plurals::is_plural_key(&t.key))
+        {
+            groups
+                .entry(plurals::extract_base_key(&translation.key))
+                .or_default()
+                .push(translation);
+        }
+
+        for (base_key, mut forms) in groups {
+            forms.sort_by(|a, b| a.key.cmp(&b.key));
+            let location = forms.iter().find_map(|t| t.location.as_ref());
+
+            let ordinal = forms
+                .iter()
+                .filter(|t| plurals::is_ordinal_key(&t.key))
+                .count();
+            if ordinal != 0 && ordinal != forms.len() {
+                issues.push(format_diagnostic(
+                    location,
+                    &format!(
+                        "Plural key '{}' in locale '{}' mixes cardinal and ordinal forms",
+                        base_key, locale
+                    ),
+                ));
+                continue;
+            }
+            let ordinal = ordinal != 0;
+
+            let required = match plurals::rules_for_locale(locale) {
+                Some(rules) => plurals::required_categories(rules, ordinal),
+                None => vec!["other"],
+            };
+            let missing: Vec<String> = required
+                .iter()
+                .filter(|category| {
+                    !forms
+                        .iter()
+                        .any(|t| plurals::plural_category_of(&t.key) == Some(**category))
+                })
+                .map(|category| {
+                    if ordinal {
+                        format!("{}{}", plurals::ORDINAL_PREFIX, category)
+                    } else {
+                        category.to_string()
+                    }
+                })
+                .collect();
+
+            if !missing.is_empty() {
+                issues.push(format_diagnostic(
+                    location,
+                    &format!(
+                        "Plural key '{}' in locale '{}' is missing forms: {}",
+                        base_key,
+                        locale,
+                        missing.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::SourceLocation;
+
+    fn translation(key: &str, locale: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: "{count}".to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_check_plural_forms_complete() {
+        let translations = vec![
+            translation("items(one)", "en"),
+            translation("items(other)", "en"),
+            translation("items(other)", "ja"),
+            translation("items(one)", "es"),
+            translation("items(other)", "es"),
+            translation("place(ordinal:one)", "fr"),
+            translation("place(ordinal:other)", "fr"),
+        ];
+        let locales: Vec<String> = ["en", "ja", "es", "fr"]
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+
+        assert!(check_plural_forms(&translations, &locales).is_empty());
+    }
+
+    #[test]
+    fn test_check_plural_forms_reports_missing() {
+        let mut one = translation("items(one)", "pl");
+        one.location = Some(SourceLocation {
+            file: "translations/pl.json".into(),
+            line: 2,
+            column: 16,
+        });
+
+        let translations = vec![
+            one,
+            translation("items(other)", "pl"),
+            translation("place(ordinal:one)", "en"),
+            translation("place(ordinal:other)", "en"),
+            translation("rank(one)", "en"),
+            translation("rank(ordinal:other)", "en"),
+        ];
+        let locales = vec!["en".to_string(), "pl".to_string()];
+
+        let issues = check_plural_forms(&translations, &locales);
+
+        assert_eq!(
+            issues,
+            vec![
+                "Plural key 'place' in locale 'en' is missing forms: ordinal:two, ordinal:few",
+                "Plural key 'rank' in locale 'en' mixes cardinal and ordinal forms",
+                "translations/pl.json:2:16: Plural key 'items' in locale 'pl' is missing forms: few, many",
+            ]
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use super::format_diagnostic;
+use crate::parser::{SourceLocation, Translation};
+use crate::utils::selects;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Check that select keys have an `other` case and the same cases in every locale
+///
+/// The base locale's cases are the reference: other locales are reported for
+/// cases they lack and for cases the base locale doesn't have.
+pub fn check_select_cases(
+    translations: &[Translation],
+    base_locale: &str,
+    supported_locales: &[String],
+) -> Vec<String> {
+    let base = select_groups(translations, base_locale);
+    let mut issues = Vec::new();
+
+    for (key, group) in &base {
+        if !group.cases.contains(selects::FALLBACK_CASE) {
+            issues.push(format_diagnostic(
+                group.location,
+                &format!(
+                    "Select key '{}' has no '{}' case to fall back to",
+                    key,
+                    selects::FALLBACK_CASE
+                ),
+            ));
+        }
+    }
+
+    for locale in supported_locales {
+        if locale == base_locale {
+            continue;
+        }
+        let groups = select_groups(translations, locale);
+
+        for (key, expected) in &base {
+            let Some(group) = groups.get(key) else {
+                continue;
+            };
+
+            let missing: Vec<&str> = expected.cases.difference(&group.cases).copied().collect();
+            if !missing.is_empty() {
+                issues.push(format_diagnostic(
+                    group.location,
+                    &format!(
+                        "Select key '{}' in locale '{}' is missing cases: {}",
+                        key,
+                        locale,
+                        missing.join(", ")
+                    ),
+                ));
+            }
+
+            let extra: Vec<&str> = group.cases.difference(&expected.cases).copied().collect();
+            if !extra.is_empty() {
+                issues.push(format_diagnostic(
+                    group.location,
+                    &format!(
+                        "Select key '{}' in locale '{}' has cases the base locale doesn't: {}",
+                        key,
+                        locale,
+                        extra.join(", ")
+                    ),
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+/// The cases of one select key in one locale
+struct SelectGroup<'a> {
+    cases: BTreeSet<&'a str>,
+    /// Location of the case that comes first in the source file
+    location: Option<&'a SourceLocation>,
+}
+
+fn select_groups<'a>(
+    translations: &'a [Translation],
+    locale: &str,
+) -> BTreeMap<String, SelectGroup<'a>> {
+    let mut groups: BTreeMap<String, SelectGroup<'a>> = BTreeMap::new();
+
+    for translation in translations.iter().filter(|t| t.locale == locale) {
+        let Some(case) = selects::select_case(&translation.key) else {
+            continue;
+        };
+
+        let group = groups
+            .entry(selects::extract_base_key(&translation.key))
+            .or_insert_with(|| SelectGroup {
+                cases: BTreeSet::new(),
+                location: None,
+            });
+        group.cases.insert(case);
+        if let Some(location) = &translation.location {
+            let earlier = group.location.is_none_or(|current| {
+                (location.line, location.column) < (current.line, current.column)
+            });
+            if earlier {
+                group.location = Some(location);
+            }
+        }
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translation(key: &str, locale: &str) -> Translation {
+        Translation {
+            key: key.to_string(),
+            value: "Hi".to_string(),
+            locale: locale.to_string(),
+            context: None,
+            description: None,
+            max_length: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_check_select_cases_consistent() {
+        let translations = vec![
+            translation("greeting(select:male)", "en"),
+            translation("greeting(select:other)", "en"),
+            translation("greeting(select:male)", "es"),
+            translation("greeting(select:other)", "es"),
+        ];
+        let locales = vec!["en".to_string(), "es".to_string()];
+
+        assert!(check_select_cases(&translations, "en", &locales).is_empty());
+    }
+
+    #[test]
+    fn test_check_select_cases_reports_issues() {
+        let mut rarity = translation("item.rarity(select:rare)", "en");
+        rarity.location = Some(SourceLocation {
+            file: "translations/en.json".into(),
+            line: 4,
+            column: 34,
+        });
+
+        let translations = vec![
+            rarity,
+            translation("item.rarity(select:common)", "en"),
+            translation("greeting(select:male)", "en"),
+            translation("greeting(select:female)", "en"),
+            translation("greeting(select:other)", "en"),
+            translation("greeting(select:male)", "es"),
+            translation("greeting(select:nonbinary)", "es"),
+            translation("greeting(select:other)", "es"),
+        ];
+        let locales = vec!["en".to_string(), "es".to_string()];
+
+        let issues = check_select_cases(&translations, "en", &locales);
+
+        assert_eq!(
+            issues,
+            vec![
+                "translations/en.json:4:34: Select key 'item.rarity' has no 'other' case to fall back to",
+                "Select key 'greeting' in locale 'es' is missing cases: female",
+                "Select key 'greeting' in locale 'es' has cases the base locale doesn't: nonbinary",
+            ]
+        );
+    }
+}
@@ -193,6 +193,117 @@ fn test_build_with_mixed_formats() {
         .success();
 }
 
+/// Tests build merges namespaced files from a locale directory
+#[test]
+fn test_build_with_locale_directory() {
+    let temp = common::create_test_project();
+
+    fs::write(
+        temp.path().join("translations/en.json"),
+        r#"{"ui": {"button": "Buy"}}"#,
+    )
+    .unwrap();
+    fs::create_dir_all(temp.path().join("translations/en/shop")).unwrap();
+    fs::write(
+        temp.path().join("translations/en/shop/items.yaml"),
+        "sword: Sword\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Parsed en (2 keys)"));
+
+    common::assert_file_contains(
+        &temp.path().join("output/Translations.lua"),
+        "function Translations:shop_items_sword()",
+    );
+}
+
+/// Tests build reads a Roblox-exported CSV holding every locale
+#[test]
+fn test_build_with_roblox_csv() {
+    let temp = common::create_test_project();
+
+    fs::write(
+        temp.path().join("translations/shop.csv"),
+        "Key,Source,Context,Example,id\n\
+         shop.buy,Buy,button,Shop button,Beli\n\
+         shop.sell,Sell,,,Jual\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Parsed en (2 keys)"))
+        .stdout(predicate::str::contains("Parsed id (2 keys)"));
+
+    common::assert_file_contains(
+        &temp.path().join("output/Translations.lua"),
+        "function Translations:shop_buy()",
+    );
+}
+
+/// Tests build reads the base locale from the Source column of a Roblox export
+#[test]
+fn test_build_with_roblox_export_fixture() {
+    let temp = common::create_test_project();
+    let fixture = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/csv/roblox_export.csv");
+    fs::copy(fixture, temp.path().join("translations/game.csv")).unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Parsed en (4 keys)"))
+        .stdout(predicate::str::contains("Parsed id (3 keys)"));
+
+    common::assert_file_contains(
+        &temp.path().join("output/roblox_upload.csv"),
+        "Buy,,shop.buy,Label on the purchase button,Buy,Beli",
+    );
+}
+
+/// Tests build reports keys defined in two files of the same locale
+#[test]
+fn test_build_with_cross_file_collision() {
+    let temp = common::create_test_project();
+
+    fs::write(
+        temp.path().join("translations/en.json"),
+        r#"{"shop": {"buy": "Buy"}}"#,
+    )
+    .unwrap();
+    fs::create_dir_all(temp.path().join("translations/en")).unwrap();
+    fs::write(
+        temp.path().join("translations/en/shop.json"),
+        r#"{"buy": "Purchase"}"#,
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Duplicate translation key 'shop.buy'",
+        ))
+        .stderr(predicate::str::contains("shop.json"));
+}
+
 /// Tests build with overrides.yaml applies overrides correctly
 /// TODO: This test is skipped because there's a bug in the overrides implementation.
 /// The overrides are not being loaded/applied correctly even when enabled in config.
@@ -243,6 +354,101 @@ id:
     );
 }
 
+/// Tests build fails on variant overrides for unknown keys and unsupported locales
+#[test]
+fn test_build_rejects_invalid_overrides() {
+    let temp = common::create_test_project_with_translations();
+
+    let config = r#"base_locale: en
+supported_locales:
+  - en
+  - id
+input_directory: translations
+output_directory: output
+overrides:
+  enabled: true
+  file: overrides.yaml
+"#;
+    fs::write(temp.path().join("slang-roblox.yaml"), config).unwrap();
+
+    let overrides = r#"variants:
+  halloween:
+    translations:
+      en:
+        ui.buttons.gift: "Gift"
+      fr:
+        ui.buttons.buy: "Acheter"
+"#;
+    fs::write(temp.path().join("overrides.yaml"), overrides).unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Variant 'halloween' override for unknown key 'ui.buttons.gift' in locale 'en'",
+        ))
+        .stderr(predicate::str::contains(
+            "Variant 'halloween' override for 'ui.buttons.buy' uses unsupported locale 'fr'",
+        ))
+        .stderr(predicate::str::contains(
+            "2 override(s) don't match the translations",
+        ));
+
+    common::assert_file_not_exists(&temp.path().join("output/Translations.lua"));
+}
+
+/// Tests build generates runtime variants from overrides.yaml
+#[test]
+fn test_build_with_override_variants() {
+    let temp = common::create_test_project_with_translations();
+
+    let config = r#"base_locale: en
+supported_locales:
+  - en
+  - id
+input_directory: translations
+output_directory: output
+overrides:
+  enabled: true
+  file: overrides.yaml
+"#;
+    fs::write(temp.path().join("slang-roblox.yaml"), config).unwrap();
+
+    let overrides = r#"variants:
+  halloween:
+    start: 2025-10-20
+    end: 2025-11-01
+    translations:
+      en:
+        ui.buttons.buy: "Trick or Treat!"
+"#;
+    fs::write(temp.path().join("overrides.yaml"), overrides).unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Loaded 1 variants"));
+
+    let luau = fs::read_to_string(temp.path().join("output/Translations.lua")).unwrap();
+    assert!(luau.contains("function Translations:setVariant(name)"));
+    assert!(luau.contains("[\"ui.buttons.buy\"] = \"Trick or Treat!\","));
+
+    // Variant values stay out of the CSV uploaded to Roblox
+    let csv = fs::read_to_string(temp.path().join("output/roblox_upload.csv")).unwrap();
+    assert!(!csv.contains("Trick or Treat!"));
+
+    common::assert_file_contains(
+        &temp.path().join("output/types/Translations.d.luau"),
+        "setVariant: (self: TranslationsInstance, name: string?) -> (),",
+    );
+}
+
 // ====================================================================================
 // Watch Command Tests
 // ====================================================================================
@@ -308,6 +514,29 @@ fn test_import_basic_csv() {
     assert!(en_json.contains("Buy"));
 }
 
+/// Tests import of a Roblox export writes the Source column as the base locale
+#[test]
+fn test_import_roblox_export() {
+    let temp = common::create_test_project();
+    let fixture = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/csv/roblox_export.csv");
+    fs::copy(fixture, temp.path().join("import.csv")).unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("import")
+        .arg("import.csv")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Wrote en (4 keys)"));
+
+    let en_json = fs::read_to_string(temp.path().join("translations/en.json")).unwrap();
+    assert!(en_json.contains("\"@value\": \"Buy\""));
+    assert!(en_json.contains("Press \\\"E\\\" to talk"));
+    common::assert_file_exists(&temp.path().join("translations/es.json"));
+}
+
 /// Tests import with multiple locales in CSV
 #[test]
 fn test_import_multiple_locales() {
@@ -375,10 +604,181 @@ fn test_import_with_special_characters() {
         .success();
 }
 
+// ====================================================================================
+// Upload Command Tests
+// ====================================================================================
+
+/// Tests upload validation fails before any cloud call, pointing at the entry
+#[test]
+fn test_upload_validation_reports_location() {
+    let temp = common::create_test_project();
+
+    fs::write(
+        temp.path().join("translations/en.json"),
+        "{\n  \"ui\": {\n    \"coins\": \"Coins: {count:integer}\"\n  }\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        temp.path().join("translations/id.json"),
+        r#"{"ui": {"coins": "Koin: {count}"}}"#,
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("upload")
+        .arg("--table-id")
+        .arg("test-table")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid placeholders:"))
+        .stderr(predicate::str::contains(
+            "Validation failed in translations/en.json:3",
+        ))
+        .stderr(predicate::str::contains(
+            "Unknown format specifier 'integer' for 'count'",
+        ));
+}
+
 // ====================================================================================
 // Validate Command Tests
 // ====================================================================================
 
+/// Tests validate --overrides reports orphan keys, locales and parameter drift
+#[test]
+fn test_validate_overrides_flag() {
+    let temp = common::create_test_project_with_translations();
+
+    let config = r#"base_locale: en
+supported_locales:
+  - en
+  - id
+input_directory: translations
+output_directory: output
+overrides:
+  enabled: true
+  file: overrides.yaml
+"#;
+    fs::write(temp.path().join("slang-roblox.yaml"), config).unwrap();
+
+    let overrides = r#"en:
+  ui.buttons.gift: "Gift"
+  ui.labels.welcome: "Welcome, {name}!"
+fr:
+  ui.buttons.buy: "Acheter"
+"#;
+    fs::write(temp.path().join("overrides.yaml"), overrides).unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("validate")
+        .arg("--overrides")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "overrides.yaml:2:20: Override for unknown key 'ui.buttons.gift' in locale 'en'",
+        ))
+        .stdout(predicate::str::contains(
+            "has parameters {name} but the base entry has {}",
+        ))
+        .stdout(predicate::str::contains(
+            "Override for 'ui.buttons.buy' uses unsupported locale 'fr'",
+        ))
+        .stdout(predicate::str::contains("Validation completed with issues"));
+}
+
+/// Tests validate --plurals reports forms a locale's plural rules need
+#[test]
+fn test_validate_plurals_flag() {
+    let temp = common::create_test_project();
+
+    let config = r#"base_locale: en
+supported_locales:
+  - en
+  - pl
+input_directory: translations
+output_directory: output
+"#;
+    fs::write(temp.path().join("slang-roblox.yaml"), config).unwrap();
+
+    let en = r#"{
+  "race": {
+    "place(ordinal:one)": "{count}st",
+    "place(ordinal:two)": "{count}nd",
+    "place(ordinal:few)": "{count}rd",
+    "place(ordinal:other)": "{count}th"
+  },
+  "items(one)": "{count} item",
+  "items(other)": "{count} items"
+}"#;
+    fs::write(temp.path().join("translations/en.json"), en).unwrap();
+
+    let pl = r#"{
+  "race": {"place(ordinal:other)": "{count}."},
+  "items(one)": "{count} przedmiot",
+  "items(other)": "{count} przedmiotu"
+}"#;
+    fs::write(temp.path().join("translations/pl.json"), pl).unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("validate")
+        .arg("--plurals")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "translations/pl.json:3:17: Plural key 'items' in locale 'pl' is missing forms: few, many",
+        ))
+        .stdout(predicate::str::contains("'race.place'").not())
+        .stdout(predicate::str::contains("Validation completed with issues"));
+}
+
+/// Tests validate --selects reports select cases that differ from the base locale
+#[test]
+fn test_validate_selects_flag() {
+    let temp = common::create_test_project_with_translations();
+
+    let en = r#"{
+  "greeting(select:male)": "Welcome, sir",
+  "greeting(select:female)": "Welcome, madam",
+  "greeting(select:other)": "Welcome"
+}"#;
+    fs::write(temp.path().join("translations/en.json"), en).unwrap();
+
+    let id = r#"{
+  "greeting(select:male)": "Selamat datang, Pak",
+  "greeting(select:other)": "Selamat datang"
+}"#;
+    fs::write(temp.path().join("translations/id.json"), id).unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("validate")
+        .arg("--selects")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "translations/id.json:2:28: Select key 'greeting' in locale 'id' is missing cases: female",
+        ))
+        .stdout(predicate::str::contains("Validation completed with issues"));
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success();
+
+    let types = fs::read_to_string(temp.path().join("output/types/Translations.d.luau")).unwrap();
+    assert!(types.contains(
+        "greeting: (self: TranslationsInstance, case: \"female\" | \"male\" | \"other\", params: {}?) -> string"
+    ));
+}
+
 /// Tests validate --missing detects missing translations
 #[test]
 fn test_validate_missing_flag() {
@@ -403,6 +803,29 @@ fn test_validate_missing_flag() {
         .stdout(predicate::str::contains("ui.label"));
 }
 
+/// Tests validate --missing points at the base locale definition
+#[test]
+fn test_validate_missing_reports_location() {
+    let temp = common::create_test_project();
+
+    let en = "{\n  \"ui\": {\n    \"button\": \"Buy\",\n    \"label\": \"Welcome\"\n  }\n}\n";
+    fs::write(temp.path().join("translations/en.json"), en).unwrap();
+
+    let id = r#"{"ui": {"button": "Beli"}}"#;
+    fs::write(temp.path().join("translations/id.json"), id).unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("validate")
+        .arg("--missing")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "translations/en.json:4:14: ui.label",
+        ));
+}
+
 /// Tests validate --conflicts detects duplicate keys
 #[test]
 fn test_validate_conflicts_flag() {
@@ -96,6 +96,104 @@ fn test_build_generates_type_definitions() {
     common::assert_file_contains(&types_path, "ui_buttons_buy");
 }
 
+/// Tests that the embedded runtime bakes every locale into the module
+#[test]
+fn test_build_embedded_runtime() {
+    let temp = common::create_test_project_with_translations();
+
+    let config_path = temp.path().join("slang-roblox.yaml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(&config_path, format!("{}runtime: embedded\n", config)).unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success();
+
+    let luau_path = temp.path().join("output/Translations.lua");
+
+    common::assert_file_contains(&luau_path, "[\"ui.buttons.buy\"] = \"Beli\",");
+    common::assert_file_contains(&luau_path, "self._translator = newTranslator(self._locale)");
+    common::assert_file_contains(&luau_path, "function Translations:ui_buttons_buy");
+    common::assert_file_not_contains(&luau_path, "GetTranslatorForLocaleAsync");
+}
+
+/// Tests that locale chunks are written next to the module and loaded on demand
+#[test]
+fn test_build_embedded_locale_chunks() {
+    let temp = common::create_test_project_with_translations();
+
+    let config_path = temp.path().join("slang-roblox.yaml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        format!("{}runtime: embedded\nembedded_chunks: locale\n", config),
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Generated 2 locale modules"));
+
+    let luau_path = temp.path().join("output/Translations.lua");
+    common::assert_file_not_contains(&luau_path, "Beli");
+    common::assert_file_contains(&luau_path, "script.Parent:WaitForChild(\"locales\")");
+
+    common::assert_file_contains(
+        &temp.path().join("output/locales/id.lua"),
+        "[\"ui.buttons.buy\"] = \"Beli\",",
+    );
+    common::assert_file_exists(&temp.path().join("output/locales/en.lua"));
+}
+
+/// Tests that a namespace names the generated files and types, so two modules can share an output directory
+#[test]
+fn test_build_namespaced_modules_coexist() {
+    let temp = common::create_test_project_with_translations();
+
+    let config_path = temp.path().join("slang-roblox.yaml");
+    let config = fs::read_to_string(&config_path).unwrap();
+
+    for namespace in ["GameStrings", "ShopStrings"] {
+        fs::write(
+            &config_path,
+            format!(
+                "{}namespace: {}\nruntime: embedded\nembedded_chunks: locale\n",
+                config, namespace
+            ),
+        )
+        .unwrap();
+
+        Command::cargo_bin("roblox-slang")
+            .unwrap()
+            .current_dir(&temp)
+            .arg("build")
+            .assert()
+            .success();
+    }
+
+    let output = temp.path().join("output");
+    for namespace in ["GameStrings", "ShopStrings"] {
+        common::assert_file_contains(
+            &output.join(format!("{}.lua", namespace)),
+            &format!("script.Parent:WaitForChild(\"{}_locales\")", namespace),
+        );
+        common::assert_file_contains(
+            &output.join(format!("types/{}.d.luau", namespace)),
+            &format!("export type {}Instance = {{", namespace),
+        );
+        common::assert_file_exists(&output.join(format!("{}_locales/id.lua", namespace)));
+        common::assert_file_exists(&output.join(format!("{}_roblox_upload.csv", namespace)));
+    }
+    assert!(!output.join("Translations.lua").exists());
+}
+
 /// Tests validate command with all checks enabled
 #[test]
 fn test_validate_command_all() {
@@ -328,3 +426,244 @@ fn test_error_handling_invalid_json() {
         .failure()
         .stderr(predicate::str::contains("Failed to parse"));
 }
+
+/// Tests that unknown format specifiers and unbalanced braces fail the build
+#[test]
+fn test_build_rejects_invalid_placeholders() {
+    let temp = common::create_test_project();
+
+    fs::write(
+        temp.path().join("translations/en.json"),
+        r#"{"ui": {"coins": "Coins: {count:integer}", "menu": "Open {menu"}}"#,
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid placeholder in key 'ui.coins' (en): Unknown format specifier 'integer' for 'count' (did you mean 'int'?)",
+        ))
+        .stderr(predicate::str::contains("Unclosed '{' at column 6"))
+        .stderr(predicate::str::contains(
+            "2 translation value(s) have invalid placeholders",
+        ));
+
+    assert!(!temp.path().join("output/Translations.lua").exists());
+}
+
+/// Tests that invalid placeholders in override variants fail the build too
+#[test]
+fn test_build_rejects_invalid_variant_placeholders() {
+    let temp = common::create_test_project();
+
+    fs::write(
+        temp.path().join("translations/en.json"),
+        r#"{"ui": {"coins": "Coins: {count:int}"}}"#,
+    )
+    .unwrap();
+    let config_path = temp.path().join("slang-roblox.yaml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        format!(
+            "{}overrides:\n  enabled: true\n  file: overrides.yaml\n",
+            config
+        ),
+    )
+    .unwrap();
+    fs::write(
+        temp.path().join("overrides.yaml"),
+        "variants:\n  sale:\n    translations:\n      en:\n        ui.coins: \"Coins: {count:integer} {\"\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Invalid placeholder in key 'ui.coins' (en): Unknown format specifier 'integer' for 'count' (did you mean 'int'?)",
+        ))
+        .stderr(predicate::str::contains(
+            "1 translation value(s) have invalid placeholders",
+        ));
+
+    assert!(!temp.path().join("output/Translations.lua").exists());
+}
+
+/// Tests that custom format specifiers from the config call their module
+#[test]
+fn test_build_custom_formatters() {
+    let temp = common::create_test_project();
+
+    let config_path = temp.path().join("slang-roblox.yaml");
+    let config = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        format!(
+            "{}formatters:\n  health:\n    module: game.ReplicatedStorage.Formatters\n    function: heartBar\n    input: number\n",
+            config
+        ),
+    )
+    .unwrap();
+    fs::write(
+        temp.path().join("translations/en.json"),
+        r#"{"hud": {"health": "HP: {hp:health}"}}"#,
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success();
+
+    common::assert_file_contains(
+        &temp.path().join("output/Translations.lua"),
+        "params.hp = require(game.ReplicatedStorage.Formatters).heartBar(params.hp, self._locale)",
+    );
+    common::assert_file_contains(
+        &temp.path().join("output/types/Translations.d.luau"),
+        "hud_health: (self: TranslationsInstance, params: { hp: number }) -> string,",
+    );
+
+    // Without the formatter, the specifier is unknown
+    fs::write(&config_path, config).unwrap();
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Unknown format specifier 'health' for 'hp'",
+        ));
+}
+
+#[test]
+fn test_build_links() {
+    let temp = common::create_test_project();
+
+    fs::write(
+        temp.path().join("translations/en.json"),
+        r#"{"common": {"gems": "Gems"}, "shop": {"buy": "Buy {count} @:common.gems"}}"#,
+    )
+    .unwrap();
+    fs::write(
+        temp.path().join("translations/id.json"),
+        r#"{"common": {"gems": "Permata"}, "shop": {"buy": "Beli {count} @:common.gems"}}"#,
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success();
+
+    common::assert_file_contains(
+        &temp.path().join("output/roblox_upload.csv"),
+        "Buy {count} Gems,,shop.buy,,Buy {count} Gems,Beli {count} Permata",
+    );
+    common::assert_file_contains(
+        &temp.path().join("output/types/Translations.d.luau"),
+        "shop_buy: (self: TranslationsInstance, params: { count: string | number }) -> string,",
+    );
+
+    // Links to missing keys fail the build
+    fs::write(
+        temp.path().join("translations/id.json"),
+        r#"{"shop": {"buy": "Beli {count} @:common.gem"}}"#,
+    )
+    .unwrap();
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Broken link in key 'shop.buy' (id): Link '@:common.gem' points to a key that doesn't exist in 'id' or its fallbacks",
+        ))
+        .stderr(predicate::str::contains(
+            "1 translation value(s) have broken links",
+        ));
+}
+
+/// Build the strict fixture with the config in `case` and compare the module
+/// with the snapshot next to that config
+///
+/// Set `UPDATE_SNAPSHOTS=1` to rewrite the snapshots in `tests/fixtures/strict`
+/// after an intended change to the generated module.
+fn assert_strict_snapshot(case: &str) -> tempfile::TempDir {
+    let fixture = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/strict");
+    let temp = tempfile::TempDir::new().unwrap();
+
+    fs::create_dir_all(temp.path().join("translations")).unwrap();
+    for file in [
+        "overrides.yaml",
+        "translations/en.json",
+        "translations/id.json",
+    ] {
+        fs::copy(fixture.join(file), temp.path().join(file)).unwrap();
+    }
+    fs::copy(
+        fixture.join(case).join("slang-roblox.yaml"),
+        temp.path().join("slang-roblox.yaml"),
+    )
+    .unwrap();
+
+    Command::cargo_bin("roblox-slang")
+        .unwrap()
+        .current_dir(&temp)
+        .arg("build")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("output/Translations.luau"));
+
+    // The strict module replaces both classic outputs
+    common::assert_file_not_exists(&temp.path().join("output/Translations.lua"));
+    common::assert_file_not_exists(&temp.path().join("output/types"));
+
+    let generated = fs::read_to_string(temp.path().join("output/Translations.luau")).unwrap();
+    let snapshot_path = fixture.join(case).join("Translations.luau");
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::write(&snapshot_path, &generated).unwrap();
+    }
+
+    let snapshot = fs::read_to_string(&snapshot_path).unwrap();
+    assert_eq!(
+        generated, snapshot,
+        "strict output differs from the snapshot; rerun with UPDATE_SNAPSHOTS=1 if intended"
+    );
+
+    temp
+}
+
+/// Tests that strict Luau output matches the bundled snapshot
+#[test]
+fn test_build_strict_module_matches_snapshot() {
+    assert_strict_snapshot(".");
+}
+
+/// Tests that strict Luau output with per-locale chunks matches its snapshot
+#[test]
+fn test_build_strict_chunked_module_matches_snapshot() {
+    let temp = assert_strict_snapshot("chunks");
+
+    let generated = fs::read_to_string(temp.path().join("output/Translations.luau")).unwrap();
+    // The chunk folder is found through Roblox's Instance, not the module's own types
+    assert!(generated
+        .contains("local localeModules = (script.Parent :: Instance):WaitForChild(\"locales\")\n"));
+    assert!(!generated.contains("type Instance ="));
+    common::assert_file_exists(&temp.path().join("output/locales/id.luau"));
+}